@@ -0,0 +1,261 @@
+use crate::{Plot, Renderer};
+use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+#[cfg(feature = "image")]
+use std::{fs, path::Path};
+
+/// Draw an animation in the terminal. See [`Animation`] to also save the animation as an image.
+///
+/// `plot_builder` is called with the index of each frame, from `0` to `frames - 1`, and the
+/// returned plot replaces the previous frame in place. Frames are drawn `fps` times per second;
+/// when building and drawing a frame takes too long, the next frames are drawn as soon as
+/// possible to catch up.
+///
+/// The cursor is hidden during the animation and shown again when it ends, even when building a
+/// frame panics. `Ctrl-C` is left to the program: by default, it ends the program without showing
+/// the cursor again, so a program handling `Ctrl-C` should write `"\x1b[?25h"` before exiting.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use termplot::*;
+///
+/// termplot::animate(
+///     |frame| {
+///         let phase = frame as f64 / 10.0;
+///         let mut plot = Plot::default();
+///         plot.set_domain(Domain(-10.0..10.0))
+///             .set_codomain(Domain(-1.2..1.2))
+///             .set_size(Size::new(50, 25))
+///             .add_plot(Box::new(plot::Graph::new(move |x| (x + phase).sin())));
+///         plot
+///     },
+///     30.0,
+///     300,
+/// )
+/// .unwrap();
+/// ```
+pub fn animate(
+    mut plot_builder: impl FnMut(usize) -> Plot,
+    fps: f64,
+    frames: usize,
+) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b[?25l")?;
+    let _cursor = ShowCursor;
+    // Stdout is locked for each frame only, so that the program can write to it in between.
+    draw_frames(stdout, &mut plot_builder, fps, frames)
+}
+
+/// Shows the cursor again when dropped.
+struct ShowCursor;
+
+impl Drop for ShowCursor {
+    fn drop(&mut self) {
+        let mut stdout = io::stdout();
+        let _ = write!(stdout, "\x1b[?25h");
+        let _ = stdout.flush();
+    }
+}
+
+/// An animation: a plot for each frame, drawn at a fixed rate.
+///
+/// See [`animate`] to draw an animation in the terminal without keeping it.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use termplot::*;
+///
+/// let mut animation = Animation::new(
+///     |frame| {
+///         let phase = frame as f64 / 10.0;
+///         let mut plot = Plot::default();
+///         plot.set_domain(Domain(-10.0..10.0))
+///             .set_codomain(Domain(-1.2..1.2))
+///             .set_size(Size::new(100, 50))
+///             .add_plot(Box::new(plot::Graph::new(move |x| (x + phase).sin())));
+///         plot
+///     },
+///     30.0,
+///     60,
+/// );
+///
+/// animation.play().unwrap();
+/// # #[cfg(feature = "image")]
+/// animation.save_gif("sine.gif").unwrap();
+/// ```
+pub struct Animation<F> {
+    plot_builder: F,
+    fps: f64,
+    frames: usize,
+}
+
+impl<F> Animation<F>
+where
+    F: FnMut(usize) -> Plot,
+{
+    /// Create a new animation of `frames` frames, drawn `fps` times per second. `plot_builder`
+    /// is called with the index of each frame to build its plot.
+    pub fn new(plot_builder: F, fps: f64, frames: usize) -> Self {
+        Self {
+            plot_builder,
+            fps,
+            frames,
+        }
+    }
+
+    /// Draw the animation in the terminal. See [`animate`].
+    pub fn play(&mut self) -> io::Result<()> {
+        animate(&mut self.plot_builder, self.fps, self.frames)
+    }
+
+    /// Save the animation as an animated GIF looping forever.
+    ///
+    /// Only the view of each frame is saved, without the decorations (title, labels, border,
+    /// etc...). Each pixel of the view is drawn as a white square of 2 by 2 pixels on a black
+    /// background. Every frame has the size of the first frame. Nothing is saved when the
+    /// animation has no frame.
+    ///
+    /// Requires the `image` feature.
+    #[cfg(feature = "image")]
+    pub fn save_gif(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        /// The size of a pixel of the view in the image.
+        const SCALE: usize = 2;
+        const PALETTE: [u8; 6] = [0, 0, 0, 255, 255, 255];
+
+        let builder = &mut self.plot_builder;
+        let mut frames = (0..self.frames).map(|frame| builder(frame).view.pixels());
+        let Some(first) = frames.next().transpose().map_err(io::Error::other)? else {
+            return Ok(());
+        };
+        let width = first.first().map_or(0, Vec::len) * SCALE;
+        let height = first.len() * SCALE;
+        let file = io::BufWriter::new(fs::File::create(path)?);
+        let mut encoder = gif::Encoder::new(
+            file,
+            u16::try_from(width).map_err(io::Error::other)?,
+            u16::try_from(height).map_err(io::Error::other)?,
+            &PALETTE,
+        )
+        .map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        for pixels in std::iter::once(Ok(first)).chain(frames) {
+            let pixels = pixels.map_err(io::Error::other)?;
+            let mut buffer = vec![0; width * height];
+            for (y, row) in buffer.chunks_mut(width.max(1)).enumerate() {
+                for (x, index) in row.iter_mut().enumerate() {
+                    let set = pixels
+                        .get(y / SCALE)
+                        .and_then(|row| row.get(x / SCALE))
+                        .copied()
+                        .unwrap_or(false);
+                    *index = set as u8;
+                }
+            }
+            let frame = gif::Frame {
+                width: width as u16,
+                height: height as u16,
+                buffer: buffer.into(),
+                delay: (100.0 / self.fps).round() as u16,
+                ..gif::Frame::default()
+            };
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+}
+
+fn draw_frames(
+    out: impl Write,
+    plot_builder: &mut impl FnMut(usize) -> Plot,
+    fps: f64,
+    frames: usize,
+) -> io::Result<()> {
+    let frame_duration = Duration::from_secs_f64(1.0 / fps.max(f64::MIN_POSITIVE));
+    let start = Instant::now();
+    let mut renderer = Renderer::new(out);
+    let mut drawing = String::new();
+    for frame in 0..frames {
+        // Deadlines are computed from the start so that delays do not accumulate.
+        let deadline = start + frame_duration.mul_f64(frame as f64);
+        if let Some(delay) = deadline.checked_duration_since(Instant::now()) {
+            thread::sleep(delay);
+        }
+        let plot = plot_builder(frame);
+        plot.render_into(&mut drawing).map_err(io::Error::other)?;
+        renderer.draw(&drawing)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{text, Domain, Size};
+
+    #[test]
+    fn frames_replace_each_other() {
+        let mut out = Vec::new();
+        let mut built = Vec::new();
+        let mut builder = |frame| {
+            built.push(frame);
+            let mut plot = Plot::default();
+            plot.set_domain(Domain(0.0..1.0))
+                .set_codomain(Domain(0.0..1.0))
+                .set_title(&format!("frame {frame}"))
+                .set_size(Size::new(10, 4));
+            plot
+        };
+        draw_frames(&mut out, &mut builder, 1000.0, 3).unwrap();
+        assert_eq!(built, [0, 1, 2]);
+        let out = text::strip_escapes(&String::from_utf8(out).unwrap());
+        assert!(out.contains("frame 0"));
+        // The next frames only write the changed digit of the title.
+        assert_eq!(out.matches("frame").count(), 1);
+        assert!(out.contains('1') && out.contains('2'));
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn gif_frames() {
+        let path = std::env::temp_dir().join(format!("termplot-{}.gif", std::process::id()));
+        let mut animation = Animation::new(
+            |frame| {
+                let mut plot = Plot::default();
+                plot.set_domain(Domain(0.0..2.0))
+                    .set_codomain(Domain(0.0..4.0))
+                    .set_size(Size::new(2, 4))
+                    .set_axis_mode(crate::AxisMode::None)
+                    .add_plot(Box::new(crate::plot::Scatter::new(vec![(
+                        frame as f64,
+                        0.0,
+                    )])));
+                plot
+            },
+            10.0,
+            2,
+        );
+        animation.save_gif(&path).unwrap();
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(fs::File::open(&path).unwrap()).unwrap();
+        let mut set = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!((frame.width, frame.height, frame.delay), (4, 8, 10));
+            let pixels = frame
+                .buffer
+                .iter()
+                .enumerate()
+                .filter(|(_, &index)| index == 1);
+            set.push(pixels.map(|(index, _)| index).collect::<Vec<_>>());
+        }
+        fs::remove_file(&path).unwrap();
+        // The point is a square of 2 by 2 pixels in the bottom row, moving right.
+        assert_eq!(set, [vec![24, 25, 28, 29], vec![26, 27, 30, 31]]);
+    }
+}
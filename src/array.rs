@@ -0,0 +1,107 @@
+//! Constructors of plots from [`ndarray`] arrays.
+//!
+//! Every function accepts anything that can be viewed as an array (`&Array1<f64>`,
+//! `ArrayView1<f64>`, a column of an `Array2<f64>`, etc...), so the data does not need to be
+//! converted with `.to_vec()` first. The values are read directly from the view and copied once
+//! into the plot.
+use crate::plot::{Bars, Heatmap, Histogram, Lines, Scatter};
+use ndarray::{AsArray, Ix2};
+use std::ops;
+
+impl Lines {
+    /// Create a new line going through the points with the x values of `x` and the y values of
+    /// `y`. Extra values of the longest array are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ndarray::Array1;
+    /// use termplot::*;
+    ///
+    /// let x = Array1::linspace(0.0, 10.0, 100);
+    /// let y = x.mapv(f64::sqrt);
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..10.0))
+    ///     .set_codomain(Domain(0.0..4.0))
+    ///     .add_plot(Box::new(plot::Lines::from_arrays(&x, &y)));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn from_arrays<'a, X, Y>(x: X, y: Y) -> Self
+    where
+        X: AsArray<'a, f64>,
+        Y: AsArray<'a, f64>,
+    {
+        Self::new(zip(x, y))
+    }
+}
+
+impl Scatter {
+    /// Create a new scatter plot from the x values of `x` and the y values of `y`. Extra values
+    /// of the longest array are ignored.
+    pub fn from_arrays<'a, X, Y>(x: X, y: Y) -> Self
+    where
+        X: AsArray<'a, f64>,
+        Y: AsArray<'a, f64>,
+    {
+        Self::new(zip(x, y))
+    }
+}
+
+impl Bars {
+    /// Create a new bars graph where each value of the array is the height of a bar.
+    pub fn from_array<'a, A: AsArray<'a, f64>>(bars_height: A) -> Self {
+        Self::new(bars_height.into().iter().copied().collect())
+    }
+}
+
+impl Histogram {
+    /// Create an histogram from the values of the array and buckets in which the values will be
+    /// sorted. See [`Histogram::new`].
+    pub fn from_array<'a, A: AsArray<'a, f64>>(
+        values: A,
+        buckets_range: Vec<ops::Range<f64>>,
+    ) -> Self {
+        Self::new(values.into().iter().copied().collect(), buckets_range)
+    }
+}
+
+impl Heatmap {
+    /// Create a new heatmap from a 2D array. `values[[row, column]]` is the value of a cell.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ndarray::Array2;
+    /// use termplot::*;
+    ///
+    /// let values = Array2::from_shape_fn((10, 10), |(y, x)| ((x * y) as f64).sqrt());
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..10.0))
+    ///     .set_codomain(Domain(0.0..10.0))
+    ///     .add_plot(Box::new(plot::Heatmap::from_array(&values)));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn from_array<'a, A: AsArray<'a, f64, Ix2>>(values: A) -> Self {
+        let values = values.into();
+        Self::new(
+            values
+                .rows()
+                .into_iter()
+                .map(|row| row.iter().copied().collect())
+                .collect(),
+        )
+    }
+}
+
+fn zip<'a, X, Y>(x: X, y: Y) -> Vec<(f64, f64)>
+where
+    X: AsArray<'a, f64>,
+    Y: AsArray<'a, f64>,
+{
+    let (x, y) = (x.into(), y.into());
+    x.iter().copied().zip(y.iter().copied()).collect()
+}
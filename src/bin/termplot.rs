@@ -0,0 +1,355 @@
+//! Plot numbers read from the standard input.
+//!
+//! Each line of the input is a record of numbers separated by commas, semicolons, tabs or
+//! spaces. With a single column, the values are plotted against their index. With more columns,
+//! the first column is the x value and every other column is a series. Lines that contain no
+//! number (a CSV header for example) are skipped.
+//!
+//! ```text
+//! seq 1 100 | awk '{ print sin($1 / 10) }' | termplot --title "sin(x)"
+//! ```
+use std::io::{self, BufRead};
+use std::process;
+use termplot::{plot, Domain, Plot, Size};
+
+const USAGE: &str = "\
+Usage: termplot [OPTIONS] < DATA
+
+Plot numbers or CSV records read from the standard input.
+
+Options:
+  -k, --kind <KIND>         lines, scatter, bars or histogram [default: lines]
+  -b, --buckets <COUNT>     number of buckets of the histogram [default: 10]
+  -d, --domain <MIN:MAX>    range of the x axis [default: range of the data]
+  -c, --codomain <MIN:MAX>  range of the y axis [default: range of the data]
+  -s, --size <WxH>          size of the view, in pixels [default: 100x60]
+  -t, --title <TITLE>       title of the plot
+  -x, --x-label <LABEL>     label of the x axis
+  -y, --y-label <LABEL>     label of the y axis
+  -h, --help                print this message";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    Lines,
+    Scatter,
+    Bars,
+    Histogram,
+}
+
+struct Options {
+    kind: Kind,
+    buckets: u32,
+    domain: Option<Domain>,
+    codomain: Option<Domain>,
+    size: Size,
+    title: String,
+    x_label: String,
+    y_label: String,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            kind: Kind::Lines,
+            buckets: 10,
+            domain: None,
+            codomain: None,
+            size: Size::new(100, 60),
+            title: String::new(),
+            x_label: String::new(),
+            y_label: String::new(),
+        }
+    }
+}
+
+impl Options {
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Self, String> {
+        let mut options = Self::default();
+        while let Some(arg) = args.next() {
+            if arg == "-h" || arg == "--help" {
+                println!("{USAGE}");
+                process::exit(0);
+            }
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for `{arg}`"))
+            };
+            match arg.as_str() {
+                "-k" | "--kind" => options.kind = parse_kind(&value()?)?,
+                "-b" | "--buckets" => match parse_number(&value()?)? {
+                    0 => return Err(String::from("`--buckets` must be at least 1")),
+                    buckets => options.buckets = buckets,
+                },
+                "-d" | "--domain" => options.domain = Some(parse_domain(&value()?)?),
+                "-c" | "--codomain" => options.codomain = Some(parse_domain(&value()?)?),
+                "-s" | "--size" => options.size = parse_size(&value()?)?,
+                "-t" | "--title" => options.title = value()?,
+                "-x" | "--x-label" => options.x_label = value()?,
+                "-y" | "--y-label" => options.y_label = value()?,
+                _ => return Err(format!("unknown option `{arg}`")),
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn parse_kind(value: &str) -> Result<Kind, String> {
+    match value {
+        "lines" | "line" => Ok(Kind::Lines),
+        "scatter" | "points" => Ok(Kind::Scatter),
+        "bars" | "bar" => Ok(Kind::Bars),
+        "histogram" | "hist" => Ok(Kind::Histogram),
+        _ => Err(format!("unknown kind of plot `{value}`")),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid number `{value}`"))
+}
+
+fn parse_domain(value: &str) -> Result<Domain, String> {
+    let (min, max) = value
+        .split_once(':')
+        .ok_or_else(|| format!("invalid range `{value}`, expected `MIN:MAX`"))?;
+    Ok(Domain(parse_number(min)?..parse_number(max)?))
+}
+
+fn parse_size(value: &str) -> Result<Size, String> {
+    let (w, h) = value
+        .split_once('x')
+        .ok_or_else(|| format!("invalid size `{value}`, expected `WxH`"))?;
+    Ok(Size::new(parse_number(w)?, parse_number(h)?))
+}
+
+/// Read the records of numbers from the input. Values that are not numbers are skipped.
+fn read_records<R: BufRead>(input: R) -> io::Result<Vec<Vec<f64>>> {
+    let mut records = Vec::new();
+    for line in input.lines() {
+        let record = line?
+            .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+            .filter_map(|value| value.trim().parse::<f64>().ok())
+            .collect::<Vec<_>>();
+        if !record.is_empty() {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+/// Split the records in series of `(x, y)` points.
+fn series(records: &[Vec<f64>]) -> Vec<Vec<(f64, f64)>> {
+    let columns = records.iter().map(|r| r.len()).max().unwrap_or(0);
+    if columns <= 1 {
+        let points = records
+            .iter()
+            .enumerate()
+            .map(|(index, record)| (index as f64, record[0]))
+            .collect();
+        return vec![points];
+    }
+    (1..columns)
+        .map(|column| {
+            records
+                .iter()
+                .filter_map(|record| Some((record[0], *record.get(column)?)))
+                .collect()
+        })
+        .collect()
+}
+
+/// A range containing every value, with a small margin so that the extremes do not touch the
+/// frame.
+fn bounds<I: Iterator<Item = f64>>(values: I) -> Domain {
+    let (min, max) = values
+        .filter(|value| value.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+    if min > max {
+        return Domain(0.0..1.0);
+    }
+    let margin = match max - min {
+        range if range <= 0.0 => 1.0,
+        range => range * 0.05,
+    };
+    Domain(min - margin..max + margin)
+}
+
+/// The number of values in the tallest bucket of an histogram with `count` buckets of the same
+/// width.
+fn tallest_bucket(values: &[f64], count: u32) -> usize {
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let width = (max - min) / count as f64;
+    let mut buckets = vec![0; count as usize];
+    for value in values.iter() {
+        let index = ((value - min) / width).floor();
+        if index >= 0.0 && (index as usize) < buckets.len() {
+            buckets[index as usize] += 1;
+        }
+    }
+    buckets.into_iter().max().unwrap_or(0)
+}
+
+fn build_plot(options: Options, records: &[Vec<f64>]) -> Plot {
+    let mut plot = Plot::default();
+    plot.set_title(&options.title)
+        .set_x_label(&options.x_label)
+        .set_y_label(&options.y_label)
+        .set_size(options.size);
+
+    let series = series(records);
+    let (domain, codomain) = match options.kind {
+        Kind::Lines | Kind::Scatter => {
+            for points in series.iter() {
+                if options.kind == Kind::Lines {
+                    plot.add_plot(Box::new(plot::Lines::new(points.clone())));
+                } else {
+                    plot.add_plot(Box::new(plot::Scatter::new(points.clone())));
+                }
+            }
+            let points = series.iter().flatten();
+            (
+                bounds(points.clone().map(|&(x, _)| x)),
+                bounds(points.map(|&(_, y)| y)),
+            )
+        }
+        Kind::Bars => {
+            let heights = series[0].iter().map(|&(_, y)| y).collect::<Vec<_>>();
+            let codomain = bounds(heights.iter().copied().chain([0.0]));
+            let domain = Domain(-0.5..heights.len() as f64 + 0.5);
+            plot.add_plot(Box::new(plot::Bars::new(heights)));
+            (domain, codomain)
+        }
+        Kind::Histogram => {
+            let values = series[0].iter().map(|&(_, y)| y).collect::<Vec<_>>();
+            let domain = bounds(values.iter().copied());
+            let tallest = tallest_bucket(&values, options.buckets);
+            let codomain = Domain(0.0..(tallest as f64 * 1.1).max(1.0));
+            plot.add_plot(Box::new(plot::Histogram::new_with_buckets_count(
+                values,
+                options.buckets,
+            )));
+            (domain, codomain)
+        }
+    };
+    plot.set_domain(options.domain.unwrap_or(domain))
+        .set_codomain(options.codomain.unwrap_or(codomain));
+    plot
+}
+
+fn main() {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            process::exit(2);
+        }
+    };
+    let records = match read_records(io::stdin().lock()) {
+        Ok(records) => records,
+        Err(error) => {
+            eprintln!("error: could not read the input: {error}");
+            process::exit(1);
+        }
+    };
+    if records.is_empty() {
+        eprintln!("error: the input does not contain any number");
+        process::exit(1);
+    }
+    match build_plot(options, &records).try_render() {
+        Ok(plot) => print!("{plot}"),
+        Err(error) => {
+            eprintln!("error: {error}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn options() {
+        let options = parse(&[
+            "-k",
+            "hist",
+            "--buckets",
+            "5",
+            "-d",
+            "-1:2.5",
+            "-s",
+            "40x20",
+        ])
+        .unwrap();
+        assert_eq!(options.kind, Kind::Histogram);
+        assert_eq!(options.buckets, 5);
+        assert_eq!(options.domain, Some(Domain(-1.0..2.5)));
+        assert_eq!(options.codomain, None);
+        assert_eq!(options.size, Size::new(40, 20));
+        let options = parse(&["--title", "a b", "-x", "time", "-y", "value"]).unwrap();
+        assert_eq!(options.kind, Kind::Lines);
+        assert_eq!(
+            (options.title, options.x_label, options.y_label),
+            ("a b".into(), "time".into(), "value".into())
+        );
+
+        assert_eq!(
+            parse(&["-b", "0"]).err().unwrap(),
+            "`--buckets` must be at least 1"
+        );
+        assert_eq!(parse(&["-b"]).err().unwrap(), "missing value for `-b`");
+        assert_eq!(
+            parse(&["-k", "pie"]).err().unwrap(),
+            "unknown kind of plot `pie`"
+        );
+        assert_eq!(
+            parse(&["-s", "40"]).err().unwrap(),
+            "invalid size `40`, expected `WxH`"
+        );
+        assert_eq!(parse(&["-c", "a:1"]).err().unwrap(), "invalid number `a`");
+        assert_eq!(
+            parse(&["--color"]).err().unwrap(),
+            "unknown option `--color`"
+        );
+    }
+
+    #[test]
+    fn records_and_series() {
+        let input = "x,a,b\n0,1,2\n\n1;3\t4\n2 5\n";
+        let records = read_records(input.as_bytes()).unwrap();
+        assert_eq!(
+            records,
+            [vec![0.0, 1.0, 2.0], vec![1.0, 3.0, 4.0], vec![2.0, 5.0]]
+        );
+        // The first column is x, and the records missing a column are skipped in its series.
+        assert_eq!(
+            series(&records),
+            [
+                vec![(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)],
+                vec![(0.0, 2.0), (1.0, 4.0)]
+            ]
+        );
+        // A single column is plotted against the index of each value.
+        let records = read_records("4\n-1\n".as_bytes()).unwrap();
+        assert_eq!(series(&records), [vec![(0.0, 4.0), (1.0, -1.0)]]);
+    }
+
+    #[test]
+    fn tallest_buckets() {
+        let values = [0.0, 1.0, 1.5, 2.0, 9.0, 10.0];
+        // Like the buckets of the histogram, the ranges do not include their end.
+        assert_eq!(tallest_bucket(&values, 5), 3);
+        assert_eq!(tallest_bucket(&values, 10), 2);
+        assert_eq!(tallest_bucket(&values, 1), 5);
+        assert_eq!(tallest_bucket(&[], 3), 0);
+    }
+}
@@ -0,0 +1,193 @@
+/// The characters used to draw the border around a [`Plot`](crate::Plot). See
+/// [`Plot::set_border`](crate::Plot::set_border).
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut plot = Plot::default();
+/// plot.set_title("Graph title")
+///     .set_border(Border::Ascii)
+///     .set_size(Size::new(50, 25))
+///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
+///
+/// println!("{plot}");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Border {
+    /// `╭─╮` with rounded corners.
+    #[default]
+    Rounded,
+    /// `┌─┐` with square corners.
+    Square,
+    /// `╔═╗` with double lines.
+    Double,
+    /// `┏━┓` with heavy lines.
+    Heavy,
+    /// `+-+` with ASCII characters only.
+    Ascii,
+    /// No border at all.
+    None,
+}
+
+/// Which sides of the border are drawn. All the sides are drawn by default.
+///
+/// See [`Plot::set_border_sides`](crate::Plot::set_border_sides).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BorderSides {
+    pub top: bool,
+    pub right: bool,
+    pub bottom: bool,
+    pub left: bool,
+}
+
+impl Default for BorderSides {
+    fn default() -> Self {
+        Self {
+            top: true,
+            right: true,
+            bottom: true,
+            left: true,
+        }
+    }
+}
+
+impl BorderSides {
+    /// No side is drawn.
+    pub(crate) const NONE: Self = Self {
+        top: false,
+        right: false,
+        bottom: false,
+        left: false,
+    };
+}
+
+/// The characters of a border: the corners from the top left to the bottom left (clockwise),
+/// then the horizontal and the vertical lines.
+#[derive(Clone, Copy)]
+pub(crate) struct BorderChars {
+    pub corners: [char; 4],
+    pub horizontal: char,
+    pub vertical: char,
+}
+
+impl Border {
+    pub(crate) fn chars(&self) -> BorderChars {
+        let (corners, horizontal, vertical) = match self {
+            Self::Rounded => (['╭', '╮', '╯', '╰'], '─', '│'),
+            Self::Square => (['┌', '┐', '┘', '└'], '─', '│'),
+            Self::Double => (['╔', '╗', '╝', '╚'], '═', '║'),
+            Self::Heavy => (['┏', '┓', '┛', '┗'], '━', '┃'),
+            Self::Ascii => (['+', '+', '+', '+'], '-', '|'),
+            Self::None => ([' '; 4], ' ', ' '),
+        };
+        BorderChars {
+            corners,
+            horizontal,
+            vertical,
+        }
+    }
+}
+
+/// A border drawn around the lines of a figure.
+pub(crate) struct Frame {
+    chars: BorderChars,
+    sides: BorderSides,
+}
+
+impl Frame {
+    pub(crate) fn new(border: Border, sides: BorderSides) -> Self {
+        let sides = match border {
+            Border::None => BorderSides::NONE,
+            _ => sides,
+        };
+        Self {
+            chars: border.chars(),
+            sides,
+        }
+    }
+
+    pub(crate) fn sides(&self) -> BorderSides {
+        self.sides
+    }
+
+    pub(crate) fn horizontal(&self) -> char {
+        self.chars.horizontal
+    }
+
+    /// The number of lines of `rows` surrounded with the border.
+    pub(crate) fn height(&self, rows: usize) -> usize {
+        rows + self.sides.top as usize + self.sides.bottom as usize
+    }
+
+    /// Write `rows` surrounded with the border to `out`, each line starting with the line of
+    /// `left` at the same index (if any) and ending with a new line. `top` is the content of the
+    /// top border (usually the title filled with [`Frame::horizontal`]) and is only used when the
+    /// top side is drawn.
+    pub(crate) fn write(
+        &self,
+        out: &mut String,
+        top: &str,
+        rows: &[String],
+        width: usize,
+        left: &[String],
+    ) {
+        let [top_left, top_right, bottom_right, bottom_left] = self.chars.corners;
+        let vertical = self.chars.vertical;
+        let mut left = left.iter();
+        let mut line = |start: char, content: &str, end: char| {
+            if let Some(left) = left.next() {
+                out.push_str(left);
+            }
+            if self.sides.left {
+                out.push(start);
+            }
+            out.push_str(content);
+            if self.sides.right {
+                out.push(end);
+            }
+            out.push('\n');
+        };
+        if self.sides.top {
+            line(top_left, top, top_right);
+        }
+        for row in rows {
+            line(vertical, row, vertical);
+        }
+        if self.sides.bottom {
+            let bottom = self.chars.horizontal.to_string().repeat(width);
+            line(bottom_left, &bottom, bottom_right);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn framed(border: Border, sides: BorderSides) -> String {
+        let mut out = String::new();
+        let frame = Frame::new(border, sides);
+        let top = frame.horizontal().to_string().repeat(2);
+        let rows = [String::from("ab"), String::from("cd")];
+        frame.write(&mut out, &top, &rows, 2, &[]);
+        out
+    }
+
+    #[test]
+    fn styles_and_sides() {
+        let all = BorderSides::default();
+        assert_eq!(framed(Border::Square, all), "┌──┐\n│ab│\n│cd│\n└──┘\n");
+        assert_eq!(framed(Border::Double, all), "╔══╗\n║ab║\n║cd║\n╚══╝\n");
+        let sides = BorderSides {
+            top: false,
+            right: false,
+            ..all
+        };
+        assert_eq!(framed(Border::Ascii, sides), "|ab\n|cd\n+--\n");
+        assert_eq!(Frame::new(Border::Ascii, sides).height(2), 3);
+        // Without a border, no side is drawn.
+        assert_eq!(framed(Border::None, all), "ab\ncd\n");
+    }
+}
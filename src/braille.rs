@@ -0,0 +1,263 @@
+//! A canvas of [braille characters](https://en.wikipedia.org/wiki/Braille_Patterns): each
+//! character holds 2 by 4 pixels (its dots) and a color.
+
+use crate::color::Ink;
+use crate::{Color, Fill};
+use std::ops;
+
+/// The bit of each dot of a braille character, by row and column of the dot in the character.
+const DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// A character of the canvas.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Cell {
+    /// The dots set, one bit per dot (see [`DOTS`]).
+    dots: u8,
+    /// A character written in the cell. It is shown while no dot of the cell is set.
+    char: Option<char>,
+    /// The color of the dots. `None` is the default color of the terminal.
+    color: Option<Color>,
+    /// The color of the background of the cell, over the background of the canvas.
+    background: Option<Color>,
+    /// Whether the cell is drawn in reverse video: with the colors of its dots and its
+    /// background swapped.
+    inverse: bool,
+}
+
+impl Cell {
+    /// Whether nothing is drawn in the cell.
+    fn is_empty(&self) -> bool {
+        self.dots == 0 && self.char.is_none()
+    }
+
+    /// The character showing the cell.
+    fn symbol(&self) -> char {
+        match (self.dots, self.char) {
+            (0, Some(char)) => char,
+            (0, None) => ' ',
+            (dots, _) => char::from_u32(0x2800 + dots as u32).unwrap_or(' '),
+        }
+    }
+}
+
+/// A grid of braille characters, with one color per character, drawn on a background.
+///
+/// The rows are rendered as text on demand, and kept: rendering the canvas again only renders the
+/// rows changed since. Cloning a canvas keeps its rendered rows, so that a canvas drawn once and
+/// cloned for every frame only renders the rows changed by each frame.
+#[derive(Clone, Debug)]
+pub(crate) struct Canvas {
+    columns: usize,
+    cells: Vec<Cell>,
+    /// The text of each row, valid for the rows that are not dirty.
+    rendered: Vec<String>,
+    /// Whether each row changed since it was rendered.
+    dirty: Vec<bool>,
+    /// The background of the rendered rows.
+    background: Fill,
+    /// Whether the rendered rows have their colors.
+    colors: bool,
+}
+
+impl Canvas {
+    /// Create an empty canvas of `width` by `height` pixels, rounded up to whole characters.
+    pub(crate) fn new(width: usize, height: usize) -> Self {
+        let (columns, rows) = (width.div_ceil(2), height.div_ceil(4));
+        Self {
+            columns,
+            cells: vec![Cell::default(); columns * rows],
+            rendered: vec![String::new(); rows],
+            dirty: vec![true; rows],
+            background: Fill::None,
+            colors: false,
+        }
+    }
+
+    /// The index of the cell holding the pixel (`x`, `y`) and the bit of the pixel in the cell,
+    /// or `None` when the pixel is outside of the canvas.
+    fn locate(&self, x: u32, y: u32) -> Option<(usize, u8)> {
+        let (column, row) = (x as usize / 2, y as usize / 4);
+        if column >= self.columns || row >= self.dirty.len() {
+            return None;
+        }
+        Some((
+            row * self.columns + column,
+            DOTS[y as usize % 4][x as usize % 2],
+        ))
+    }
+
+    /// Set the pixel (`x`, `y`), giving `color` to its character. Pixels outside of the canvas
+    /// are ignored.
+    pub(crate) fn set(&mut self, x: u32, y: u32, color: Option<Color>) {
+        if let Some((index, dot)) = self.locate(x, y) {
+            let cell = &mut self.cells[index];
+            cell.dots |= dot;
+            cell.char = None;
+            cell.color = color;
+            self.dirty[index / self.columns] = true;
+        }
+    }
+
+    /// Write `char` in the character holding the pixel (`x`, `y`), clearing its dots.
+    pub(crate) fn set_char(&mut self, x: u32, y: u32, char: char) {
+        if let Some((index, _)) = self.locate(x, y) {
+            let cell = &mut self.cells[index];
+            *cell = Cell {
+                dots: 0,
+                char: Some(char),
+                color: None,
+                ..*cell
+            };
+            self.dirty[index / self.columns] = true;
+        }
+    }
+
+    /// Whether the pixel (`x`, `y`) is set.
+    #[cfg(any(feature = "image", test))]
+    pub(crate) fn get(&self, x: u32, y: u32) -> bool {
+        self.locate(x, y)
+            .is_some_and(|(index, dot)| self.cells[index].dots & dot != 0)
+    }
+
+    /// Set the pixels of the line from `from` to `to`, stepping one pixel at a time along the
+    /// longest axis.
+    pub(crate) fn line(&mut self, from: (u32, u32), to: (u32, u32), color: Option<Color>) {
+        let (dx, dy) = (from.0.abs_diff(to.0), from.1.abs_diff(to.1));
+        let steps = dx.max(dy);
+        let along = |from: u32, to: u32, difference: u32, step: u32| match difference {
+            0 => from,
+            _ if from <= to => from + step * difference / steps,
+            _ => from - step * difference / steps,
+        };
+        for step in 0..=steps {
+            let x = along(from.0, to.0, dx, step);
+            let y = along(from.1, to.1, dy, step);
+            self.set(x, y, color);
+        }
+    }
+
+    /// Highlight the cells from `columns` and `rows`: with a background of `color`, or in reverse
+    /// video when the color is `None`. The cells outside of the canvas are ignored.
+    pub(crate) fn highlight(
+        &mut self,
+        columns: ops::Range<usize>,
+        rows: ops::Range<usize>,
+        color: Option<Color>,
+    ) {
+        let columns = columns.start..columns.end.min(self.columns);
+        let rows = rows.start..rows.end.min(self.dirty.len());
+        for row in rows {
+            for column in columns.clone() {
+                let cell = &mut self.cells[row * self.columns + column];
+                match color {
+                    Some(color) => cell.background = Some(color),
+                    None => cell.inverse = true,
+                }
+            }
+            self.dirty[row] = true;
+        }
+    }
+
+    /// The text of each row of the canvas drawn on `background`, from the top, with its colors
+    /// when `colors` is `true`. Only the rows changed since the last call are rendered again,
+    /// unless the background or the colors changed.
+    pub(crate) fn rows(&mut self, background: Fill, colors: bool) -> &[String] {
+        if background != self.background || colors != self.colors {
+            (self.background, self.colors) = (background, colors);
+            self.dirty.fill(true);
+        }
+        let background_color = match background {
+            Fill::Color(color) => Some(color),
+            _ => None,
+        };
+        for (index, dirty) in self.dirty.iter_mut().enumerate() {
+            if !*dirty {
+                continue;
+            }
+            let row = &mut self.rendered[index];
+            row.clear();
+            let cells = &self.cells[index * self.columns..(index + 1) * self.columns];
+            for cell in cells {
+                let (symbol, faint) = match background {
+                    Fill::Char(fill) if cell.is_empty() => (fill, true),
+                    _ => (cell.symbol(), false),
+                };
+                let ink = Ink {
+                    color: cell.color.filter(|_| cell.dots != 0),
+                    background: cell.background.or(background_color),
+                    dimmed: faint,
+                    reversed: cell.inverse,
+                    ..Ink::default()
+                };
+                match colors && ink != Ink::default() {
+                    true => row.push_str(&ink.paint(symbol.encode_utf8(&mut [0; 4]), colors)),
+                    false => row.push(symbol),
+                }
+            }
+            *dirty = false;
+        }
+        &self.rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text;
+
+    #[test]
+    fn dots_and_chars() {
+        let mut canvas = Canvas::new(3, 5);
+        canvas.set(0, 0, None);
+        canvas.set(1, 3, None);
+        canvas.set(2, 4, None);
+        canvas.set(4, 8, None);
+        assert!(canvas.get(1, 3) && !canvas.get(1, 2));
+        assert_eq!(canvas.rows(Fill::None, false), ["⢁ ", " ⠁"]);
+        canvas.set_char(2, 0, 'a');
+        assert_eq!(canvas.rows(Fill::None, false), ["⢁a", " ⠁"]);
+        canvas.set(3, 1, None);
+        assert_eq!(canvas.rows(Fill::None, false), ["⢁⠐", " ⠁"]);
+    }
+
+    #[test]
+    fn lines() {
+        let mut canvas = Canvas::new(8, 4);
+        canvas.line((0, 0), (7, 3), None);
+        canvas.line((0, 3), (0, 3), None);
+        assert_eq!(canvas.rows(Fill::None, false), ["⡉⠑⠢⢄"]);
+    }
+
+    #[test]
+    fn highlighted_cells() {
+        let mut canvas = Canvas::new(6, 8);
+        canvas.highlight(1..5, 1..2, None);
+        canvas.set_char(2, 4, 'a');
+        let inverse = canvas
+            .cells
+            .iter()
+            .map(|cell| cell.inverse)
+            .collect::<Vec<_>>();
+        assert_eq!(inverse, [false, false, false, false, true, true]);
+        assert_eq!(canvas.cells[4].char, Some('a'));
+    }
+
+    #[test]
+    fn dirty_rows() {
+        let mut canvas = Canvas::new(2, 8);
+        canvas.set(0, 0, None);
+        canvas.rows(Fill::None, false);
+        canvas.rendered[1] = String::from("stale");
+        canvas.set(0, 1, None);
+        assert_eq!(canvas.rows(Fill::None, false), ["⠃", "stale"]);
+        let rows = canvas.rows(Fill::Char('·'), false);
+        assert_eq!(
+            rows.iter()
+                .map(|row| text::strip_escapes(row))
+                .collect::<Vec<_>>(),
+            ["⠃", "·"]
+        );
+        // The rows are rendered again with their colors.
+        assert!(canvas.rows(Fill::Char('·'), true)[1].contains('\x1b'));
+    }
+}
@@ -0,0 +1,119 @@
+//! Clipping of lines to the view with the
+//! [Cohen–Sutherland algorithm](https://en.wikipedia.org/wiki/Cohen%E2%80%93Sutherland_algorithm).
+
+const INSIDE: u8 = 0;
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const BOTTOM: u8 = 4;
+const TOP: u8 = 8;
+
+/// A rectangle to which lines are clipped.
+pub(crate) struct ClipRect {
+    pub x_min: f64,
+    pub x_max: f64,
+    pub y_min: f64,
+    pub y_max: f64,
+}
+
+impl ClipRect {
+    /// On which sides of the rectangle the point (`x`, `y`) is.
+    fn outcode(&self, x: f64, y: f64) -> u8 {
+        let mut code = INSIDE;
+        if x < self.x_min {
+            code |= LEFT;
+        } else if x > self.x_max {
+            code |= RIGHT;
+        }
+        if y < self.y_min {
+            code |= BOTTOM;
+        } else if y > self.y_max {
+            code |= TOP;
+        }
+        code
+    }
+
+    /// Clip the line from (`x0`, `y0`) to (`x1`, `y1`) to the rectangle. Returns `None` when no
+    /// part of the line is inside of the rectangle or when a coordinate is `NaN`.
+    pub(crate) fn line(
+        &self,
+        (mut x0, mut y0): (f64, f64),
+        (mut x1, mut y1): (f64, f64),
+    ) -> Option<((f64, f64), (f64, f64))> {
+        if [x0, y0, x1, y1].iter().any(|value| value.is_nan()) {
+            return None;
+        }
+        let mut code0 = self.outcode(x0, y0);
+        let mut code1 = self.outcode(x1, y1);
+        loop {
+            if code0 | code1 == INSIDE {
+                return Some(((x0, y0), (x1, y1)));
+            }
+            if code0 & code1 != INSIDE {
+                return None;
+            }
+            // Move the endpoint outside of the rectangle to the edge it crosses.
+            let code = code0.max(code1);
+            let (x, y) = if code & TOP != 0 {
+                (x0 + (x1 - x0) * (self.y_max - y0) / (y1 - y0), self.y_max)
+            } else if code & BOTTOM != 0 {
+                (x0 + (x1 - x0) * (self.y_min - y0) / (y1 - y0), self.y_min)
+            } else if code & RIGHT != 0 {
+                (self.x_max, y0 + (y1 - y0) * (self.x_max - x0) / (x1 - x0))
+            } else {
+                (self.x_min, y0 + (y1 - y0) * (self.x_min - x0) / (x1 - x0))
+            };
+            // An infinite endpoint gives a NaN intersection.
+            if x.is_nan() || y.is_nan() {
+                return None;
+            }
+            if code == code0 {
+                (x0, y0) = (x, y);
+                code0 = self.outcode(x0, y0);
+            } else {
+                (x1, y1) = (x, y);
+                code1 = self.outcode(x1, y1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECT: ClipRect = ClipRect {
+        x_min: 0.0,
+        x_max: 10.0,
+        y_min: 0.0,
+        y_max: 10.0,
+    };
+
+    #[test]
+    fn inside() {
+        let line = ((1.0, 2.0), (3.0, 4.0));
+        assert_eq!(RECT.line(line.0, line.1), Some(line));
+    }
+
+    #[test]
+    fn outside() {
+        assert_eq!(RECT.line((-1.0, 20.0), (11.0, 20.0)), None);
+        assert_eq!(RECT.line((-5.0, 1.0), (1.0, -5.0)), None);
+        assert_eq!(RECT.line((f64::NAN, 1.0), (1.0, 1.0)), None);
+    }
+
+    #[test]
+    fn crossing() {
+        assert_eq!(
+            RECT.line((5.0, 5.0), (5.0, 100.0)),
+            Some(((5.0, 5.0), (5.0, 10.0)))
+        );
+        assert_eq!(
+            RECT.line((-10.0, 5.0), (20.0, 5.0)),
+            Some(((0.0, 5.0), (10.0, 5.0)))
+        );
+        assert_eq!(
+            RECT.line((-1.0, 9.0), (1.0, 11.0)),
+            Some(((0.0, 10.0), (0.0, 10.0)))
+        );
+    }
+}
@@ -0,0 +1,373 @@
+//! Colors used to draw plots and decorations.
+
+use crate::Domain;
+
+/// A color of the terminal.
+///
+/// The named colors use the palette of the terminal. [`Color::Rgb`] is drawn as is on terminals
+/// supporting true colors (when the `COLORTERM` environment variable is `truecolor` or `24bit`),
+/// and as the closest color of the 256 colors palette on the others.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// A true color with its red, green and blue components.
+    Rgb(u8, u8, u8),
+}
+
+/// The colors given to each series when no color is specified.
+pub(crate) const PALETTE: [Color; 6] = [
+    Color::Blue,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Cyan,
+];
+
+impl Color {
+    /// Draw `text` with this color, when `colors` is `true`. See [`Ink::paint`].
+    pub(crate) fn paint(&self, text: &str, colors: bool) -> String {
+        let ink = Ink {
+            color: Some(*self),
+            ..Ink::default()
+        };
+        ink.paint(text, colors)
+    }
+}
+
+/// Whether colors are written when rendering for the terminal: when the output supports them,
+/// unless it is overridden (see the [`colored`] crate for the environment variables and the
+/// functions controlling this).
+pub(crate) fn enabled() -> bool {
+    colored::control::SHOULD_COLORIZE.should_colorize()
+}
+
+/// The colors and the style of some text.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Ink {
+    pub color: Option<Color>,
+    pub background: Option<Color>,
+    pub bold: bool,
+    pub dimmed: bool,
+    /// Whether the colors of the text and of its background are swapped.
+    pub reversed: bool,
+}
+
+impl Ink {
+    /// Draw `text` with the colors and the style, written as ANSI escape sequences when `colors`
+    /// is `true`. Rendering decides once whether colors are written, and passes it down, so that
+    /// a plot can be rendered with colors for a file while others are printed without them.
+    pub(crate) fn paint(&self, text: &str, colors: bool) -> String {
+        let mut codes: Vec<std::borrow::Cow<str>> = Vec::new();
+        for (set, code) in [(self.bold, "1"), (self.dimmed, "2"), (self.reversed, "7")] {
+            if set {
+                codes.push(code.into());
+            }
+        }
+        if let Some(background) = self.background {
+            codes.push(parameters(background, true));
+        }
+        if let Some(color) = self.color {
+            codes.push(parameters(color, false));
+        }
+        match colors && !codes.is_empty() {
+            true => format!("\x1b[{}m{text}\x1b[0m", codes.join(";")),
+            false => text.to_string(),
+        }
+    }
+}
+
+/// The SGR parameters drawing `color` as the color of the text, or of the `background`.
+fn parameters(color: Color, background: bool) -> std::borrow::Cow<'static, str> {
+    let Color::Rgb(r, g, b) = color else {
+        let color = colored::Color::from(color);
+        return match background {
+            true => color.to_bg_str(),
+            false => color.to_fg_str(),
+        };
+    };
+    let layer = if background { 48 } else { 38 };
+    let truecolor =
+        std::env::var("COLORTERM").is_ok_and(|term| term == "truecolor" || term == "24bit");
+    match truecolor {
+        true => format!("{layer};2;{r};{g};{b}").into(),
+        false => format!("{layer};5;{}", ansi_256((r, g, b))).into(),
+    }
+}
+
+/// The index of the color of the 256 colors palette closest to `rgb`, in the 6x6x6 color cube
+/// (`16` to `231`) or in the grayscale ramp (`232` to `255`). The first 16 colors are skipped,
+/// as terminals change them with their theme.
+pub(crate) fn ansi_256(rgb: (u8, u8, u8)) -> u8 {
+    let level = |component: u8| match component {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (component - 35) / 40,
+    };
+    let cube = 16 + 36 * level(rgb.0) + 6 * level(rgb.1) + level(rgb.2);
+    let average = (rgb.0 as u16 + rgb.1 as u16 + rgb.2 as u16) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+    let distance = |index: u8| {
+        let (r, g, b) = xterm_256(index);
+        let square = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        square(r, rgb.0) + square(g, rgb.1) + square(b, rgb.2)
+    };
+    match distance(gray) < distance(cube) {
+        true => gray,
+        false => cube,
+    }
+}
+
+/// The red, green and blue components of the color `index` of the 256 colors palette, from the
+/// color cube or the grayscale ramp. The first 16 colors are black.
+pub(crate) fn xterm_256(index: u8) -> (u8, u8, u8) {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    match index {
+        0..=15 => (0, 0, 0),
+        16..=231 => {
+            let index = index - 16;
+            let level = |step: u8| LEVELS[(index / step % 6) as usize];
+            (level(36), level(6), level(1))
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            (level, level, level)
+        }
+    }
+}
+
+/// A color with its red, green and blue components.
+#[cfg(any(feature = "sixel", feature = "kitty", feature = "iterm"))]
+pub(crate) type Rgb = (u8, u8, u8);
+
+/// The colors of the palette of the terminal (the ones of xterm), in the order of [`Color`].
+#[cfg(any(feature = "sixel", feature = "kitty", feature = "iterm"))]
+const XTERM: [Rgb; 16] = [
+    (0x00, 0x00, 0x00),
+    (0xcd, 0x00, 0x00),
+    (0x00, 0xcd, 0x00),
+    (0xcd, 0xcd, 0x00),
+    (0x00, 0x00, 0xee),
+    (0xcd, 0x00, 0xcd),
+    (0x00, 0xcd, 0xcd),
+    (0xe5, 0xe5, 0xe5),
+    (0x7f, 0x7f, 0x7f),
+    (0xff, 0x00, 0x00),
+    (0x00, 0xff, 0x00),
+    (0xff, 0xff, 0x00),
+    (0x5c, 0x5c, 0xff),
+    (0xff, 0x00, 0xff),
+    (0x00, 0xff, 0xff),
+    (0xff, 0xff, 0xff),
+];
+
+#[cfg(any(feature = "sixel", feature = "kitty", feature = "iterm"))]
+impl Color {
+    /// The red, green and blue components of the color, using the palette of xterm for the
+    /// named colors, to draw it in an image.
+    pub(crate) fn rgb(self) -> Rgb {
+        let index = match self {
+            Color::Rgb(r, g, b) => return (r, g, b),
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            Color::BrightBlack => 8,
+            Color::BrightRed => 9,
+            Color::BrightGreen => 10,
+            Color::BrightYellow => 11,
+            Color::BrightBlue => 12,
+            Color::BrightMagenta => 13,
+            Color::BrightCyan => 14,
+            Color::BrightWhite => 15,
+        };
+        XTERM[index]
+    }
+}
+
+impl From<Color> for colored::Color {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Black => Self::Black,
+            Color::Red => Self::Red,
+            Color::Green => Self::Green,
+            Color::Yellow => Self::Yellow,
+            Color::Blue => Self::Blue,
+            Color::Magenta => Self::Magenta,
+            Color::Cyan => Self::Cyan,
+            Color::White => Self::White,
+            Color::BrightBlack => Self::BrightBlack,
+            Color::BrightRed => Self::BrightRed,
+            Color::BrightGreen => Self::BrightGreen,
+            Color::BrightYellow => Self::BrightYellow,
+            Color::BrightBlue => Self::BrightBlue,
+            Color::BrightMagenta => Self::BrightMagenta,
+            Color::BrightCyan => Self::BrightCyan,
+            Color::BrightWhite => Self::BrightWhite,
+            Color::Rgb(r, g, b) => Self::TrueColor { r, g, b },
+        }
+    }
+}
+
+/// A map from values to colors, to show intensities with colors.
+///
+/// Apart from [`Colormap::Grayscale`], the maps are the perceptually uniform maps of
+/// [matplotlib](https://matplotlib.org/stable/users/explain/colors/colormaps.html): equal steps
+/// of the value look like equal steps of color. The colors are true colors ([`Color::Rgb`]). On
+/// terminals without true colors (when the `COLORTERM` environment variable is neither
+/// `truecolor` nor `24bit`), each color falls back to the closest color of the 256 colors
+/// palette.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+/// use termplot::color::Colormap;
+///
+/// let values = (0..10)
+///     .map(|y| (0..10).map(|x| ((x * y) as f64).sqrt()).collect())
+///     .collect();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..10.0))
+///     .set_codomain(Domain(0.0..10.0))
+///     .set_size(Size::new(60, 40))
+///     .add_plot(Box::new(
+///         plot::Heatmap::new(values).with_colormap(Colormap::Viridis),
+///     ));
+///
+/// println!("{plot}");
+/// assert_eq!(Colormap::Grayscale.color(1.0), Color::Rgb(255, 255, 255));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Colormap {
+    /// From dark blue through green to yellow.
+    #[default]
+    Viridis,
+    /// From dark blue through magenta to yellow.
+    Plasma,
+    /// From black through purple and orange to pale yellow.
+    Magma,
+    /// From black to white.
+    Grayscale,
+}
+
+impl Colormap {
+    /// The color of `fraction`, from `0.0` (the start of the map) to `1.0` (its end). Fractions
+    /// outside of this range are clamped, and `NaN` gives the start of the map.
+    pub fn color(&self, fraction: f64) -> Color {
+        let stops: &[(u8, u8, u8)] = match self {
+            Self::Viridis => &VIRIDIS,
+            Self::Plasma => &PLASMA,
+            Self::Magma => &MAGMA,
+            Self::Grayscale => &[(0, 0, 0), (255, 255, 255)],
+        };
+        let position = match fraction.is_nan() {
+            true => 0.0,
+            false => fraction.clamp(0.0, 1.0) * (stops.len() - 1) as f64,
+        };
+        let index = (position.floor() as usize).min(stops.len() - 2);
+        let t = position - index as f64;
+        let (from, to) = (stops[index], stops[index + 1]);
+        let mix = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+        Color::Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+    }
+
+    /// The color of `value` when the map is stretched over `range`.
+    pub fn color_in(&self, value: f64, range: &Domain) -> Color {
+        self.color((value - range.min()) / range.range())
+    }
+
+    /// A gradient coloring lines by their y value stretched over `range`, for
+    /// [`Graph::with_gradient`](crate::plot::Graph::with_gradient) and
+    /// [`Lines::with_gradient`](crate::plot::Lines::with_gradient).
+    pub fn gradient(self, range: Domain) -> impl Fn(f64, f64) -> Color {
+        move |_, y| self.color_in(y, &range)
+    }
+}
+
+/// Colors of the maps at 9 evenly spaced fractions, between which colors are interpolated.
+const VIRIDIS: [(u8, u8, u8); 9] = [
+    (0x44, 0x01, 0x54),
+    (0x47, 0x2d, 0x7b),
+    (0x3b, 0x52, 0x8b),
+    (0x2c, 0x72, 0x8e),
+    (0x21, 0x91, 0x8c),
+    (0x28, 0xae, 0x80),
+    (0x5e, 0xc9, 0x62),
+    (0xad, 0xdc, 0x30),
+    (0xfd, 0xe7, 0x25),
+];
+const PLASMA: [(u8, u8, u8); 9] = [
+    (0x0d, 0x08, 0x87),
+    (0x4c, 0x02, 0xa1),
+    (0x7e, 0x03, 0xa8),
+    (0xa9, 0x23, 0x95),
+    (0xcc, 0x47, 0x78),
+    (0xe6, 0x6c, 0x5c),
+    (0xf8, 0x95, 0x40),
+    (0xfd, 0xc5, 0x27),
+    (0xf0, 0xf9, 0x21),
+];
+const MAGMA: [(u8, u8, u8); 9] = [
+    (0x00, 0x00, 0x04),
+    (0x1c, 0x10, 0x44),
+    (0x4f, 0x12, 0x7b),
+    (0x81, 0x25, 0x81),
+    (0xb5, 0x36, 0x7a),
+    (0xe5, 0x50, 0x64),
+    (0xfb, 0x87, 0x61),
+    (0xfe, 0xc2, 0x87),
+    (0xfc, 0xfd, 0xbf),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closest_of_256_colors() {
+        assert_eq!(ansi_256((0, 0, 0)), 16);
+        assert_eq!(ansi_256((255, 255, 255)), 231);
+        assert_eq!(ansi_256((255, 0, 0)), 196);
+        assert_eq!(ansi_256((95, 135, 175)), 67);
+        // Grays are closer in the grayscale ramp than in the cube.
+        assert_eq!(ansi_256((128, 128, 128)), 244);
+        assert_eq!(ansi_256((0x44, 0x01, 0x54)), 53);
+        for index in 16..=255 {
+            assert_eq!(ansi_256(xterm_256(index)), index, "{index}");
+        }
+    }
+
+    #[test]
+    fn colormap_ends_and_interpolation() {
+        assert_eq!(Colormap::Viridis.color(0.0), Color::Rgb(0x44, 0x01, 0x54));
+        assert_eq!(Colormap::Viridis.color(1.0), Color::Rgb(0xfd, 0xe7, 0x25));
+        assert_eq!(Colormap::Viridis.color(2.0), Colormap::Viridis.color(1.0));
+        assert_eq!(Colormap::Magma.color(f64::NAN), Color::Rgb(0, 0, 4));
+        assert_eq!(Colormap::Grayscale.color(0.5), Color::Rgb(128, 128, 128));
+        assert_eq!(
+            Colormap::Grayscale.color_in(15.0, &Domain(10.0..30.0)),
+            Colormap::Grayscale.color(0.25)
+        );
+    }
+}
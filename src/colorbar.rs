@@ -0,0 +1,57 @@
+use crate::color::Colormap;
+use crate::ticks::YTicks;
+use crate::{text, Align, Domain, Scale, TickFormat};
+
+/// A vertical bar showing the colors of a colormap from the largest value (on the first row) to
+/// the smallest value (on the last row), with the values written next to it.
+pub(crate) struct Colorbar {
+    colormap: Colormap,
+    range: Domain,
+}
+
+impl Colorbar {
+    pub fn new(colormap: Colormap, range: Domain) -> Self {
+        Self { colormap, range }
+    }
+
+    /// The rows of the colorbar, written on the right of `row_count` rows of the view, with
+    /// `tick_count` values. Every row has the same width. The colors are only written when
+    /// `colors` is `true`.
+    pub fn rows(&self, row_count: usize, tick_count: usize, colors: bool) -> Vec<String> {
+        let ticks = YTicks::new(
+            &self.range,
+            Scale::Linear,
+            TickFormat::Decimal,
+            row_count,
+            tick_count,
+        );
+        let width = ticks.display_width();
+        let last_row = row_count.max(2) - 1;
+        (0..row_count)
+            .map(|row| {
+                let fraction = 1.0 - row as f64 / last_row as f64;
+                let bar = self.colormap.color(fraction).paint("██", colors);
+                let label = text::align(ticks.get(row), width, Align::Left, ' ');
+                format!(" {bar} {label}")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_from_the_largest_value() {
+        let rows = Colorbar::new(Colormap::Grayscale, Domain(0.0..10.0)).rows(5, 3, false);
+        assert_eq!(rows.len(), 5);
+        assert!(rows
+            .iter()
+            .all(|row| text::width(row) == text::width(&rows[0])));
+        assert!(rows[0].ends_with(" 10.0"));
+        assert!(rows[2].ends_with(" 5.0 "));
+        assert!(rows[4].ends_with(" 0.0 "));
+        assert!(rows[1].ends_with("     "));
+    }
+}
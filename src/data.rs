@@ -0,0 +1,6 @@
+//! Loading series from external sources.
+
+#[cfg(feature = "criterion")]
+pub mod criterion;
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
@@ -0,0 +1,218 @@
+//! Plotting the timings of [criterion](https://github.com/bheisler/criterion.rs) benchmarks.
+//!
+//! The timings are either loaded from the directory where criterion saves its results
+//! (`target/criterion`) with [`load`], or parsed from the JSON messages of `cargo criterion
+//! --message-format=json` with [`parse_messages`]. [`plot`] compares the benchmarks side by side:
+//! the time of each sample as a point, summarized by a box plot.
+//!
+//! Requires the `criterion` feature.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use termplot::data::criterion;
+//!
+//! let benchmarks = criterion::load("target/criterion").unwrap();
+//! println!("{}", criterion::plot(&benchmarks));
+//! ```
+
+use crate::plot::Strip;
+use crate::{Domain, Plot, Size};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The timings of a benchmark.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Benchmark {
+    /// The identifier of the benchmark, like `fibonacci/iterative/20`.
+    pub name: String,
+    /// The time of an iteration in each sample, in nanoseconds.
+    pub times: Vec<f64>,
+}
+
+/// An error while loading the timings of benchmarks.
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be read.
+    Io(io::Error),
+    /// A file or a message is not valid JSON.
+    Json(serde_json::Error),
+    /// A file or a message is valid JSON, but not the output of criterion.
+    Format(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read the benchmarks: {error}"),
+            Self::Json(error) => write!(f, "invalid benchmark output: {error}"),
+            Self::Format(error) => write!(f, "unexpected benchmark output: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Load the last timings of every benchmark saved in `directory`, usually `target/criterion`.
+///
+/// Criterion saves the samples of each benchmark in a `new/sample.json` file, next to a
+/// `new/benchmark.json` file holding its identifier. The benchmarks are sorted by name.
+pub fn load(directory: impl AsRef<Path>) -> Result<Vec<Benchmark>, Error> {
+    let mut benchmarks = Vec::new();
+    find(directory.as_ref(), directory.as_ref(), &mut benchmarks)?;
+    benchmarks.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(benchmarks)
+}
+
+/// Add the benchmarks found in `directory` and its subdirectories to `benchmarks`. Their name is
+/// their path from `root` when they have no `benchmark.json` file.
+fn find(root: &Path, directory: &Path, benchmarks: &mut Vec<Benchmark>) -> Result<(), Error> {
+    let samples = directory.join("new").join("sample.json");
+    if samples.is_file() {
+        let read = |path: &Path| fs::read_to_string(path).map_err(Error::Io);
+        let name = match read(&directory.join("new").join("benchmark.json")) {
+            Ok(json) => {
+                let benchmark =
+                    serde_json::from_str::<serde_json::Value>(&json).map_err(Error::Json)?;
+                benchmark["full_id"].as_str().map(str::to_string)
+            }
+            Err(_) => None,
+        };
+        let name = name.unwrap_or_else(|| {
+            let path = directory.strip_prefix(root).unwrap_or(directory);
+            path.to_string_lossy().replace('\\', "/")
+        });
+        benchmarks.push(parse_sample(&name, &read(&samples)?)?);
+        return Ok(());
+    }
+    for entry in fs::read_dir(directory).map_err(Error::Io)? {
+        let path = entry.map_err(Error::Io)?.path();
+        // The reports of criterion are HTML pages, without samples.
+        if path.is_dir() && !path.ends_with("report") {
+            find(root, &path, benchmarks)?;
+        }
+    }
+    Ok(())
+}
+
+/// Parse a `sample.json` file of criterion: the total time of each sample (in nanoseconds) with
+/// its number of iterations.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::data::criterion;
+///
+/// let json = r#"{"sampling_mode":"Linear","iters":[10.0,20.0],"times":[1500.0,2800.0]}"#;
+/// let benchmark = criterion::parse_sample("parse", json).unwrap();
+/// assert_eq!(benchmark.times, vec![150.0, 140.0]);
+/// ```
+pub fn parse_sample(name: &str, json: &str) -> Result<Benchmark, Error> {
+    let sample = serde_json::from_str::<serde_json::Value>(json).map_err(Error::Json)?;
+    let times = per_iteration(&sample["times"], &sample["iters"])
+        .ok_or_else(|| Error::Format(format!("invalid samples for {name}")))?;
+    Ok(Benchmark {
+        name: name.to_string(),
+        times,
+    })
+}
+
+/// Parse the JSON messages (one per line) written by `cargo criterion --message-format=json`.
+/// There is a benchmark for each `benchmark-complete` message, in their order. The other
+/// messages are ignored.
+pub fn parse_messages(json: &str) -> Result<Vec<Benchmark>, Error> {
+    let mut benchmarks = Vec::new();
+    for line in json.lines().filter(|line| !line.trim().is_empty()) {
+        let message = serde_json::from_str::<serde_json::Value>(line).map_err(Error::Json)?;
+        if message["reason"] != "benchmark-complete" {
+            continue;
+        }
+        let name = message["id"].as_str().unwrap_or_default().to_string();
+        if message["unit"] != "ns" {
+            let unit = message["unit"].as_str().unwrap_or_default();
+            return Err(Error::Format(format!(
+                "{name} is measured in {unit:?}, not in ns"
+            )));
+        }
+        let times = per_iteration(&message["measured_values"], &message["iteration_count"])
+            .ok_or_else(|| Error::Format(format!("invalid samples for {name}")))?;
+        benchmarks.push(Benchmark { name, times });
+    }
+    Ok(benchmarks)
+}
+
+/// The time of an iteration in each sample, from the total `times` and `iterations` of each
+/// sample.
+fn per_iteration(times: &serde_json::Value, iterations: &serde_json::Value) -> Option<Vec<f64>> {
+    let (times, iterations) = (times.as_array()?, iterations.as_array()?);
+    if times.len() != iterations.len() {
+        return None;
+    }
+    times
+        .iter()
+        .zip(iterations)
+        .map(|(time, count)| Some(time.as_f64()? / count.as_f64().filter(|&c| c > 0.0)?))
+        .collect()
+}
+
+/// A plot comparing `benchmarks`: the time of an iteration in each of their samples, with a box
+/// plot, in the unit (from nanoseconds to seconds) fitting the slowest sample.
+pub fn plot(benchmarks: &[Benchmark]) -> Plot {
+    let slowest = benchmarks
+        .iter()
+        .flat_map(|benchmark| benchmark.times.iter().copied())
+        .filter(|time| time.is_finite())
+        .fold(0.0, f64::max);
+    let (unit, scale) = match slowest {
+        time if time < 1e3 => ("ns", 1.0),
+        time if time < 1e6 => ("µs", 1e3),
+        time if time < 1e9 => ("ms", 1e6),
+        _ => ("s", 1e9),
+    };
+    let samples = benchmarks
+        .iter()
+        .map(|benchmark| benchmark.times.iter().map(|time| time / scale).collect())
+        .collect();
+    let labels = benchmarks
+        .iter()
+        .map(|benchmark| benchmark.name.clone())
+        .collect();
+    let strip = Strip::new(samples)
+        .with_jitter(0.5)
+        .with_box(true)
+        .with_labels(labels);
+    let count = benchmarks.len().max(1);
+    let mut plot = Plot::default();
+    plot.set_domain(Domain(0.0..count as f64))
+        .set_codomain(Domain(0.0..(slowest / scale * 1.1).max(1.0)))
+        .set_y_label(&format!("Time per iteration ({unit})"))
+        .set_size(Size::new((count * 30).clamp(60, 180), 40))
+        .add_plot(Box::new(strip));
+    plot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cargo_criterion_messages() {
+        let json = r#"{"reason":"group-complete","group_name":"fib","benchmarks":[]}
+{"reason":"benchmark-complete","id":"fib/20","unit":"ns","iteration_count":[2,4],"measured_values":[100.0,180.0]}
+"#;
+        let benchmarks = parse_messages(json).unwrap();
+        assert_eq!(
+            benchmarks,
+            vec![Benchmark {
+                name: String::from("fib/20"),
+                times: vec![50.0, 45.0],
+            }]
+        );
+        let json = r#"{"reason":"benchmark-complete","id":"a","unit":"cycles","iteration_count":[1],"measured_values":[1.0]}"#;
+        assert!(matches!(parse_messages(json), Err(Error::Format(_))));
+        let json = r#"{"iters":[1.0,0.0],"times":[5.0,5.0]}"#;
+        assert!(matches!(parse_sample("a", json), Err(Error::Format(_))));
+    }
+}
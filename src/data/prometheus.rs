@@ -0,0 +1,402 @@
+//! Plotting metrics from [Prometheus](https://prometheus.io).
+//!
+//! Series are either queried from the
+//! [range API](https://prometheus.io/docs/prometheus/latest/querying/api/#range-queries) of a
+//! server with [`query_range`], or built from successive scrapes of the text exposition format
+//! with [`from_scrapes`]. The x coordinate of each point is its time, in seconds since the Unix
+//! epoch: use [`TickFormat::Time`](crate::TickFormat::Time) to write the time of the day on the x
+//! axis.
+//!
+//! Requires the `prometheus` feature.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use termplot::data::prometheus;
+//! use termplot::*;
+//!
+//! let end = 1_700_000_000.0;
+//! let series = prometheus::query_range(
+//!     "http://localhost:9090",
+//!     "rate(http_requests_total[5m])",
+//!     end - 3600.0,
+//!     end,
+//!     60.0,
+//! )
+//! .unwrap();
+//!
+//! let mut plot = Plot::default();
+//! plot.set_domain(Domain(end - 3600.0..end))
+//!     .set_codomain(Domain(0.0..100.0))
+//!     .set_x_tick_format(TickFormat::Time)
+//!     .set_size(Size::new(100, 40));
+//! for series in series {
+//!     plot.add_plot(Box::new(plot::Lines::from(series)));
+//! }
+//!
+//! println!("{plot}");
+//! ```
+
+use crate::plot::Lines;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// How long [`query_range`] waits for the server to accept the connection, and for each read and
+/// write.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A series of a metric.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+    /// The name of the metric with its labels, like `up{instance="localhost:9090"}`.
+    pub name: String,
+    /// The `(time, value)` points of the series, with the time in seconds since the Unix epoch.
+    pub points: Vec<(f64, f64)>,
+}
+
+impl From<Series> for Lines {
+    fn from(series: Series) -> Self {
+        Lines::new(series.points)
+    }
+}
+
+/// An error while loading series from Prometheus.
+#[derive(Debug)]
+pub enum Error {
+    /// The address of the server is not an `http://` URL.
+    Url(String),
+    /// The server could not be reached.
+    Io(io::Error),
+    /// The server answered with an unexpected HTTP status.
+    Http(u16),
+    /// The answer of the server is not valid JSON.
+    Json(serde_json::Error),
+    /// The server answered with an error, or with something else than a range vector.
+    Api(String),
+    /// The line of a scrape at this number (starting at 1) is not a valid sample.
+    Exposition(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Url(url) => write!(f, "invalid Prometheus server address: {url}"),
+            Self::Io(error) => write!(f, "failed to query Prometheus: {error}"),
+            Self::Http(status) => write!(f, "Prometheus answered with HTTP status {status}"),
+            Self::Json(error) => write!(f, "invalid Prometheus answer: {error}"),
+            Self::Api(error) => write!(f, "Prometheus query failed: {error}"),
+            Self::Exposition(line) => write!(f, "invalid sample on line {line} of the scrape"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Query the range API of the server at `server` (like `http://localhost:9090`), evaluating
+/// `query` every `step` seconds from `start` to `end` (in seconds since the Unix epoch).
+///
+/// There is a series for each set of labels returned by the query. Only plain HTTP is supported.
+/// The query fails when the server does not answer within [`DEFAULT_TIMEOUT`], see
+/// [`query_range_with_timeout`].
+pub fn query_range(
+    server: &str,
+    query: &str,
+    start: f64,
+    end: f64,
+    step: f64,
+) -> Result<Vec<Series>, Error> {
+    query_range_with_timeout(server, query, start, end, step, DEFAULT_TIMEOUT)
+}
+
+/// Query the range API like [`query_range`], waiting at most `timeout` for the server to accept
+/// the connection, and for each read and write.
+pub fn query_range_with_timeout(
+    server: &str,
+    query: &str,
+    start: f64,
+    end: f64,
+    step: f64,
+    timeout: Duration,
+) -> Result<Vec<Series>, Error> {
+    let address = server
+        .strip_prefix("http://")
+        .ok_or_else(|| Error::Url(server.to_string()))?;
+    let (host, prefix) = match address.find('/') {
+        Some(index) => address.split_at(index),
+        None => (address, ""),
+    };
+    if host.is_empty() {
+        return Err(Error::Url(server.to_string()));
+    }
+    let authority = match host.contains(':') {
+        true => host.to_string(),
+        false => format!("{host}:80"),
+    };
+    let path = format!(
+        "{}/api/v1/query_range?query={}&start={start}&end={end}&step={step}",
+        prefix.trim_end_matches('/'),
+        encode(query)
+    );
+
+    let mut stream = connect(&authority, timeout).map_err(Error::Io)?;
+    stream.set_read_timeout(Some(timeout)).map_err(Error::Io)?;
+    stream.set_write_timeout(Some(timeout)).map_err(Error::Io)?;
+    // HTTP/1.0 keeps the body of the answer in one piece.
+    write!(
+        stream,
+        "GET {path} HTTP/1.0\r\nHost: {host}\r\nAccept: application/json\r\n\r\n"
+    )
+    .map_err(Error::Io)?;
+    let mut answer = Vec::new();
+    stream.read_to_end(&mut answer).map_err(Error::Io)?;
+    let answer = String::from_utf8_lossy(&answer);
+    let (head, body) = answer.split_once("\r\n\r\n").unwrap_or((&answer, ""));
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .unwrap_or(0);
+
+    match parse_range(body) {
+        // Errors of the API come with a client or server error status.
+        Err(Error::Json(_)) if !(200..300).contains(&status) => Err(Error::Http(status)),
+        result => result,
+    }
+}
+
+/// Connect to the first address of `authority` (`host:port`) accepting the connection within
+/// `timeout`.
+fn connect(authority: &str, timeout: Duration) -> io::Result<TcpStream> {
+    let mut error = io::Error::new(io::ErrorKind::NotFound, "the server address has no IP");
+    for address in authority.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&address, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(failed) => error = failed,
+        }
+    }
+    Err(error)
+}
+
+/// Parse the JSON answer of the range API of Prometheus.
+pub fn parse_range(json: &str) -> Result<Vec<Series>, Error> {
+    let answer = serde_json::from_str::<serde_json::Value>(json).map_err(Error::Json)?;
+    if answer["status"] != "success" {
+        let error = answer["error"].as_str().unwrap_or("unknown error");
+        return Err(Error::Api(error.to_string()));
+    }
+    if answer["data"]["resultType"] != "matrix" {
+        return Err(Error::Api(String::from("the result is not a range vector")));
+    }
+    let invalid = || Error::Api(String::from("invalid range vector"));
+    let results = answer["data"]["result"].as_array().ok_or_else(invalid)?;
+    results
+        .iter()
+        .map(|result| {
+            let metric = result["metric"].as_object().ok_or_else(invalid)?;
+            let labels = metric
+                .iter()
+                .filter(|(label, _)| *label != "__name__")
+                .map(|(label, value)| format!("{label}={value}"))
+                .collect::<Vec<_>>();
+            let mut name = metric
+                .get("__name__")
+                .and_then(|name| name.as_str())
+                .unwrap_or_default()
+                .to_string();
+            if !labels.is_empty() || name.is_empty() {
+                name = format!("{name}{{{}}}", labels.join(","));
+            }
+            let values = result["values"].as_array().ok_or_else(invalid)?;
+            let points = values
+                .iter()
+                .map(|point| {
+                    let time = point[0].as_f64();
+                    let value = point[1].as_str().and_then(|value| value.parse().ok());
+                    time.zip(value).ok_or_else(invalid)
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Series { name, points })
+        })
+        .collect()
+}
+
+/// Build series from successive scrapes of the text exposition format of Prometheus, each with the
+/// time it was made at (in seconds since the Unix epoch).
+///
+/// There is a series for each metric with its labels, in the order they first appear. Samples
+/// with a timestamp use it instead of the time of their scrape. Comments and empty lines are
+/// ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::data::prometheus;
+///
+/// let series = prometheus::from_scrapes(&[
+///     (10.0, "# TYPE queue_size gauge\nqueue_size{queue=\"mail\"} 4\n"),
+///     (20.0, "queue_size{queue=\"mail\"} 7\n"),
+/// ])
+/// .unwrap();
+///
+/// assert_eq!(series[0].name, "queue_size{queue=\"mail\"}");
+/// assert_eq!(series[0].points, vec![(10.0, 4.0), (20.0, 7.0)]);
+/// ```
+pub fn from_scrapes(scrapes: &[(f64, &str)]) -> Result<Vec<Series>, Error> {
+    let mut series = Vec::<Series>::new();
+    let mut indexes = HashMap::<String, usize>::new();
+    for &(time, scrape) in scrapes {
+        for (index, line) in scrape.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (name, value, timestamp) = sample(line).ok_or(Error::Exposition(index + 1))?;
+            let index = *indexes.entry(name.to_string()).or_insert_with(|| {
+                series.push(Series {
+                    name: name.to_string(),
+                    points: Vec::new(),
+                });
+                series.len() - 1
+            });
+            series[index]
+                .points
+                .push((timestamp.unwrap_or(time), value));
+        }
+    }
+    Ok(series)
+}
+
+/// Split a line of the exposition format in the name of the metric with its labels, the value
+/// and the timestamp (in seconds).
+fn sample(line: &str) -> Option<(&str, f64, Option<f64>)> {
+    let end = match line.find(['{', ' ', '\t'])? {
+        // Braces can only appear inside of a label value, which is quoted.
+        index if line[index..].starts_with('{') => {
+            let mut quoted = false;
+            let mut escaped = false;
+            let close = line[index..].char_indices().find(|&(_, c)| {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => quoted = !quoted,
+                    '}' if !quoted => return true,
+                    _ => {}
+                }
+                false
+            })?;
+            index + close.0 + 1
+        }
+        index => index,
+    };
+    let (name, rest) = line.split_at(end);
+    let mut fields = rest.split_whitespace();
+    let value = fields.next()?.parse().ok()?;
+    let timestamp = match fields.next() {
+        Some(timestamp) => Some(timestamp.parse::<f64>().ok()? / 1000.0),
+        None => None,
+    };
+    fields.next().is_none().then_some((name, value, timestamp))
+}
+
+/// Percent-encode `text` to be used in the query of a URL.
+fn encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_answer() {
+        let json = r#"{"status":"success","data":{"resultType":"matrix","result":[
+            {"metric":{"__name__":"up","job":"api"},"values":[[1700000000,"1"],[1700000060.5,"0"]]},
+            {"metric":{},"values":[[1700000000,"NaN"]]}
+        ]}}"#;
+        let series = parse_range(json).unwrap();
+        assert_eq!(series[0].name, r#"up{job="api"}"#);
+        assert_eq!(
+            series[0].points,
+            vec![(1_700_000_000.0, 1.0), (1_700_000_060.5, 0.0)]
+        );
+        assert_eq!(series[1].name, "{}");
+        assert!(series[1].points[0].1.is_nan());
+
+        let json = r#"{"status":"error","errorType":"bad_data","error":"parse error"}"#;
+        assert!(matches!(parse_range(json), Err(Error::Api(error)) if error == "parse error"));
+    }
+
+    #[test]
+    fn exposition_samples() {
+        assert_eq!(sample("up 1"), Some(("up", 1.0, None)));
+        assert_eq!(
+            sample(r#"http_requests_total{path="/a b}",code="200"} 1027 1395066363000"#),
+            Some((
+                r#"http_requests_total{path="/a b}",code="200"}"#,
+                1027.0,
+                Some(1_395_066_363.0)
+            ))
+        );
+        assert_eq!(
+            sample("temperature -Inf"),
+            Some(("temperature", f64::NEG_INFINITY, None))
+        );
+        assert_eq!(sample("up"), None);
+        assert_eq!(sample("up one"), None);
+        assert!(matches!(
+            from_scrapes(&[(0.0, "# HELP up\n\nup one")]),
+            Err(Error::Exposition(3))
+        ));
+    }
+
+    #[test]
+    fn range_request() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let server = format!("http://{}/prometheus/", listener.local_addr().unwrap());
+        let answering = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            while !request.ends_with(b"\r\n\r\n") {
+                let mut byte = [0];
+                stream.read_exact(&mut byte).unwrap();
+                request.push(byte[0]);
+            }
+            let json = r#"{"status":"success","data":{"resultType":"matrix","result":[
+                {"metric":{"__name__":"up"},"values":[[10,"1"]]}]}}"#;
+            write!(stream, "HTTP/1.0 200 OK\r\n\r\n{json}").unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        let series = query_range(&server, "up", 0.0, 60.0, 15.0).unwrap();
+        assert_eq!(series[0].points, vec![(10.0, 1.0)]);
+        let request = answering.join().unwrap();
+        assert!(request.starts_with(
+            "GET /prometheus/api/v1/query_range?query=up&start=0&end=60&step=15 HTTP/1.0\r\n"
+        ));
+    }
+
+    #[test]
+    fn silent_server() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let server = format!("http://{}", listener.local_addr().unwrap());
+        let timeout = Duration::from_millis(50);
+        // The connection is accepted by the system, but nothing is ever answered.
+        let result = query_range_with_timeout(&server, "up", 0.0, 60.0, 15.0, timeout);
+        assert!(matches!(result, Err(Error::Io(_))));
+        drop(listener);
+    }
+
+    #[test]
+    fn query_encoding() {
+        assert_eq!(encode("rate(x[5m])"), "rate%28x%5B5m%5D%29");
+    }
+}
@@ -0,0 +1,110 @@
+//! Constructors of plots from [`polars`] series and data frames.
+//!
+//! The values are cast to `f64`, so any numeric column can be used. Null values are skipped: a
+//! point is only drawn if both its x and y values are present.
+use crate::plot::{Bars, Histogram, Lines, Scatter};
+use polars::prelude::{DataFrame, DataType, PolarsResult, Series};
+use std::ops;
+
+impl Lines {
+    /// Create a new line going through the points with the x values of `x` and the y values of
+    /// `y`.
+    pub fn from_series(x: &Series, y: &Series) -> PolarsResult<Self> {
+        Ok(Self::new(points(x, y)?))
+    }
+
+    /// Create a new line going through the points with the x values of the column `x` and the y
+    /// values of the column `y` of the data frame.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use polars::prelude::*;
+    /// use termplot::*;
+    ///
+    /// let df = DataFrame::new(vec![
+    ///     Column::new("time".into(), &[0.0, 1.0, 2.0, 3.0]),
+    ///     Column::new("latency".into(), &[Some(12.0), None, Some(15.0), Some(9.0)]),
+    /// ])
+    /// .unwrap();
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..3.0))
+    ///     .set_codomain(Domain(0.0..20.0))
+    ///     .add_plot(Box::new(plot::Lines::from_columns(&df, "time", "latency").unwrap()));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn from_columns(df: &DataFrame, x: &str, y: &str) -> PolarsResult<Self> {
+        Self::from_series(column(df, x)?, column(df, y)?)
+    }
+}
+
+impl Scatter {
+    /// Create a new scatter plot from the x values of `x` and the y values of `y`.
+    pub fn from_series(x: &Series, y: &Series) -> PolarsResult<Self> {
+        Ok(Self::new(points(x, y)?))
+    }
+
+    /// Create a new scatter plot from the x values of the column `x` and the y values of the
+    /// column `y` of the data frame.
+    pub fn from_columns(df: &DataFrame, x: &str, y: &str) -> PolarsResult<Self> {
+        Self::from_series(column(df, x)?, column(df, y)?)
+    }
+}
+
+impl Bars {
+    /// Create a new bars graph where each value of the series is the height of a bar.
+    pub fn from_series(bars_height: &Series) -> PolarsResult<Self> {
+        Ok(Self::new(values(bars_height)?))
+    }
+
+    /// Create a new bars graph where each value of a column of the data frame is the height of a
+    /// bar.
+    pub fn from_column(df: &DataFrame, name: &str) -> PolarsResult<Self> {
+        Self::from_series(column(df, name)?)
+    }
+}
+
+impl Histogram {
+    /// Create an histogram from the values of the series and buckets in which the values will be
+    /// sorted. See [`Histogram::new`].
+    pub fn from_series(values: &Series, buckets_range: Vec<ops::Range<f64>>) -> PolarsResult<Self> {
+        Ok(Self::new(self::values(values)?, buckets_range))
+    }
+
+    /// Create an histogram from the values of a column of the data frame and buckets in which
+    /// the values will be sorted. See [`Histogram::new`].
+    pub fn from_column(
+        df: &DataFrame,
+        name: &str,
+        buckets_range: Vec<ops::Range<f64>>,
+    ) -> PolarsResult<Self> {
+        Self::from_series(column(df, name)?, buckets_range)
+    }
+}
+
+fn column<'a>(df: &'a DataFrame, name: &str) -> PolarsResult<&'a Series> {
+    Ok(df.column(name)?.as_materialized_series())
+}
+
+/// The values of the series as `f64`, including the null values.
+fn nullable_values(series: &Series) -> PolarsResult<Vec<Option<f64>>> {
+    let series = series.cast(&DataType::Float64)?;
+    Ok(series.f64()?.into_iter().collect())
+}
+
+/// The values of the series as `f64`, without the null values.
+fn values(series: &Series) -> PolarsResult<Vec<f64>> {
+    Ok(nullable_values(series)?.into_iter().flatten().collect())
+}
+
+/// The points of two series, without the points where a value is null.
+fn points(x: &Series, y: &Series) -> PolarsResult<Vec<(f64, f64)>> {
+    let x = nullable_values(x)?;
+    let y = nullable_values(y)?;
+    Ok(x.into_iter()
+        .zip(y)
+        .filter_map(|(x, y)| Some((x?, y?)))
+        .collect())
+}
@@ -0,0 +1,275 @@
+use crate::{color, legend, text, Align, Decorations, Plot, Position, RenderError};
+use std::fmt;
+
+/// The space between two plots of a row.
+const GAP: &str = "  ";
+
+/// Several plots drawn side by side, in a grid filled row by row.
+///
+/// Each plot keeps its own size and decorations. By default every plot writes its own legend;
+/// use [`Figure::set_shared_legend`] to write a single legend for the whole figure instead. Plots
+/// with common axes can also share their labels, see [`Figure::set_shared_x_label`] and
+/// [`Figure::set_shared_y_label`].
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let x = (0..50).map(|x| x as f64 / 5.0).collect::<Vec<_>>();
+/// let series = |f: fn(f64) -> f64| x.iter().map(|x| f(*x)).collect::<Vec<_>>();
+///
+/// let mut figure = Figure::new(2);
+/// for ys in [vec![series(f64::sin), series(f64::cos)], vec![series(f64::sin)]] {
+///     let mut plot = Plot::default();
+///     plot.set_domain(Domain(0.0..10.0))
+///         .set_codomain(Domain(-1.2..1.2))
+///         .set_size(Size::new(40, 20))
+///         .add_plot(Box::new(
+///             plot::MultiLines::new(x.clone(), ys).with_names(vec!["sin(x)", "cos(x)"]),
+///         ));
+///     figure.add_plot(plot);
+/// }
+/// figure
+///     .set_title("Waves")
+///     .set_shared_x_label("time")
+///     .set_shared_legend(Position::Bottom);
+///
+/// println!("{figure}");
+/// ```
+pub struct Figure {
+    plots: Vec<Plot>,
+    columns: usize,
+    legend: Option<Position>,
+    title: String,
+    x_label: String,
+    y_label: String,
+}
+
+impl Figure {
+    /// Create an empty figure with `columns` plots on each row.
+    pub fn new(columns: usize) -> Self {
+        Self {
+            plots: Vec::new(),
+            columns: columns.max(1),
+            legend: None,
+            title: String::new(),
+            x_label: String::new(),
+            y_label: String::new(),
+        }
+    }
+
+    /// Add a plot after the plots that were already added, on a new row when the last row is
+    /// full.
+    pub fn add_plot(&mut self, plot: Plot) -> &mut Self {
+        self.plots.push(plot);
+        self
+    }
+
+    /// Write a single legend for the whole figure instead of a legend below each plot. The
+    /// legend lists the named series of every plot, once each (series with the same name and
+    /// the same color in several plots are listed once).
+    ///
+    /// With [`Position::Right`], the legend is written beside the grid, one entry per line. The
+    /// other positions write it centered below the grid.
+    pub fn set_shared_legend(&mut self, position: Position) -> &mut Self {
+        self.legend = Some(position);
+        self
+    }
+
+    /// Set the title of the whole figure, centered above the grid. The title breaks on `\n` and
+    /// is wrapped to the width of the grid.
+    pub fn set_title(&mut self, title: &str) -> &mut Self {
+        self.title = String::from(title);
+        self
+    }
+
+    /// Set a label of the x axis shared by every plot, centered below the grid. The x labels
+    /// of the plots are hidden when it is set, and their y labels are kept.
+    pub fn set_shared_x_label(&mut self, label: &str) -> &mut Self {
+        self.x_label = String::from(label);
+        self
+    }
+
+    /// Set a label of the y axis shared by every plot, written vertically along the left edge
+    /// of the grid. The y labels of the plots are hidden when it is set, and their x labels are
+    /// kept.
+    pub fn set_shared_y_label(&mut self, label: &str) -> &mut Self {
+        self.y_label = String::from(label);
+        self
+    }
+
+    /// Render the figure. Fails when one of the plots cannot be rendered, see
+    /// [`Plot::try_render`].
+    pub fn try_render(&self) -> Result<String, RenderError> {
+        let mut cells = Vec::new();
+        for plot in &self.plots {
+            let decorations = Decorations {
+                x_label: plot.decorations.x_label && self.x_label.is_empty(),
+                y_label: plot.decorations.y_label && self.y_label.is_empty(),
+                legend: plot.decorations.legend && self.legend.is_none(),
+                ..plot.decorations
+            };
+            let rendered = plot.render(decorations)?;
+            cells.push(rendered.lines().map(String::from).collect::<Vec<_>>());
+        }
+        let mut lines = Vec::new();
+        for row in cells.chunks(self.columns) {
+            let widths = row
+                .iter()
+                .map(|cell| cell.iter().map(|line| text::width(line)).max().unwrap_or(0))
+                .collect::<Vec<_>>();
+            let height = row.iter().map(Vec::len).max().unwrap_or(0);
+            for index in 0..height {
+                let line = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| {
+                        let line = cell.get(index).map(String::as_str).unwrap_or_default();
+                        text::align(line, *width, Align::Left, ' ')
+                    })
+                    .collect::<Vec<_>>();
+                lines.push(line.join(GAP));
+            }
+        }
+        let width = lines
+            .iter()
+            .map(|line| text::width(line))
+            .max()
+            .unwrap_or(0);
+        let mut below = Vec::new();
+        for line in text::wrap(&self.x_label, width) {
+            below.push(text::center(&line, width));
+        }
+        match self.legend {
+            Some(Position::Right) => {
+                // A width of zero puts each entry on its own row.
+                let entries = legend::rows(&self.legend_entries(), 0, color::enabled());
+                let top = lines.len().saturating_sub(entries.len()) / 2;
+                for (index, entry) in entries.iter().enumerate() {
+                    if top + index == lines.len() {
+                        lines.push(String::new());
+                    }
+                    let line = &mut lines[top + index];
+                    *line = format!("{}{GAP}{entry}", text::align(line, width, Align::Left, ' '));
+                }
+            }
+            Some(_) => {
+                for row in legend::rows(&self.legend_entries(), width, color::enabled()) {
+                    below.push(text::center(&row, width));
+                }
+            }
+            None => {}
+        }
+        // With a shared y label, every line of the grid starts with a column holding the label,
+        // and the lines above and below the grid are shifted by it.
+        let mut margin = String::new();
+        if !self.y_label.is_empty() {
+            let left = text::vertical(&self.y_label.replace('\n', " "), lines.len());
+            margin = " ".repeat(left.first().map_or(0, |column| text::width(column)) + 1);
+            for (line, left) in lines.iter_mut().zip(left) {
+                *line = format!("{left} {line}");
+            }
+        }
+        let mut out = Vec::new();
+        for line in text::wrap(&self.title, width) {
+            out.push(format!("{margin}{}", text::center(&line, width)));
+        }
+        out.extend(lines);
+        out.extend(below.into_iter().map(|line| format!("{margin}{line}")));
+        Ok(out.into_iter().map(|line| line + "\n").collect())
+    }
+
+    /// The entries of the legends of every plot, without duplicates.
+    fn legend_entries(&self) -> Vec<legend::LegendEntry> {
+        let mut entries = Vec::new();
+        for entry in self.plots.iter().flat_map(|plot| plot.view.legend()) {
+            if !entries.contains(&entry) {
+                entries.push(entry);
+            }
+        }
+        entries
+    }
+}
+
+impl fmt::Display for Figure {
+    /// Write the figure, or the reason it cannot be rendered on a single line, see
+    /// [`Figure::try_render`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.try_render() {
+            Ok(rendered) => f.write_str(&rendered),
+            Err(error) => writeln!(f, "{error}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{plot, Domain, Size};
+
+    fn plot(names: Vec<&str>) -> Plot {
+        let ys = names.iter().map(|_| vec![0.0, 1.0]).collect();
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..1.0))
+            .set_codomain(Domain(0.0..1.0))
+            .set_size(Size::new(40, 8))
+            .add_plot(Box::new(
+                plot::MultiLines::new(vec![0.0, 1.0], ys).with_names(names),
+            ));
+        plot
+    }
+
+    #[test]
+    fn shared_legend() {
+        let mut figure = Figure::new(2);
+        figure
+            .add_plot(plot(vec!["a", "b"]))
+            .add_plot(plot(vec!["a"]));
+        let count = |rendered: &str, label: &str| {
+            text::strip_escapes(rendered)
+                .lines()
+                .flat_map(|line| line.split("   ").map(str::trim).collect::<Vec<_>>())
+                .filter(|entry| entry.ends_with(&format!("── {label}")))
+                .count()
+        };
+        let rendered = figure.try_render().unwrap();
+        assert_eq!((count(&rendered, "a"), count(&rendered, "b")), (2, 1));
+        let rendered = figure
+            .set_shared_legend(Position::Bottom)
+            .try_render()
+            .unwrap();
+        assert_eq!((count(&rendered, "a"), count(&rendered, "b")), (1, 1));
+        let rendered = figure
+            .set_shared_legend(Position::Right)
+            .try_render()
+            .unwrap();
+        assert!(rendered.lines().any(|line| line.trim_end().ends_with(" a")));
+    }
+
+    #[test]
+    fn shared_labels() {
+        let mut figure = Figure::new(2);
+        let mut labelled = plot(vec!["a"]);
+        labelled.set_x_label("own");
+        figure.add_plot(labelled).add_plot(plot(vec!["b"]));
+        figure
+            .set_title("Title")
+            .set_shared_x_label("x")
+            .set_shared_y_label("y");
+        let rendered = text::strip_escapes(&figure.try_render().unwrap());
+        let lines = rendered.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0].trim(), "Title");
+        assert!(lines.iter().any(|line| line.trim() == "x"));
+        assert!(lines.iter().any(|line| line.starts_with("y ")));
+        assert!(!rendered.contains("own"));
+        // Only the label of the shared axis is hidden.
+        let mut figure = Figure::new(1);
+        let mut labelled = plot(vec!["a"]);
+        labelled.set_x_label("own x").set_y_label("own y");
+        figure.add_plot(labelled).set_shared_x_label("x");
+        let rendered = text::strip_escapes(&figure.try_render().unwrap());
+        assert!(!rendered.contains("own x"));
+        assert!(rendered.contains("own y"));
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{plot, Decorations, Domain, Plot, RenderError, Size};
+use std::fmt;
+
+/// A price chart with the traded volumes below it: an [OHLC](plot::Ohlc) panel on top and a
+/// volume bar panel under it, sharing the same x domain.
+///
+/// The views of both panels are aligned column by column, even when the values of the y axis
+/// of the panels have different widths, and the values of the x axis are written once, below
+/// the volumes.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let quotes = vec![
+///     plot::Quote::new(1.0, 100.0, 104.0, 98.0, 103.0),
+///     plot::Quote::new(2.0, 103.0, 107.0, 101.0, 106.0),
+///     plot::Quote::new(3.0, 106.0, 108.0, 99.0, 100.0),
+///     plot::Quote::new(4.0, 100.0, 102.0, 95.0, 97.0),
+///     plot::Quote::new(5.0, 97.0, 105.0, 96.0, 104.0),
+/// ];
+/// let volumes = vec![12000.0, 18500.0, 23000.0, 9000.0, 15500.0];
+///
+/// let mut chart = FinancialChart::new(quotes, volumes);
+/// chart.set_title("ACME").set_size(Size::new(60, 24));
+///
+/// println!("{chart}");
+/// ```
+pub struct FinancialChart {
+    quotes: Vec<plot::Quote>,
+    volumes: Vec<f64>,
+    title: String,
+    size: Size,
+    volume_height: usize,
+}
+
+impl FinancialChart {
+    /// Create a new chart from the quote of each period and the volume traded during each
+    /// period, in the same order. Extra volumes are ignored.
+    pub fn new(quotes: Vec<plot::Quote>, volumes: Vec<f64>) -> Self {
+        Self {
+            quotes,
+            volumes,
+            title: String::new(),
+            size: Size::new(100, 40),
+            volume_height: 16,
+        }
+    }
+
+    /// Set the title written above the prices.
+    pub fn set_title(&mut self, title: &str) -> &mut Self {
+        self.title = String::from(title);
+        self
+    }
+
+    /// Set the size of the view of the prices. The view of the volumes is as wide. See
+    /// [`Plot::set_size`] for more informations.
+    pub fn set_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the height of the view of the volumes, in pixels (4 pixels per row). By default, the
+    /// volumes are 16 pixels high.
+    pub fn set_volume_height(&mut self, height: usize) -> &mut Self {
+        self.volume_height = height;
+        self
+    }
+
+    /// The smallest distance between two periods, or 1 when there is a single period.
+    fn spacing(&self) -> f64 {
+        let mut positions = self.quotes.iter().map(|quote| quote.x).collect::<Vec<_>>();
+        positions.sort_by(f64::total_cmp);
+        let spacing = positions
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .filter(|&distance| distance > 0.0)
+            .fold(f64::INFINITY, f64::min);
+        if spacing.is_finite() {
+            spacing
+        } else {
+            1.0
+        }
+    }
+
+    /// The panels of the prices and of the volumes.
+    fn panels(&self) -> (Plot, Plot) {
+        let spacing = self.spacing();
+        let fold = |init, f: fn(f64, f64) -> f64, value: fn(&plot::Quote) -> f64| {
+            self.quotes.iter().map(value).fold(init, f)
+        };
+        let first = fold(f64::INFINITY, f64::min, |quote| quote.x);
+        let last = fold(f64::NEG_INFINITY, f64::max, |quote| quote.x);
+        let domain = Domain(first - spacing / 2.0..last + spacing / 2.0);
+        let low = fold(f64::INFINITY, f64::min, |quote| quote.low);
+        let high = fold(f64::NEG_INFINITY, f64::max, |quote| quote.high);
+        let margin = (high - low).max(f64::EPSILON) * 0.05;
+        let volumes = self
+            .quotes
+            .iter()
+            .zip(&self.volumes)
+            .map(|(quote, &volume)| (quote.x, volume))
+            .collect::<Vec<_>>();
+        let max_volume = volumes
+            .iter()
+            .map(|&(_, volume)| volume)
+            .fold(0.0, f64::max);
+        let max_volume = if max_volume > 0.0 { max_volume } else { 1.0 };
+
+        let mut prices = Plot::default();
+        prices
+            .set_title(&self.title)
+            .set_domain(domain.clone())
+            .set_codomain(Domain(low - margin..high + margin))
+            .set_size(self.size.clone())
+            .set_decorations(Decorations {
+                x_ticks: false,
+                x_label: false,
+                y_label: false,
+                ..Decorations::default()
+            })
+            .add_plot(Box::new(plot::Ohlc::new(self.quotes.clone())));
+        let mut volume = Plot::default();
+        volume
+            .set_domain(domain)
+            .set_codomain(Domain(0.0..max_volume * 1.1))
+            .set_size(Size::new(self.size.w, self.volume_height))
+            .set_decorations(Decorations {
+                x_label: false,
+                y_label: false,
+                ..Decorations::default()
+            })
+            .add_plot(Box::new(
+                plot::Bars::with_positions(volumes)
+                    .with_bar_width(spacing)
+                    .with_gap(spacing / 3.0)
+                    .with_style(plot::BarStyle::Filled),
+            ));
+        let width = prices.view.y_ticks_width().max(volume.view.y_ticks_width());
+        prices.view.y_ticks_width = width;
+        volume.view.y_ticks_width = width;
+        (prices, volume)
+    }
+
+    /// Render the chart. Fails when a panel cannot be rendered, see [`Plot::try_render`].
+    pub fn try_render(&self) -> Result<String, RenderError> {
+        let (prices, volume) = self.panels();
+        Ok(prices.try_render()? + &volume.try_render()?)
+    }
+}
+
+impl fmt::Display for FinancialChart {
+    /// Write the chart, or the reason it cannot be rendered on a single line, see
+    /// [`FinancialChart::try_render`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.try_render() {
+            Ok(rendered) => f.write_str(&rendered),
+            Err(error) => writeln!(f, "{error}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text;
+
+    #[test]
+    fn aligned_panels() {
+        let quotes = vec![
+            plot::Quote::new(1.0, 1.0, 1.5, 0.5, 1.2),
+            plot::Quote::new(2.0, 1.2, 1.4, 0.9, 1.0),
+        ];
+        let mut chart = FinancialChart::new(quotes, vec![125000.0, 98000.0]);
+        chart.set_size(Size::new(40, 16));
+        let rendered = chart.try_render().unwrap();
+        let widths = rendered.lines().map(text::width).collect::<Vec<_>>();
+        assert!(widths.iter().all(|&width| width == widths[0]));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::ViewCanvas;
+
+/// The number of pixels between two dots of a faint grid line.
+const FAINT_SPACING: usize = 4;
+
+/// Draw faint vertical grid lines at each value of `xs` and horizontal grid lines at each value
+/// of `ys`, with a dot every few pixels.
+pub(crate) fn draw_faint(canvas: &mut ViewCanvas, xs: &[f64], ys: &[f64]) {
+    let view = canvas.view();
+    let (width, height) = (view.size.w, view.size.h);
+    for &x in xs.iter() {
+        for row in (0..height).step_by(FAINT_SPACING) {
+            let y = view
+                .y_scale
+                .value_at(&view.codomain, row as f64 / height as f64);
+            canvas.point(x, y);
+        }
+    }
+    for &y in ys.iter() {
+        for column in (0..width).step_by(FAINT_SPACING) {
+            let x = view
+                .x_scale
+                .value_at(&view.domain, column as f64 / width as f64);
+            canvas.point(x, y);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Converting the output of a plot to HTML.
+
+use crate::color;
+use crate::renderer::{self, Cell};
+
+/// The colors of the palette of the terminal (the ones of xterm), indexed by their ANSI code:
+/// `30` to `37`, then `90` to `97` for the bright colors.
+const PALETTE: [&str; 16] = [
+    "#000000", "#cd0000", "#00cd00", "#cdcd00", "#0000ee", "#cd00cd", "#00cdcd", "#e5e5e5",
+    "#7f7f7f", "#ff0000", "#00ff00", "#ffff00", "#5c5cff", "#ff00ff", "#00ffff", "#ffffff",
+];
+
+/// Write `text` (lines of characters with ANSI escape sequences) as a `<pre>` block, each run of
+/// characters with the same style in a `<span>` with inline CSS.
+pub(crate) fn from_ansi(text: &str) -> String {
+    let mut html = String::from("<pre style=\"font-family: monospace; line-height: 1.2;\">");
+    for (index, line) in text.lines().enumerate() {
+        if index > 0 {
+            html.push('\n');
+        }
+        let cells = renderer::cells(line);
+        for run in cells.chunk_by(|a: &Cell, b: &Cell| a.style == b.style) {
+            let content = run.iter().map(|cell| escape(cell.char)).collect::<String>();
+            match css(&run[0].style) {
+                css if css.is_empty() => html.push_str(&content),
+                css => html.push_str(&format!("<span style=\"{css}\">{content}</span>")),
+            }
+        }
+    }
+    html.push_str("</pre>\n");
+    html
+}
+
+/// The inline CSS of the SGR escape sequences (`\x1b[...m`) of `style`.
+fn css(style: &str) -> String {
+    let mut color = None;
+    let mut background = None;
+    let mut bold = false;
+    let mut dim = false;
+    let mut reversed = false;
+    for sequence in style
+        .split('\x1b')
+        .filter(|sequence| sequence.ends_with('m'))
+    {
+        let parameters = sequence
+            .trim_start_matches('[')
+            .trim_end_matches('m')
+            .split(';')
+            .map(|parameter| parameter.parse::<u8>().unwrap_or(0))
+            .collect::<Vec<_>>();
+        let mut parameters = parameters.into_iter();
+        while let Some(parameter) = parameters.next() {
+            match parameter {
+                0 => {
+                    (color, background) = (None, None);
+                    (bold, dim, reversed) = (false, false, false);
+                }
+                1 => bold = true,
+                2 => dim = true,
+                7 => reversed = true,
+                22 => (bold, dim) = (false, false),
+                27 => reversed = false,
+                30..=37 => color = Some(PALETTE[parameter as usize - 30].to_string()),
+                90..=97 => color = Some(PALETTE[parameter as usize - 90 + 8].to_string()),
+                38 => color = extended(&mut parameters).or(color),
+                39 => color = None,
+                40..=47 => background = Some(PALETTE[parameter as usize - 40].to_string()),
+                100..=107 => background = Some(PALETTE[parameter as usize - 100 + 8].to_string()),
+                48 => background = extended(&mut parameters).or(background),
+                49 => background = None,
+                _ => {}
+            }
+        }
+    }
+    // In reverse video, the default colors of the page (the CSS system colors) are swapped too.
+    if reversed {
+        (color, background) = (
+            Some(background.unwrap_or_else(|| String::from("Canvas"))),
+            Some(color.unwrap_or_else(|| String::from("CanvasText"))),
+        );
+    }
+    let mut css = Vec::new();
+    if let Some(color) = color {
+        css.push(format!("color: {color}"));
+    }
+    if let Some(background) = background {
+        css.push(format!("background-color: {background}"));
+    }
+    if bold {
+        css.push(String::from("font-weight: bold"));
+    }
+    if dim {
+        css.push(String::from("opacity: 0.5"));
+    }
+    css.join("; ")
+}
+
+/// The color of the parameters following `38` (the color of the text) or `48` (the color of the
+/// background): a true color (`2;r;g;b`), or a color of the 256 colors palette (`5;n`) written
+/// on terminals without true colors.
+fn extended(parameters: &mut impl Iterator<Item = u8>) -> Option<String> {
+    match parameters.next() {
+        Some(2) => {
+            let rgb = parameters.take(3).collect::<Vec<_>>();
+            match rgb[..] {
+                [r, g, b] => Some(format!("#{r:02x}{g:02x}{b:02x}")),
+                _ => None,
+            }
+        }
+        Some(5) => match parameters.next()? {
+            index @ 0..=15 => Some(PALETTE[index as usize].to_string()),
+            index => {
+                let (r, g, b) = color::xterm_256(index);
+                Some(format!("#{r:02x}{g:02x}{b:02x}"))
+            }
+        },
+        _ => None,
+    }
+}
+
+/// `c` escaped for HTML.
+fn escape(c: char) -> String {
+    match c {
+        '&' => String::from("&amp;"),
+        '<' => String::from("&lt;"),
+        '>' => String::from("&gt;"),
+        c => c.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{plot::Highlight, AxisMode, Color, Decorations, Domain, Fill, Plot, Size};
+
+    #[test]
+    fn styles_become_spans() {
+        assert_eq!(
+            from_ansi("a<\x1b[31mbc\x1b[0m\n\x1b[1;38;2;255;128;0md\x1b[0m"),
+            "<pre style=\"font-family: monospace; line-height: 1.2;\">a&lt;\
+             <span style=\"color: #cd0000\">bc</span>\n\
+             <span style=\"color: #ff8000; font-weight: bold\">d</span></pre>\n"
+        );
+    }
+
+    #[test]
+    fn colored_background() {
+        let mut plot = Plot::default();
+        plot.set_size(Size::new(2, 4))
+            .set_axis_mode(AxisMode::None)
+            .set_decorations(Decorations::NONE)
+            .set_background(Fill::Color(Color::Blue));
+        assert_eq!(
+            plot.to_html().unwrap(),
+            "<pre style=\"font-family: monospace; line-height: 1.2;\">\
+             <span style=\"background-color: #0000ee\"> </span></pre>\n"
+        );
+        assert_eq!(
+            css("\x1b[44;38;2;1;2;3m"),
+            "color: #010203; background-color: #0000ee"
+        );
+        assert_eq!(css("\x1b[105m\x1b[49m"), "");
+        assert_eq!(
+            css("\x1b[38;5;9;48;5;67m"),
+            "color: #ff0000; background-color: #5f87af"
+        );
+    }
+
+    #[test]
+    fn highlighted_range() {
+        let html = |highlight| {
+            let mut plot = Plot::default();
+            plot.set_domain(Domain(0.0..2.0))
+                .set_size(Size::new(4, 4))
+                .set_axis_mode(AxisMode::None)
+                .set_decorations(Decorations::NONE)
+                .add_plot(Box::new(highlight));
+            plot.to_html().unwrap()
+        };
+        // Without colors, the reversed cell takes the colors of the page, swapped.
+        assert_eq!(
+            html(Highlight::x_range(0.0..1.0)),
+            "<pre style=\"font-family: monospace; line-height: 1.2;\">\
+             <span style=\"color: Canvas; background-color: CanvasText\"> </span> </pre>\n"
+        );
+        assert_eq!(
+            html(Highlight::x_range(0.0..1.0).with_color(Color::Red)),
+            "<pre style=\"font-family: monospace; line-height: 1.2;\">\
+             <span style=\"background-color: #cd0000\"> </span> </pre>\n"
+        );
+        assert_eq!(
+            css("\x1b[7;31m"),
+            "color: Canvas; background-color: #cd0000"
+        );
+        assert_eq!(css("\x1b[7m\x1b[27;41m"), "background-color: #cd0000");
+    }
+}
@@ -0,0 +1,655 @@
+//! Exploring a plot in the terminal with the keyboard and the mouse.
+
+use crate::{color, Domain, DrawView, Plot, Renderer, TickFormat, View, ViewCanvas};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
+};
+use crossterm::{cursor, execute, terminal};
+use std::io;
+
+/// The proportion of the range moved by a pan.
+const PAN: f64 = 0.1;
+/// The factor applied to the range when zooming in.
+const ZOOM: f64 = 0.8;
+
+impl Plot {
+    /// Show the plot in the whole terminal and explore it with the keyboard and the mouse.
+    ///
+    /// The arrow keys move the view, `+` and `-` zoom in and out around the center of the view,
+    /// the keys `1` to `9` hide or show the plots in the order they were added, and `q`, `Esc`
+    /// or `Ctrl-C` exit. The terminal is restored when exiting, and the plot keeps
+    /// the domain and codomain of the view at that moment.
+    ///
+    /// A status line below the plot shows the coordinates under the mouse pointer, and the data
+    /// point closest to the pointer (see [`DrawView::points`]).
+    ///
+    /// `c` shows a data cursor on a point of the first plot with points, with its exact
+    /// coordinates in the status line. While it is shown, `←` and `→` move it to the previous and
+    /// next points of its series, `Tab` moves it to the next series (see
+    /// [`DrawView::series_points`]), and `c` or `Esc` hide it.
+    ///
+    /// Dragging the mouse over the view, or `s`, selects a region of the view. While it is
+    /// shown, the arrow keys move it, `+` and `-` shrink and grow it, `Enter` zooms on it and
+    /// `Esc` hides it. `r` resets the view to the domain and codomain it had at the start.
+    ///
+    /// The figure fills the terminal (see [`Plot::set_char_size`]) and is fitted again whenever
+    /// the terminal is resized. The size of the plot is restored when exiting.
+    ///
+    /// Requires the `interactive` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-0.3..1.2))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
+    ///
+    /// plot.show_interactive().unwrap();
+    /// ```
+    pub fn show_interactive(&mut self) -> io::Result<()> {
+        let _terminal = RawTerminal::enter()?;
+        let size = self.view.size.clone();
+        let result = self.explore();
+        self.view.size = size;
+        result
+    }
+
+    /// Draw the plot and handle the events until exiting. See [`Plot::show_interactive`].
+    fn explore(&mut self) -> io::Result<()> {
+        let mut renderer = Renderer::new(io::stdout());
+        let (columns, rows) = terminal::size()?;
+        self.fit_terminal(columns, rows);
+        let mut frame = String::new();
+        let original = (self.view.domain.clone(), self.view.codomain.clone());
+        // The position of the mouse pointer in the terminal, once it moved.
+        let mut pointer = None;
+        let mut data_cursor: Option<DataCursor> = None;
+        let mut selection: Option<Selection> = None;
+        // Where the mouse started dragging a selection.
+        let mut anchor = None;
+        loop {
+            frame.clear();
+            // The data cursor and the selection are drawn over the plots while rendering.
+            let point = data_cursor.and_then(|data_cursor| self.view.cursor_point(data_cursor));
+            if let Some(point) = point {
+                self.view.overlays.push(Box::new(CursorMark(point)));
+            }
+            if let Some(selection) = &selection {
+                self.view.overlays.push(Box::new(selection.clone()));
+            }
+            let rendered = self.render_to(self.decorations, color::enabled(), &mut frame);
+            self.view.overlays.clear();
+            // The position of the view in the terminal.
+            let origin = rendered.map_err(io::Error::other)?;
+            let readout = match (data_cursor, point) {
+                (Some(data_cursor), Some((x, y))) => Some(format!(
+                    "plot {}   series {}   x {x}   y {y}",
+                    data_cursor.plot + 1,
+                    data_cursor.series + 1
+                )),
+                _ => pointer
+                    .and_then(|pointer| self.view.point_under(origin, pointer))
+                    .map(|point| self.view.readout(point)),
+            };
+            frame.push_str(&readout.unwrap_or_default());
+            frame.push_str(match (&selection, data_cursor) {
+                (Some(_), _) => "\n← ↑ → ↓ move   + - resize   enter zoom   esc cancel   q quit",
+                (None, Some(_)) => {
+                    "\n← → move   tab next series   ↑ ↓ pan   + - zoom   c hide   q quit"
+                }
+                (None, None) => {
+                    "\n← ↑ → ↓ pan   + - zoom   s select   r reset   1-9 toggle   c cursor   q quit"
+                }
+            });
+            renderer.draw(&frame)?;
+
+            let key = match event::read()? {
+                Event::Key(key) => key,
+                Event::Mouse(mouse) => {
+                    pointer = Some((mouse.column, mouse.row));
+                    let point = self.view.point_under(origin, (mouse.column, mouse.row));
+                    match mouse.kind {
+                        MouseEventKind::Down(MouseButton::Left) => anchor = point,
+                        MouseEventKind::Drag(MouseButton::Left) => {
+                            if let (Some(anchor), Some(point)) = (anchor, point) {
+                                selection = Some(Selection::between(anchor, point));
+                            }
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                Event::Resize(columns, rows) => {
+                    // The lines of the previous frame were rewrapped by the terminal: they are
+                    // cleared instead of being overwritten.
+                    execute!(
+                        io::stdout(),
+                        terminal::Clear(terminal::ClearType::All),
+                        cursor::MoveTo(0, 0)
+                    )?;
+                    renderer.reset();
+                    self.fit_terminal(columns, rows);
+                    continue;
+                }
+                _ => continue,
+            };
+            if key.kind == KeyEventKind::Release {
+                continue;
+            }
+            let control = key.modifiers.contains(KeyModifiers::CONTROL);
+            if let Some(current) = selection.clone() {
+                // The selection after the key, or `None` for the keys handled like without the
+                // selection.
+                let next = match key.code {
+                    KeyCode::Left => Some(Some(current.panned(-PAN, 0.0))),
+                    KeyCode::Right => Some(Some(current.panned(PAN, 0.0))),
+                    KeyCode::Down => Some(Some(current.panned(0.0, -PAN))),
+                    KeyCode::Up => Some(Some(current.panned(0.0, PAN))),
+                    KeyCode::Char('+') | KeyCode::Char('=') => Some(Some(current.zoomed(ZOOM))),
+                    KeyCode::Char('-') => Some(Some(current.zoomed(1.0 / ZOOM))),
+                    KeyCode::Enter => {
+                        self.view.zoom_on(&current);
+                        Some(None)
+                    }
+                    KeyCode::Esc => Some(None),
+                    _ => None,
+                };
+                if let Some(next) = next {
+                    selection = next;
+                    continue;
+                }
+            }
+            if let Some(current) = data_cursor {
+                // The cursor after the key, or `None` for the keys handled like without the
+                // cursor.
+                let moved = match key.code {
+                    KeyCode::Left => Some(Some(self.view.moved_cursor(current, -1))),
+                    KeyCode::Right => Some(Some(self.view.moved_cursor(current, 1))),
+                    KeyCode::Tab => Some(Some(self.view.next_cursor(current).unwrap_or(current))),
+                    KeyCode::Char('c') if !control => Some(None),
+                    KeyCode::Esc => Some(None),
+                    _ => None,
+                };
+                if let Some(moved) = moved {
+                    data_cursor = moved;
+                    if let Some(point) = moved.and_then(|moved| self.view.cursor_point(moved)) {
+                        self.view.reveal(point);
+                    }
+                    continue;
+                }
+            } else if key.code == KeyCode::Char('c') && !control {
+                data_cursor = self.view.first_cursor();
+                continue;
+            }
+            let (domain, codomain) = (&mut self.view.domain, &mut self.view.codomain);
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => break,
+                KeyCode::Char('c') if control => break,
+                KeyCode::Left => *domain = pan(domain, -PAN),
+                KeyCode::Right => *domain = pan(domain, PAN),
+                KeyCode::Down => *codomain = pan(codomain, -PAN),
+                KeyCode::Up => *codomain = pan(codomain, PAN),
+                KeyCode::Char('+') | KeyCode::Char('=') => {
+                    *domain = zoom(domain, ZOOM);
+                    *codomain = zoom(codomain, ZOOM);
+                }
+                KeyCode::Char('-') => {
+                    *domain = zoom(domain, 1.0 / ZOOM);
+                    *codomain = zoom(codomain, 1.0 / ZOOM);
+                }
+                KeyCode::Char(key @ '1'..='9') => self.view.toggle(key as usize - '1' as usize),
+                KeyCode::Char('s') => selection = Some(Selection::centered(&self.view)),
+                KeyCode::Char('r') => (self.view.domain, self.view.codomain) = original.clone(),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Fit the figure to a terminal of `columns` by `rows` characters, leaving a line for the
+    /// status, a line for the help and a line for the cursor below it.
+    fn fit_terminal(&mut self, columns: u16, rows: u16) {
+        self.set_char_size(columns as usize, (rows as usize).saturating_sub(3));
+    }
+}
+
+/// The data cursor of the interactive mode: a data point of a series of a plot, selected with
+/// the keyboard. See [`Plot::show_interactive`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct DataCursor {
+    /// The index of the plot among the plots that were added.
+    plot: usize,
+    /// The index of the series among the series of the plot.
+    series: usize,
+    /// The index of the point among the points of the series, sorted by x.
+    index: usize,
+}
+
+/// A region of the view selected to zoom on it. See [`Plot::show_interactive`].
+#[derive(Clone, Debug, PartialEq)]
+struct Selection {
+    domain: Domain,
+    codomain: Domain,
+}
+
+impl Selection {
+    /// The selection between two opposite corners.
+    fn between((x0, y0): (f64, f64), (x1, y1): (f64, f64)) -> Self {
+        Self {
+            domain: Domain(x0.min(x1)..x0.max(x1)),
+            codomain: Domain(y0.min(y1)..y0.max(y1)),
+        }
+    }
+
+    /// The middle of the view, half as wide and half as high.
+    fn centered(view: &View) -> Self {
+        Self {
+            domain: zoom(&view.domain, 0.5),
+            codomain: zoom(&view.codomain, 0.5),
+        }
+    }
+
+    /// The selection moved by a proportion of its width and of its height.
+    fn panned(&self, x: f64, y: f64) -> Self {
+        Self {
+            domain: pan(&self.domain, x),
+            codomain: pan(&self.codomain, y),
+        }
+    }
+
+    /// The selection scaled around its center.
+    fn zoomed(&self, factor: f64) -> Self {
+        Self {
+            domain: zoom(&self.domain, factor),
+            codomain: zoom(&self.codomain, factor),
+        }
+    }
+}
+
+/// The selection is drawn in reverse video.
+impl DrawView for Selection {
+    fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+        canvas.highlight_rect(
+            self.domain.min(),
+            self.codomain.min(),
+            self.domain.max(),
+            self.codomain.max(),
+        );
+    }
+}
+
+/// The mark of the data cursor: the character holding the point, in reverse video.
+struct CursorMark((f64, f64));
+
+impl DrawView for CursorMark {
+    fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+        let (x, y) = self.0;
+        canvas.highlight_rect(x, y, x, y);
+    }
+}
+
+impl View {
+    /// The series of every plot that was added that can hold the data cursor, as the index of
+    /// the plot and the index of the series in the plot, in the order they were added. The
+    /// series of the hidden plots are skipped.
+    fn cursor_series(&self) -> Vec<(usize, usize)> {
+        let view = self.fitted();
+        (0..self.plots.len())
+            .filter(|plot| !self.hidden.contains(plot))
+            .flat_map(|plot| {
+                let count = self.plots[plot].1.series_points(&view).len();
+                (0..count).map(move |series| (plot, series))
+            })
+            .collect()
+    }
+
+    /// The points of the series `series` of the plot added at `plot` that can hold the data
+    /// cursor, sorted by x: none when the plot is hidden.
+    fn series_points(&self, plot: usize, series: usize) -> Vec<(f64, f64)> {
+        if self.hidden.contains(&plot) {
+            return Vec::new();
+        }
+        let Some((_, drawn)) = self.plots.get(plot) else {
+            return Vec::new();
+        };
+        let mut points = drawn
+            .series_points(&self.fitted())
+            .into_iter()
+            .nth(series)
+            .unwrap_or_default();
+        points.retain(|(x, y)| x.is_finite() && y.is_finite());
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        points
+    }
+
+    /// The point of the data cursor. The cursor stays on the last point when there are fewer
+    /// points than before (a graph sampled over a smaller domain, for example).
+    fn cursor_point(&self, cursor: DataCursor) -> Option<(f64, f64)> {
+        let points = self.series_points(cursor.plot, cursor.series);
+        let last = points.len().checked_sub(1)?;
+        Some(points[cursor.index.min(last)])
+    }
+
+    /// The data cursor moved by `offset` points, without leaving the points of its series.
+    fn moved_cursor(&self, cursor: DataCursor, offset: isize) -> DataCursor {
+        let last = self
+            .series_points(cursor.plot, cursor.series)
+            .len()
+            .saturating_sub(1);
+        let index = cursor.index.min(last).saturating_add_signed(offset);
+        DataCursor {
+            index: index.min(last),
+            ..cursor
+        }
+    }
+
+    /// The data cursor on the point of the series `series` of the plot added at `plot` closest
+    /// to `x`, if the series has points.
+    fn cursor_near(&self, (plot, series): (usize, usize), x: f64) -> Option<DataCursor> {
+        let points = self.series_points(plot, series);
+        let index = (0..points.len())
+            .min_by(|&a, &b| (points[a].0 - x).abs().total_cmp(&(points[b].0 - x).abs()))?;
+        Some(DataCursor {
+            plot,
+            series,
+            index,
+        })
+    }
+
+    /// The data cursor on the first series with points, near the center of the view.
+    fn first_cursor(&self) -> Option<DataCursor> {
+        let center = (self.domain.min() + self.domain.max()) / 2.0;
+        self.cursor_series()
+            .into_iter()
+            .find_map(|series| self.cursor_near(series, center))
+    }
+
+    /// The data cursor moved to the next series with points, near the x of its current point.
+    /// The series are cycled through: the cursor stays on its series when it is the only one.
+    fn next_cursor(&self, cursor: DataCursor) -> Option<DataCursor> {
+        let (x, _) = self.cursor_point(cursor)?;
+        let series = self.cursor_series();
+        let current = series
+            .iter()
+            .position(|&series| series == (cursor.plot, cursor.series))?;
+        (1..=series.len())
+            .map(|offset| series[(current + offset) % series.len()])
+            .find_map(|series| self.cursor_near(series, x))
+    }
+
+    /// Zoom on `selection`: it becomes the domain and the codomain of the view. An empty selection
+    /// (a click without dragging) is ignored.
+    fn zoom_on(&mut self, selection: &Selection) {
+        if selection.domain.range() > 0.0 && selection.codomain.range() > 0.0 {
+            self.domain = selection.domain.clone();
+            self.codomain = selection.codomain.clone();
+        }
+    }
+
+    /// Pan the view so that `point` is inside of it.
+    fn reveal(&mut self, (x, y): (f64, f64)) {
+        self.domain = shifted(&self.domain, x);
+        self.codomain = shifted(&self.codomain, y);
+    }
+
+    /// Hide the plot added at `index` (from 0) when it is shown, or show it when it is hidden.
+    fn toggle(&mut self, index: usize) {
+        if index < self.plots.len() && !self.hidden.remove(&index) {
+            self.hidden.insert(index);
+        }
+    }
+
+    /// The point of the plotting space at the center of the character at `column` and `row` of
+    /// the view, from its top left character, or `None` outside of the view.
+    fn point_at(&self, column: usize, row: usize) -> Option<(f64, f64)> {
+        let view = self.fitted();
+        let (width, height) = (view.size.w as f64, view.size.h as f64);
+        let (x, y) = (column as f64 * 2.0 + 1.0, row as f64 * 4.0 + 2.0);
+        if x >= width + 1.0 || y >= height + 2.0 {
+            return None;
+        }
+        Some((
+            view.x_scale.value_at(&view.domain, (x / width).min(1.0)),
+            view.y_scale
+                .value_at(&view.codomain, (1.0 - y / height).max(0.0)),
+        ))
+    }
+
+    /// The data point of the plots closest to (`x`, `y`) on the screen, among the points inside
+    /// of the view. See [`DrawView::points`].
+    fn nearest_point(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let view = self.fitted();
+        // The distances are measured in pixels, where a character is 2 by 4 pixels.
+        let project = |(x, y): (f64, f64)| {
+            (
+                view.x_scale.fraction(&view.domain, x) * view.size.w as f64,
+                view.y_scale.fraction(&view.codomain, y) * view.size.h as f64,
+            )
+        };
+        let target = project((x, y));
+        self.all_plots()
+            .flat_map(|(_, plot)| plot.points(&view))
+            .filter(|&(x, y)| {
+                (view.domain.min()..=view.domain.max()).contains(&x)
+                    && (view.codomain.min()..=view.codomain.max()).contains(&y)
+            })
+            .map(|point| {
+                let (px, py) = project(point);
+                ((px - target.0).hypot(py - target.1), point)
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, point)| point)
+    }
+
+    /// The point under the mouse pointer at `column` and `row` of the terminal, when the first
+    /// character of the view is at `origin`. `None` outside of the view.
+    fn point_under(&self, origin: (usize, usize), (column, row): (u16, u16)) -> Option<(f64, f64)> {
+        let column = (column as usize).checked_sub(origin.0)?;
+        let row = (row as usize).checked_sub(origin.1)?;
+        self.point_at(column, row)
+    }
+
+    /// The status line for the mouse pointer on (`x`, `y`): its coordinates and the closest data
+    /// point.
+    fn readout(&self, (x, y): (f64, f64)) -> String {
+        let (x_format, y_format) = (self.x_format, self.y_format);
+        let mut readout = format!("x {}   y {}", value(x_format, x), value(y_format, y));
+        if let Some((x, y)) = self.nearest_point(x, y) {
+            let (x, y) = (value(x_format, x), value(y_format, y));
+            readout.push_str(&format!("   nearest ({x}, {y})"));
+        }
+        readout
+    }
+}
+
+/// Write `value` for a readout: with the format of its axis, or with more decimals than the
+/// ticks for the default format.
+fn value(format: TickFormat, value: f64) -> String {
+    match format {
+        TickFormat::Decimal => format!("{value:.4}"),
+        format => format.format(value),
+    }
+}
+
+/// Move `domain` by a proportion of its range.
+fn pan(domain: &Domain, proportion: f64) -> Domain {
+    let offset = (domain.max() - domain.min()) * proportion;
+    Domain(domain.min() + offset..domain.max() + offset)
+}
+
+/// Move `domain` as little as possible so that it contains `value`.
+fn shifted(domain: &Domain, value: f64) -> Domain {
+    let offset = match value {
+        value if value < domain.min() => value - domain.min(),
+        value if value > domain.max() => value - domain.max(),
+        _ => 0.0,
+    };
+    Domain(domain.min() + offset..domain.max() + offset)
+}
+
+/// Scale the range of `domain` around its center.
+fn zoom(domain: &Domain, factor: f64) -> Domain {
+    let center = (domain.min() + domain.max()) / 2.0;
+    let half = (domain.max() - domain.min()) * factor / 2.0;
+    Domain(center - half..center + half)
+}
+
+/// The terminal in raw mode on the alternate screen. The terminal is restored when dropped, even
+/// when an error occurs while drawing.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(
+            io::stdout(),
+            terminal::EnterAlternateScreen,
+            event::EnableMouseCapture,
+            cursor::Hide,
+            cursor::MoveTo(0, 0)
+        )?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(
+            io::stdout(),
+            event::DisableMouseCapture,
+            cursor::Show,
+            terminal::LeaveAlternateScreen
+        );
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{plot, AxisMode, Size};
+
+    #[test]
+    fn pan_and_zoom() {
+        assert_eq!(pan(&Domain(0.0..10.0), 0.1), Domain(1.0..11.0));
+        assert_eq!(zoom(&Domain(0.0..10.0), 0.8), Domain(1.0..9.0));
+    }
+
+    #[test]
+    fn toggled_plots() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..1.0))
+            .set_codomain(Domain(0.0..1.0))
+            .set_size(Size::new(8, 8))
+            .set_axis_mode(AxisMode::None)
+            .add_plot(Box::new(plot::Graph::new(|x| x)))
+            .add_plot(Box::new(plot::Graph::new(|x| 1.0 - x)));
+        let both = plot.to_string();
+        plot.view.toggle(1);
+        plot.view.toggle(5);
+        assert_ne!(plot.to_string(), both);
+        assert_eq!(plot.view.hidden.len(), 1);
+        plot.view.toggle(1);
+        assert_eq!(plot.to_string(), both);
+    }
+
+    #[test]
+    fn data_cursor() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..10.0))
+            .set_codomain(Domain(0.0..10.0))
+            .add_plot(Box::new(plot::Bars::new(vec![1.0])))
+            .add_plot(Box::new(plot::Lines::new(vec![
+                (3.0, 1.0),
+                (1.0, 2.0),
+                (8.0, 3.0),
+            ])))
+            .add_plot(Box::new(plot::Scatter::new(vec![(2.0, 9.0), (6.0, 4.0)])));
+        let view = &mut plot.view;
+        let cursor = view.first_cursor().unwrap();
+        assert_eq!(
+            cursor,
+            DataCursor {
+                plot: 1,
+                series: 0,
+                index: 1
+            }
+        );
+        let last = view.moved_cursor(cursor, 5);
+        assert_eq!(view.cursor_point(last), Some((8.0, 3.0)));
+        assert_eq!(
+            view.cursor_point(view.moved_cursor(last, -1)),
+            Some((3.0, 1.0))
+        );
+        assert_eq!(view.moved_cursor(cursor, -5).index, 0);
+        let next = view.next_cursor(cursor).unwrap();
+        assert_eq!(view.cursor_point(next), Some((2.0, 9.0)));
+        assert_eq!(view.next_cursor(next).unwrap().plot, 1);
+        view.reveal((12.0, -1.0));
+        assert_eq!(view.domain, Domain(2.0..12.0));
+        assert_eq!(view.codomain, Domain(-1.0..9.0));
+    }
+
+    #[test]
+    fn data_cursor_on_series() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..10.0))
+            .set_codomain(Domain(0.0..10.0))
+            .add_plot(Box::new(plot::MultiLines::new(
+                vec![1.0, 5.0],
+                vec![vec![1.0, 2.0], vec![8.0, 9.0]],
+            )));
+        let view = &plot.view;
+        let cursor = view.first_cursor().unwrap();
+        assert_eq!(view.cursor_point(cursor), Some((5.0, 2.0)));
+        // The cursor stays on its series, and moves to the other one with `Tab`.
+        assert_eq!(
+            view.cursor_point(view.moved_cursor(cursor, 1)),
+            Some((5.0, 2.0))
+        );
+        let next = view.next_cursor(cursor).unwrap();
+        assert_eq!((next.plot, next.series), (0, 1));
+        assert_eq!(view.cursor_point(next), Some((5.0, 9.0)));
+        assert_eq!(view.next_cursor(next).unwrap().series, 0);
+    }
+
+    #[test]
+    fn zoom_on_selection() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..8.0))
+            .set_codomain(Domain(0.0..4.0));
+        let selection = Selection::centered(&plot.view);
+        assert_eq!(selection, Selection::between((6.0, 1.0), (2.0, 3.0)));
+        assert_eq!(
+            selection.zoomed(0.5).panned(1.0, 0.0).domain,
+            Domain(5.0..7.0)
+        );
+        plot.view
+            .zoom_on(&Selection::between((1.0, 1.0), (1.0, 2.0)));
+        assert_eq!(plot.view.domain, Domain(0.0..8.0));
+        plot.view.zoom_on(&selection);
+        assert_eq!(plot.view.domain, Domain(2.0..6.0));
+        assert_eq!(plot.view.codomain, Domain(1.0..3.0));
+    }
+
+    #[test]
+    fn pointer_readout() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..10.0))
+            .set_codomain(Domain(0.0..10.0))
+            .set_size(Size::new(20, 40))
+            .add_plot(Box::new(plot::Scatter::new(vec![(1.0, 9.0), (8.0, 2.0)])));
+        assert_eq!(plot.view.point_at(0, 0), Some((0.5, 9.5)));
+        assert_eq!(plot.view.point_at(10, 0), None);
+        assert_eq!(plot.view.nearest_point(6.0, 1.0), Some((8.0, 2.0)));
+        assert_eq!(plot.view.point_under((2, 1), (2, 0)), None);
+        assert_eq!(plot.view.point_under((2, 1), (3, 2)), Some((1.5, 8.5)));
+        assert_eq!(
+            plot.view.readout((0.5, 9.5)),
+            "x 0.5000   y 9.5000   nearest (1.0000, 9.0000)"
+        );
+    }
+}
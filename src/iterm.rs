@@ -0,0 +1,37 @@
+//! Drawing images with the inline images of iTerm2 (`OSC 1337`).
+
+use crate::color::Rgb;
+use crate::raster;
+use base64::Engine;
+
+/// Whether the terminal is iTerm2, from its environment variables.
+pub(crate) fn supported() -> bool {
+    let var = |name| std::env::var(name).unwrap_or_default();
+    var("TERM_PROGRAM") == "iTerm.app" || var("LC_TERMINAL") == "iTerm2"
+}
+
+/// Encode `pixels` (rows of colors, `None` for the pixels that are not set) as a PNG image
+/// stretched over `columns` by `rows` characters. The pixels that are not set are transparent.
+pub(crate) fn encode(pixels: &[Vec<Option<Rgb>>], columns: usize, rows: usize) -> String {
+    let png = raster::png(pixels);
+    let size = png.len();
+    let data = base64::engine::general_purpose::STANDARD.encode(png);
+    format!(
+        "\x1b]1337;File=inline=1;size={size};width={columns};height={rows};\
+         preserveAspectRatio=0:{data}\x07"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inline_png() {
+        let image = encode(&[vec![Some((255, 0, 0)), None]], 1, 1);
+        let prefix = "\x1b]1337;File=inline=1;size=";
+        assert!(image.starts_with(prefix));
+        assert!(image.contains(";width=1;height=1;preserveAspectRatio=0:iVBORw0KGgo"));
+        assert!(image.ends_with('\x07'));
+    }
+}
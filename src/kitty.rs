@@ -0,0 +1,61 @@
+//! Drawing images with the graphics protocol of kitty, also supported by WezTerm.
+
+use crate::color::Rgb;
+use crate::raster;
+use base64::Engine;
+
+/// The size of the chunks of base64 data, the maximum accepted by the protocol.
+const CHUNK: usize = 4096;
+
+/// Whether the terminal supports the graphics protocol, from its environment variables.
+pub(crate) fn supported() -> bool {
+    let var = |name| std::env::var(name).unwrap_or_default();
+    std::env::var_os("KITTY_WINDOW_ID").is_some()
+        || var("TERM").contains("kitty")
+        || var("TERM_PROGRAM") == "WezTerm"
+}
+
+/// Encode `pixels` (rows of colors, `None` for the pixels that are not set) as a PNG image
+/// scaled to fit `columns` by `rows` characters. The pixels that are not set are transparent.
+pub(crate) fn encode(pixels: &[Vec<Option<Rgb>>], columns: usize, rows: usize) -> String {
+    let data = base64::engine::general_purpose::STANDARD.encode(raster::png(pixels));
+    let chunks = data.as_bytes().chunks(CHUNK).collect::<Vec<_>>();
+    let mut out = String::new();
+    for (index, chunk) in chunks.iter().enumerate() {
+        // `m=1` while more chunks follow. The first chunk holds the options: a PNG image
+        // (`f=100`), transmitted and displayed (`a=T`) without any response (`q=2`).
+        let more = (index + 1 < chunks.len()) as u8;
+        let options = match index {
+            0 => format!("f=100,a=T,q=2,c={columns},r={rows},m={more}"),
+            _ => format!("m={more}"),
+        };
+        let chunk = std::str::from_utf8(chunk).expect("base64 is ASCII");
+        out.push_str(&format!("\x1b_G{options};{chunk}\x1b\\"));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_png() {
+        let pixels = vec![vec![Some((255, 0, 0)); 20]; 10];
+        let image = encode(&pixels, 5, 3);
+        assert!(image.starts_with("\x1b_Gf=100,a=T,q=2,c=5,r=3,m=0;iVBORw0KGgo"));
+        assert_eq!(image.matches("\x1b_G").count(), 1);
+        // Pixels hard to compress, which need several chunks.
+        let pixels = (0..100u32)
+            .map(|y| {
+                (0..100u32)
+                    .map(|x| Some(((x * y) as u8, (x * 7 + y * 13) as u8, (x ^ y) as u8)))
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+        let image = encode(&pixels, 25, 25);
+        assert!(image.starts_with("\x1b_Gf=100,a=T,q=2,c=25,r=25,m=1;"));
+        assert!(image.contains("\x1b_Gm=0;"));
+        assert!(image.ends_with("\x1b\\"));
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{text, Color};
+
+/// An entry of the legend of a plot: the name of a series and the color it is drawn with.
+///
+/// See [`DrawView::legend`](crate::DrawView::legend).
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegendEntry {
+    /// The name of the series.
+    pub label: String,
+    /// The color of the series, if it is drawn with a specific color.
+    pub color: Option<Color>,
+}
+
+impl LegendEntry {
+    pub fn new(label: &str, color: Option<Color>) -> Self {
+        Self {
+            label: String::from(label),
+            color,
+        }
+    }
+
+    /// The entry as it is printed: a short line with the color of the series (when `colors` is
+    /// `true`) and its name.
+    fn display(&self, colors: bool) -> String {
+        let line = match self.color {
+            Some(color) => color.paint("──", colors),
+            None => String::from("──"),
+        };
+        format!("{line} {}", self.label)
+    }
+}
+
+/// Lay out the entries on as many rows of `width` columns as needed, with their colors when
+/// `colors` is `true`.
+pub(crate) fn rows(entries: &[LegendEntry], width: usize, colors: bool) -> Vec<String> {
+    let mut rows: Vec<String> = Vec::new();
+    for entry in entries.iter().map(|entry| entry.display(colors)) {
+        match rows.last_mut() {
+            Some(row) if text::width(row) + 3 + text::width(&entry) <= width => {
+                row.push_str("   ");
+                row.push_str(&entry);
+            }
+            _ => rows.push(entry),
+        }
+    }
+    rows
+}
@@ -17,6 +17,33 @@
 //!
 //! [`Plot`] is the entry point of the library. Start by creating a [`Plot`] and play with it!
 //!
+//! ### Optional features
+//!  - `serde`: implement `Serialize` and `Deserialize` for [`PlotSpec`], [`ViewState`], [`Domain`]
+//!    and [`Size`].
+//!  - `json`: load a [`PlotSpec`] from JSON with `PlotSpec::from_json`.
+//!  - `toml`: load a [`PlotSpec`] from TOML with `PlotSpec::from_toml`.
+//!  - `cli`: build the `termplot` binary, which plots numbers or CSV records read from the
+//!    standard input (`cargo install termplot --features cli`).
+//!  - `ndarray`: create plots directly from `ndarray` arrays (`Lines::from_arrays`,
+//!    `Heatmap::from_array`, etc...).
+//!  - `polars`: create plots from the columns of a `polars` data frame (`Lines::from_columns`,
+//!    `Histogram::from_column`, etc...).
+//!  - `interactive`: explore a plot in the terminal, panning with the arrow keys and zooming
+//!    with `+` and `-` (`Plot::show_interactive`), and fit the plots of a training monitor to the
+//!    terminal (`TrainingMonitor::set_fit_terminal`).
+//!  - `image`: save animations as animated GIFs (`Animation::save_gif`).
+//!  - `kitty`: draw the view as an image with the graphics protocol of kitty and WezTerm
+//!    (`Plot::set_backend(Backend::Kitty)`).
+//!  - `iterm`: draw the view as an inline image of iTerm2 (`Plot::set_backend(Backend::ITerm2)`).
+//!  - `sixel`: draw the view as a sixel image in the terminals supporting them
+//!    (`Plot::set_backend(Backend::Sixel)`).
+//!  - `complex`: draw complex functions with domain coloring (`plot::DomainColoring`).
+//!  - `dsp`: plot the amplitude spectrum of a signal (`plot::Spectrum::from_samples`).
+//!  - `prometheus`: plot metrics queried from a Prometheus server or scraped from an exporter
+//!    (`data::prometheus`).
+//!  - `criterion`: compare the timings of criterion benchmarks after `cargo bench`
+//!    (`data::criterion`).
+//!
 //! ## Examples
 //!
 //! ### Plotting a function
@@ -103,8 +130,63 @@
 use std::fmt;
 use std::ops;
 
+mod animate;
+#[cfg(feature = "ndarray")]
+mod array;
+mod border;
+mod braille;
+mod clip;
+pub mod color;
+mod colorbar;
+#[cfg(any(feature = "prometheus", feature = "criterion"))]
+pub mod data;
+#[cfg(feature = "polars")]
+mod dataframe;
+mod figure;
+mod financial;
+mod grid;
+mod html;
+#[cfg(feature = "interactive")]
+pub mod interactive;
+#[cfg(feature = "iterm")]
+mod iterm;
+#[cfg(feature = "kitty")]
+mod kitty;
+mod legend;
+mod monitor;
 pub mod plot;
+#[cfg(any(feature = "kitty", feature = "iterm"))]
+mod raster;
+mod renderer;
+mod scale;
+#[cfg(feature = "sixel")]
+mod sixel;
+mod source;
+mod spec;
+mod text;
+mod theme;
 mod ticks;
+mod title;
+mod watermark;
+
+pub use animate::{animate, Animation};
+pub use border::{Border, BorderSides};
+pub use color::Color;
+pub use figure::Figure;
+pub use financial::FinancialChart;
+pub use legend::LegendEntry;
+pub use monitor::TrainingMonitor;
+#[cfg(feature = "complex")]
+pub use num_complex::Complex;
+pub use renderer::Renderer;
+pub use scale::Scale;
+pub use source::{ChannelSource, DataSource};
+#[cfg(any(feature = "json", feature = "toml"))]
+pub use spec::SpecError;
+pub use spec::{PlotSpec, SeriesSpec};
+pub use theme::Theme;
+pub use ticks::TickFormat;
+pub use title::{Align, TitleStyle};
 
 /// A drawable component on the view.
 pub trait DrawView {
@@ -143,9 +225,74 @@ pub trait DrawView {
     /// println!("{plot}");
     /// ```
     fn draw(&self, view: &View, canvas: &mut ViewCanvas);
+
+    /// The entries of the component in the legend of the plot.
+    ///
+    /// By default, a component has no entry in the legend. `view` gives access to the theme of
+    /// the plot, see [`View::series_color`].
+    fn legend(&self, _view: &View) -> Vec<LegendEntry> {
+        Vec::new()
+    }
+
+    /// The domain the component must be shown in, replacing the domain of the plot.
+    ///
+    /// By default, a component does not request a domain. When several components request a
+    /// domain, the first one added is used. See [`plot::RollingSeries`] for a component following
+    /// its data.
+    fn domain(&self) -> Option<Domain> {
+        None
+    }
+
+    /// Labels written below the x axis, centered on their x coordinate, for components drawn
+    /// along a categorical axis.
+    ///
+    /// By default, a component has no labels. When a component has labels, they are written
+    /// instead of the values of the x axis. See [`plot::Bars::labeled`].
+    fn x_labels(&self) -> Vec<(f64, String)> {
+        Vec::new()
+    }
+
+    /// Labels written left of the y axis, on the row of their y coordinate, for components drawn
+    /// along a categorical axis.
+    ///
+    /// By default, a component has no labels. When a component has labels, they are written
+    /// instead of the values of the y axis. See [`plot::Gantt`].
+    fn y_labels(&self) -> Vec<(f64, String)> {
+        Vec::new()
+    }
+
+    /// The colormap the component colors its values with, and the range of values it spans.
+    ///
+    /// By default, a component has no colormap. When a component has one, a colorbar showing the
+    /// value of each color is drawn on the right of the view. When several components have a
+    /// colormap, the first one added is used. See [`plot::Heatmap::with_colormap`].
+    fn colorbar(&self) -> Option<(color::Colormap, Domain)> {
+        None
+    }
+
+    /// The data points of the component, read by the pointer of the interactive mode (see
+    /// [`Plot::show_interactive`]).
+    ///
+    /// By default, a component has no points. A component drawing a function can sample it over
+    /// the domain of `view`, like [`plot::Graph`].
+    fn points(&self, _view: &View) -> Vec<(f64, f64)> {
+        Vec::new()
+    }
+
+    /// The data points of each series drawn by the component, which the data cursor of the
+    /// interactive mode moves along (see [`Plot::show_interactive`]).
+    ///
+    /// By default, a component draws a single series made of its [`DrawView::points`]. A
+    /// component drawing several series gives their points separately, like
+    /// [`plot::MultiLines`].
+    fn series_points(&self, view: &View) -> Vec<Vec<(f64, f64)>> {
+        vec![self.points(view)]
+    }
 }
 
 /// A size.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Size {
     /// The width.
     w: usize,
@@ -157,6 +304,29 @@ impl Size {
     pub fn new(w: usize, h: usize) -> Self {
         Self { w, h }
     }
+
+    /// The size of `columns` by `rows` characters of the terminal, each one holding 2 by 4
+    /// pixels.
+    ///
+    /// This is the size of the view only. Use [`Plot::set_char_size`] for the size of the whole
+    /// figure, decorations included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::Size;
+    ///
+    /// assert_eq!(Size::chars(40, 10), Size::new(80, 40));
+    /// ```
+    pub fn chars(columns: usize, rows: usize) -> Self {
+        Self::new(columns * 2, rows * 4)
+    }
+
+    /// The number of columns and rows of the characters holding the pixels.
+    #[cfg(any(feature = "kitty", feature = "iterm"))]
+    pub(crate) fn cells(&self) -> (usize, usize) {
+        (self.w.div_ceil(2), self.h.div_ceil(4))
+    }
 }
 
 impl Default for Size {
@@ -165,6 +335,230 @@ impl Default for Size {
     }
 }
 
+/// Where a decoration is placed around the view.
+///
+/// The decorations do not all support every position: the corners are only used by the
+/// watermark, see [`Plot::set_watermark`]. More positions may be added in future releases.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Position {
+    /// Below the figure.
+    #[default]
+    Bottom,
+    /// On the left of the figure.
+    Left,
+    /// On the right of the figure.
+    Right,
+    /// In the top left corner of the view.
+    TopLeft,
+    /// In the top right corner of the view.
+    TopRight,
+    /// In the bottom left corner of the view.
+    BottomLeft,
+    /// In the bottom right corner of the view.
+    BottomRight,
+}
+
+/// How the units of the x and y axis are scaled relatively to each other. See
+/// [`Plot::set_aspect`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Aspect {
+    /// The domain and the codomain are stretched to fill the view.
+    #[default]
+    Auto,
+    /// A unit of the x axis is as long as a unit of the y axis, so circles look like circles.
+    Equal,
+}
+
+/// Where the x and y axis are drawn in the view. See [`Plot::set_axis_mode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AxisMode {
+    /// Around the view, on the edges of the domain and the codomain.
+    Box,
+    /// Through `x = 0` and `y = 0`. An axis is not drawn when zero is outside of the view.
+    #[default]
+    Zero,
+    /// The axis are not drawn.
+    None,
+}
+
+/// Which decorations are drawn around the view. All of them are drawn by default. See
+/// [`Plot::set_decorations`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Decorations {
+    /// The border around the figure, with the sides of [`Plot::set_border_sides`].
+    pub border: bool,
+    /// The title, embedded in the top border or on its own lines.
+    pub title: bool,
+    /// The values written below the x axis.
+    pub x_ticks: bool,
+    /// The values written left of the y axis.
+    pub y_ticks: bool,
+    /// The label of the x axis.
+    pub x_label: bool,
+    /// The label of the y axis.
+    pub y_label: bool,
+    /// The legend below the view, listing the named series.
+    pub legend: bool,
+}
+
+impl Decorations {
+    /// No decoration is drawn: only the view, with the caption when there is one.
+    pub const NONE: Self = Self {
+        border: false,
+        title: false,
+        x_ticks: false,
+        y_ticks: false,
+        x_label: false,
+        y_label: false,
+        legend: false,
+    };
+}
+
+impl Default for Decorations {
+    fn default() -> Self {
+        Self {
+            border: true,
+            title: true,
+            x_ticks: true,
+            y_ticks: true,
+            x_label: true,
+            y_label: true,
+            legend: true,
+        }
+    }
+}
+
+/// What a [`ViewCanvas`] does with the points outside of the view. See
+/// [`ViewCanvas::set_out_of_view`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutOfView {
+    /// The points are moved to the closest edge of the view, so that they stay visible.
+    #[default]
+    Clamp,
+    /// The points are not drawn, and neither are the cells and rectangles entirely outside of
+    /// the view.
+    Skip,
+}
+
+/// A plot added to a [`Plot`]: its position among the plots added with [`Plot::add_plot`] and
+/// [`Plot::add_plot_with_order`], from `0`, whatever their layers. Static plots are not counted.
+/// See [`Plot::set_visible`] and [`Plot::set_style`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SeriesId(pub usize);
+
+/// How a plot is drawn, overriding the choices of the plot. See [`Plot::set_style`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Style {
+    /// The color of the whole plot, replacing the colors chosen by the plot and its entries in
+    /// the legend.
+    pub color: Option<Color>,
+    /// The minimum width of the lines of the plot, in pixels. See
+    /// [`ViewCanvas::line_with_width`].
+    pub line_width: f64,
+}
+
+/// The part of the plane shown by the view of a [`Plot`], and how: its domain, codomain, size and
+/// scales. See [`Plot::view_state`] and [`Plot::apply_view_state`].
+///
+/// With the `serde` feature, the state can be stored between runs, to come back to the view
+/// explored in an interactive session or followed by a monitoring tool.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewState {
+    /// The range of the x axis shown by the view. See [`Plot::set_domain`].
+    pub domain: Domain,
+    /// The range of the y axis shown by the view. See [`Plot::set_codomain`].
+    pub codomain: Domain,
+    /// The size of the view, in pixels.
+    pub size: Size,
+    /// How the values are placed along the x axis. See [`Plot::set_x_scale`].
+    pub x_scale: Scale,
+    /// How the values are placed along the y axis. See [`Plot::set_y_scale`].
+    pub y_scale: Scale,
+}
+
+/// What fills the empty area of the view. See [`Plot::set_background`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Fill {
+    /// The empty characters of the view are spaces.
+    #[default]
+    None,
+    /// The empty characters of the view are this character, written faintly.
+    Char(char),
+    /// The whole view is drawn on a background of this color.
+    Color(Color),
+}
+
+/// How the view is drawn in the terminal. See [`Plot::set_backend`].
+///
+/// The backends other than [`Backend::Braille`] are only available with their feature, so the
+/// enum is non exhaustive: enabling a feature in another crate does not break the matches on it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Backend {
+    /// Braille characters, each one holding 2 by 4 pixels. Works in every terminal.
+    #[default]
+    Braille,
+    /// A sixel image, drawn at a higher resolution than the braille characters. Only shown by
+    /// the terminals supporting sixel graphics, like xterm, mlterm or foot.
+    #[cfg(feature = "sixel")]
+    Sixel,
+    /// An image sent with the graphics protocol of kitty, also supported by WezTerm, drawn at
+    /// the resolution of the screen. Falls back to [`Backend::Braille`] in other terminals.
+    #[cfg(feature = "kitty")]
+    Kitty,
+    /// An inline image of iTerm2, drawn at the resolution of the screen. Falls back to
+    /// [`Backend::Braille`] in other terminals.
+    #[cfg(feature = "iterm")]
+    ITerm2,
+}
+
+/// Space added around the domain and the codomain so that the data does not touch the edges of
+/// the view. See [`Plot::set_padding`].
+///
+/// The padding is in the units of the axis, or in percent of the range of the axis when
+/// `percent` is `true`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Padding {
+    pub top: f64,
+    pub bottom: f64,
+    pub left: f64,
+    pub right: f64,
+    pub percent: bool,
+}
+
+impl Padding {
+    /// A padding in the units of the axis.
+    pub fn new(top: f64, bottom: f64, left: f64, right: f64) -> Self {
+        Self {
+            top,
+            bottom,
+            left,
+            right,
+            percent: false,
+        }
+    }
+
+    /// A padding in percent of the range of the axis.
+    pub fn percent(top: f64, bottom: f64, left: f64, right: f64) -> Self {
+        Self {
+            percent: true,
+            ..Self::new(top, bottom, left, right)
+        }
+    }
+
+    /// Extend `domain` by `before` at its start and `after` at its end.
+    fn apply(&self, domain: &Domain, before: f64, after: f64) -> Domain {
+        let scale = if self.percent {
+            domain.range() / 100.0
+        } else {
+            1.0
+        };
+        Domain(domain.min() - before * scale..domain.max() + after * scale)
+    }
+}
+
 /// A container used for adding plots.
 ///
 /// # Examples
@@ -190,8 +584,13 @@ pub struct Plot {
     title: String,
     x_label: String,
     y_label: String,
+    y_label_position: Position,
+    caption: String,
+    title_style: TitleStyle,
+    border_sides: BorderSides,
     view: View,
-    with_decoration: bool,
+    decorations: Decorations,
+    backend: Backend,
 }
 
 impl Default for Plot {
@@ -200,8 +599,13 @@ impl Default for Plot {
             title: String::new(),
             x_label: String::new(),
             y_label: String::new(),
+            y_label_position: Position::Bottom,
+            caption: String::new(),
+            title_style: TitleStyle::default(),
+            border_sides: BorderSides::default(),
             view: View::default(),
-            with_decoration: true,
+            decorations: Decorations::default(),
+            backend: Backend::Braille,
         }
     }
 }
@@ -225,280 +629,2050 @@ impl Plot {
     /// println!("{plot}");
     /// ```
     pub fn add_plot(&mut self, plot: Box<dyn DrawView>) -> &mut Self {
-        self.view.plots.push(plot);
-        self
+        self.add_plot_with_order(plot, 0)
     }
 
-    /// Set the domain (range of the x axis) of the plot.
+    /// Add a plot or graph to the view, drawn in the layer `z`.
     ///
-    /// By default the domain is from -10 to 10.
+    /// Plots are drawn from the lowest layer to the highest, so plots of a higher layer are drawn
+    /// over plots of a lower layer. Plots of the same layer are drawn in the order they were
+    /// added. [`Plot::add_plot`] adds plots in the layer `0`.
     ///
-    /// This function sets the minimum and maximum x values in the graph.
-    pub fn set_domain(&mut self, domain: Domain) -> &mut Self {
-        self.view.domain = domain;
+    /// Pixels of the canvas are never erased, but the color of a character and the text written
+    /// in it come from the last plot drawn in it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..6.0))
+    ///     .set_codomain(Domain(0.0..10.0))
+    ///     .add_plot_with_order(Box::new(plot::Graph::new(|x| x + 2.0)), 1)
+    ///     .add_plot(Box::new(plot::Bars::new(vec![2.0, 5.0, 1.0, 8.0, 9.0, 1.0])));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn add_plot_with_order(&mut self, plot: Box<dyn DrawView>, z: i32) -> &mut Self {
+        self.view.plots.push((z, plot));
         self
     }
 
-    /// Set the codomain (range of the y axis) of the plot.
+    /// Add a plot or graph that does not change between frames, like a background curve or a
+    /// reference line, in the layer `z`.
     ///
-    /// By default the codomain is from -10 to 10.
+    /// The static plots and the axis are drawn once and their pixels are kept: the next renders
+    /// only draw the other plots over them, as long as the view (its domain, codomain, size,
+    /// axis and theme) does not change. This saves evaluating expensive graphs and drawing the
+    /// axis at every frame of a live plot. The static plots and the axis are always drawn below
+    /// the other plots, in the order of their layers.
     ///
-    /// This function sets the minimum and maximum y values in the graph.
-    pub fn set_codomain(&mut self, codomain: Domain) -> &mut Self {
-        self.view.codomain = codomain;
+    /// Use [`Plot::clear_plots`] to replace the other plots at each frame.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-1.2..1.2))
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_static_plot(Box::new(plot::Graph::new(|x| x.sin() / x)), 0);
+    ///
+    /// let mut renderer = Renderer::new(std::io::stdout());
+    /// for frame in 0..10 {
+    ///     let phase = frame as f64 / 10.0;
+    ///     plot.clear_plots()
+    ///         .add_plot(Box::new(plot::Graph::new(move |x| (x + phase).sin())));
+    ///     renderer.draw(&plot.to_string()).unwrap();
+    /// }
+    /// ```
+    pub fn add_static_plot(&mut self, plot: Box<dyn DrawView>, z: i32) -> &mut Self {
+        self.view.static_plots.push((z, plot));
         self
     }
 
-    /// Set the title of the plot.
-    pub fn set_title(&mut self, title: &str) -> &mut Self {
-        self.title = String::from(title);
+    /// Remove the plots and graphs that were added, except the static plots (see
+    /// [`Plot::add_static_plot`]).
+    ///
+    /// The visibility and the styles set with [`Plot::set_visible`] and [`Plot::set_style`] are
+    /// kept, and apply to the plots added next at the same positions.
+    pub fn clear_plots(&mut self) -> &mut Self {
+        self.view.plots.clear();
         self
     }
 
-    /// Set the label of the x axis.
+    /// Show or hide the plot `series`. A hidden plot is not drawn and has no entry in the
+    /// legend, but it is kept: showing it again draws it like before. By default, every plot is
+    /// visible.
     ///
-    /// The label is shown at the bottom of the figure.
-    pub fn set_x_label(&mut self, label: &str) -> &mut Self {
-        self.x_label = String::from(label);
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-1.5..1.5))
+    ///     .set_size(Size::new(50, 20))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin())))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.cos())));
+    ///
+    /// plot.set_visible(SeriesId(1), false);
+    /// println!("{plot}");
+    /// plot.set_visible(SeriesId(1), true);
+    /// println!("{plot}");
+    /// ```
+    pub fn set_visible(&mut self, series: SeriesId, visible: bool) -> &mut Self {
+        match visible {
+            true => self.view.hidden.remove(&series.0),
+            false => self.view.hidden.insert(series.0),
+        };
         self
     }
 
-    /// Set the label of the y axis.
+    /// Set how the plot `series` is drawn, to emphasize it or to fade it without changing it. See
+    /// [`Style`]. `Style::default()` draws the plot as it chooses.
     ///
-    /// The label is shown at the bottom of the figure.
-    pub fn set_y_label(&mut self, label: &str) -> &mut Self {
-        self.y_label = String::from(label);
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-1.5..1.5))
+    ///     .set_size(Size::new(50, 20))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin())))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.cos())));
+    ///
+    /// let emphasized = Style {
+    ///     color: Some(Color::Red),
+    ///     line_width: 2.0,
+    /// };
+    /// plot.set_style(SeriesId(0), emphasized);
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_style(&mut self, series: SeriesId, style: Style) -> &mut Self {
+        self.view.styles.insert(series.0, style);
         self
     }
 
-    /// Set the size of the view. This does not include decorations around the plot.
+    /// Set the layer in which the axis are drawn. See [`Plot::add_plot_with_order`].
     ///
-    /// The size is not the number of chars but the number of pixels. Pixels are smaller than
-    /// chars. A char in the terminal is 2 by 4 pixels.
-    pub fn set_size(&mut self, size: Size) -> &mut Self {
-        self.view.size = size;
+    /// The axis are drawn below the plots of the same layer. By default, the axis are in the layer
+    /// `0` and are drawn below every plot added with [`Plot::add_plot`]. Use a higher layer to
+    /// draw the axis above the plots.
+    pub fn set_axis_order(&mut self, z: i32) -> &mut Self {
+        self.view.axis_order = z;
         self
     }
-}
-
-impl fmt::Display for Plot {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let rows = self.view.drawing(self.with_decoration);
-        if !self.with_decoration {
-            return write!(f, "{}", rows.join("\n"));
-        }
-        let width = rows[0].chars().count();
-        writeln!(f, "╭{:─^width$}╮", self.title)?;
-        for row in rows.iter() {
-            writeln!(f, "│{row}│")?;
-        }
-        writeln!(f, "╰{:─<width$}╯", "")?;
-        writeln!(f, " {: ^width$} ", self.x_label)?;
-        writeln!(f, " {: ^width$} ", self.y_label)
-    }
-}
-
-/// A `View` is where the graph and plots are drawn. The view does not includes decorations around
-/// the plot (labels, title, border, etc..).
-#[derive(Default)]
-pub struct View {
-    /// Domain (range of the x axis) of the plot or graph.
-    pub domain: Domain,
-    /// Codomain (range of the y axis) of the plot or graph.
-    pub codomain: Domain,
 
-    /// The size of the view. This does not include decorations around the plot.
+    /// The domain, codomain, size and scales of the view. See [`ViewState`].
     ///
-    /// The size is not the number of chars but the number of pixels. Pixels are smaller than
-    /// chars. A char in the terminal is 2 by 4 pixels.
-    pub size: Size,
-    plots: Vec<Box<dyn DrawView>>,
-}
-
-impl View {
-    /// Draw x and y axis onto the view.
-    fn draw_axis(&self, canvas: &mut ViewCanvas) {
-        canvas.line(self.domain.min(), 0.0, self.domain.max(), 0.0);
-        canvas.line(0.0, self.codomain.min(), 0.0, self.codomain.max());
-    }
-
-    /// Draw the plots and graphs that were added.
-    fn draw_plots(&self, canvas: &mut ViewCanvas) {
-        for plot in self.plots.iter() {
-            plot.draw(&self, canvas);
-        }
-    }
-
-    /// Return the plot with labels as a vector of strings.
+    /// # Examples
     ///
-    /// This function create a [`ViewCanvas`] and draw elements (like axis and plots) onto the
-    /// canvas. It also generates and add the label of the axis.
-    pub(crate) fn drawing(&self, with_decoration: bool) -> Vec<String> {
-        let mut canvas = ViewCanvas::new(&self);
-        self.draw_axis(&mut canvas);
-        self.draw_plots(&mut canvas);
-        let rows = canvas.rows();
-        if !with_decoration {
-            return rows;
-        }
-        let width = rows[0].chars().count();
-        let mut out = Vec::new();
-        let y_ticks = ticks::YTicks::new(&self.codomain, rows.len(), 2);
-        let offset = y_ticks.display_width();
-        let x_ticks = ticks::XTicks::new(&self.domain, width, 2);
-        for (index, row) in rows.iter().enumerate() {
-            out.push(format!("{: >offset$}{row}", y_ticks.get(index)));
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..100.0))
+    ///     .set_codomain(Domain(-1.0..1.0))
+    ///     .set_size(Size::new(60, 20));
+    /// let state = plot.view_state();
+    ///
+    /// // The view changes, by panning in an interactive session for example...
+    /// plot.set_domain(Domain(40.0..60.0));
+    ///
+    /// // ...and comes back.
+    /// plot.apply_view_state(state);
+    /// assert_eq!(plot.view_state().domain, Domain(0.0..100.0));
+    /// ```
+    pub fn view_state(&self) -> ViewState {
+        ViewState {
+            domain: self.view.domain.clone(),
+            codomain: self.view.codomain.clone(),
+            size: self.view.size.clone(),
+            x_scale: self.view.x_scale,
+            y_scale: self.view.y_scale,
         }
-        out.push(format!("{: >offset$}{x_ticks}", ""));
-        out
     }
-}
-
-/// Domain or codomain of a graph.
-///
-/// [`Domain`] is needed for implementing [`DrawView`] for a new type of plot.
-pub struct Domain(pub std::ops::Range<f64>);
 
-impl Default for Domain {
-    fn default() -> Self {
-        Self(-10.0..10.0)
+    /// Set the domain, codomain, size and scales of the view to the ones of `state`, saved with
+    /// [`Plot::view_state`].
+    pub fn apply_view_state(&mut self, state: ViewState) -> &mut Self {
+        self.set_domain(state.domain)
+            .set_codomain(state.codomain)
+            .set_size(state.size)
+            .set_x_scale(state.x_scale)
+            .set_y_scale(state.y_scale)
     }
-}
 
-impl Domain {
-    /// The smallest value of the domain.
+    /// Set where the x and y axis are drawn in the view.
+    ///
+    /// By default, the axis go through `x = 0` and `y = 0` ([`AxisMode::Zero`]). Use
+    /// [`AxisMode::Box`] when the domain or the codomain does not include zero.
     ///
     /// # Examples
+    ///
     /// ```rust
-    /// use termplot::Domain;
+    /// use termplot::*;
     ///
-    /// let domain = Domain(-10.0..10.0);
-    /// assert_eq!(domain.min(), -10.0);
-    pub fn min(&self) -> f64 {
-        self.0.start
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(1.0..10.0))
+    ///     .set_codomain(Domain(0.5..2.5))
+    ///     .set_axis_mode(AxisMode::Box)
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.ln())));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_axis_mode(&mut self, mode: AxisMode) -> &mut Self {
+        self.view.axis_mode = mode;
+        self
     }
 
-    /// The largest value of the domain.
+    /// Set the number of values written next to the y axis, on evenly spaced rows from the top
+    /// to the bottom of the view. Each value has a mark on the y axis when
+    /// [`Plot::set_tick_marks`] is enabled.
+    ///
+    /// By default, only the start and the end of the codomain are written. There are always at
+    /// least two values, and at most one per row.
     ///
     /// # Examples
+    ///
     /// ```rust
-    /// use termplot::Domain;
+    /// use termplot::*;
     ///
-    /// let domain = Domain(-10.0..10.0);
-    /// assert_eq!(domain.max(), 10.0);
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-0.3..1.2))
+    ///     .set_y_ticks(4)
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
+    ///
+    /// println!("{plot}");
     /// ```
-    pub fn max(&self) -> f64 {
-        self.0.end
+    pub fn set_y_ticks(&mut self, count: usize) -> &mut Self {
+        self.view.tick_counts.y = count;
+        self
     }
 
-    /// The range of the domain as an absolute value.
+    /// Set the number of values written below the x axis, at evenly spaced columns from the left
+    /// to the right of the view. Each value has a mark on the x axis when
+    /// [`Plot::set_tick_marks`] is enabled.
+    ///
+    /// By default, only the start and the end of the domain are written. There are always at
+    /// least two values. A value that would overlap another value is not written.
     ///
     /// # Examples
+    ///
     /// ```rust
-    /// use termplot::Domain;
+    /// use termplot::*;
     ///
-    /// let domain = Domain(-10.0..10.0);
-    /// assert_eq!(domain.range(), 20.0);
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-0.3..1.2))
+    ///     .set_x_ticks(5)
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
     ///
-    /// let domain = Domain(8.0..-8.0);
-    /// assert_eq!(domain.range(), 16.0);
+    /// println!("{plot}");
     /// ```
-    pub fn range(&self) -> f64 {
-        (self.0.end - self.0.start).abs()
+    pub fn set_x_ticks(&mut self, count: usize) -> &mut Self {
+        self.view.tick_counts.x = count;
+        self
     }
 
-    /// An iterator over the range where a number of steps.
+    /// Set the scale of the x axis. See [`Scale`].
+    ///
+    /// By default, the scale is linear.
+    pub fn set_x_scale(&mut self, scale: Scale) -> &mut Self {
+        self.view.x_scale = scale;
+        self
+    }
+
+    /// Set the scale of the y axis. See [`Scale`].
+    ///
+    /// By default, the scale is linear.
+    pub fn set_y_scale(&mut self, scale: Scale) -> &mut Self {
+        self.view.y_scale = scale;
+        self
+    }
+
+    /// Set the format of the values written below the x axis. See [`TickFormat`].
+    pub fn set_x_tick_format(&mut self, format: TickFormat) -> &mut Self {
+        self.view.x_format = format;
+        self
+    }
+
+    /// Set the format of the values written next to the y axis. See [`TickFormat`].
+    pub fn set_y_tick_format(&mut self, format: TickFormat) -> &mut Self {
+        self.view.y_format = format;
+        self
+    }
+
+    /// Set the number of minor ticks drawn between two major ticks (the values written next to
+    /// the axis). Minor ticks are small marks on the axis lines.
     ///
-    /// For example, use this if it's needed to compute the value of each pixel for a plot.
+    /// By default, there are no minor ticks.
     ///
     /// # Examples
+    ///
     /// ```rust
-    /// use termplot::{Domain};
+    /// use termplot::*;
     ///
-    /// let domain = Domain(-10.0..10.0);
-    /// let view_width = 100;
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-0.3..1.2))
+    ///     .set_minor_ticks(9)
+    ///     .set_minor_grid(true)
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
     ///
-    /// domain
-    ///     .iter(view_width)
-    ///     .for_each(|x| {
+    /// println!("{plot}");
+    /// ```
+    pub fn set_minor_ticks(&mut self, count: usize) -> &mut Self {
+        self.view.minor_ticks = count;
+        self
+    }
+
+    /// Draw a mark across the axis lines at each major tick, so that the values written next to
+    /// the axis can be matched to their position. The marks of the major ticks are twice as long
+    /// as the marks of the minor ticks.
+    ///
+    /// By default, the major ticks have no marks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..10.0))
+    ///     .set_codomain(Domain(0.0..1.0))
+    ///     .set_axis_mode(AxisMode::Box)
+    ///     .set_x_ticks(6)
+    ///     .set_tick_marks(true)
+    ///     .set_size(Size::new(50, 20))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x / 10.0)));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_tick_marks(&mut self, tick_marks: bool) -> &mut Self {
+        self.view.tick_marks = tick_marks;
+        self
+    }
+
+    /// Draw a faint grid (a dotted line every few pixels) at each minor tick. See
+    /// [`Plot::set_minor_ticks`].
+    pub fn set_minor_grid(&mut self, minor_grid: bool) -> &mut Self {
+        self.view.minor_grid = minor_grid;
+        self
+    }
+
+    /// Draw the lines with sub-pixel precision, in the spirit of
+    /// [Xiaolin Wu's algorithm](https://en.wikipedia.org/wiki/Xiaolin_Wu%27s_line_algorithm).
+    ///
+    /// By default, both ends of a line are rounded to a pixel before the line is drawn, which
+    /// gives jagged curves on small views. With antialiasing, the line follows its exact
+    /// position: at each step, the closest dot is set, and both dots are set where the line
+    /// passes between two of them, smoothing the steps of shallow slopes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-0.3..1.2))
+    ///     .set_antialiasing(true)
+    ///     .set_size(Size::new(40, 12))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_antialiasing(&mut self, antialiasing: bool) -> &mut Self {
+        self.view.antialiasing = antialiasing;
+        self
+    }
+
+    /// Set how the view is drawn in the terminal. The default is [`Backend::Braille`].
+    ///
+    /// With an image backend, the view is drawn as an image at a higher resolution than the
+    /// braille characters, each plot keeping its colors, with the title above it and the labels,
+    /// the legend and the caption below it. The tick labels, the border and the text drawn on
+    /// the canvas are not part of the image.
+    ///
+    /// Terminals without sixel support show nothing for `Backend::Sixel` (`sixel` feature), or
+    /// the escape sequence itself. `Backend::Kitty` (`kitty` feature) and `Backend::ITerm2`
+    /// (`iterm` feature) fall back to braille characters outside of kitty and WezTerm, and
+    /// outside of iTerm2, which are recognized by their environment variables.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "sixel")]
+    /// # {
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-0.3..1.2))
+    ///     .set_title("Graph title")
+    ///     .set_backend(Backend::Sixel)
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
+    ///
+    /// println!("{plot}");
+    /// # }
+    /// ```
+    pub fn set_backend(&mut self, backend: Backend) -> &mut Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Set the domain (range of the x axis) of the plot.
+    ///
+    /// By default the domain is from -10 to 10.
+    ///
+    /// This function sets the minimum and maximum x values in the graph.
+    pub fn set_domain(&mut self, domain: Domain) -> &mut Self {
+        self.view.domain = domain;
+        self
+    }
+
+    /// Set the codomain (range of the y axis) of the plot.
+    ///
+    /// By default the codomain is from -10 to 10.
+    ///
+    /// This function sets the minimum and maximum y values in the graph.
+    pub fn set_codomain(&mut self, codomain: Domain) -> &mut Self {
+        self.view.codomain = codomain;
+        self
+    }
+
+    /// Set the title of the plot.
+    ///
+    /// The title breaks on `\n` and is wrapped to the width of the figure. When it is embedded in
+    /// the top border, its last line is written in the border and the others above it.
+    pub fn set_title(&mut self, title: &str) -> &mut Self {
+        self.title = String::from(title);
+        self
+    }
+
+    /// Set how the title is drawn: its alignment, emphasis, color and padding, and whether it is
+    /// embedded in the top border or written on its own line. See [`TitleStyle`].
+    pub fn set_title_style(&mut self, style: TitleStyle) -> &mut Self {
+        self.title_style = style;
+        self
+    }
+
+    /// Set the characters of the border around the figure.
+    ///
+    /// By default, the border has rounded corners. See [`Border`].
+    ///
+    /// This replaces the border of the current theme.
+    pub fn set_border(&mut self, border: Border) -> &mut Self {
+        self.view.theme.border = border;
+        self
+    }
+
+    /// Set the colors and the styles of the plot. See [`Theme`].
+    ///
+    /// The border of the theme replaces the border set with [`Plot::set_border`].
+    pub fn set_theme(&mut self, theme: Theme) -> &mut Self {
+        self.view.theme = theme;
+        self
+    }
+
+    /// Set which sides of the border are drawn.
+    ///
+    /// When the top side is hidden, the title is written on its own line above the figure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_title("Graph title")
+    ///     .set_border_sides(BorderSides {
+    ///         top: false,
+    ///         right: false,
+    ///         ..BorderSides::default()
+    ///     })
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_border_sides(&mut self, sides: BorderSides) -> &mut Self {
+        self.border_sides = sides;
+        self
+    }
+
+    /// Set which decorations are drawn around the view, to trade them for room to plot in a
+    /// small terminal. See [`Decorations`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_title("Graph title")
+    ///     .set_decorations(Decorations {
+    ///         border: false,
+    ///         y_ticks: false,
+    ///         ..Decorations::default()
+    ///     })
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_decorations(&mut self, decorations: Decorations) -> &mut Self {
+        self.decorations = decorations;
+        self
+    }
+
+    /// Set the label of the x axis.
+    ///
+    /// The label is shown at the bottom of the figure. It breaks on `\n` and is wrapped to the
+    /// width of the figure.
+    pub fn set_x_label(&mut self, label: &str) -> &mut Self {
+        self.x_label = String::from(label);
+        self
+    }
+
+    /// Set the label of the y axis.
+    ///
+    /// By default, the label is shown at the bottom of the figure, wrapped like the label of the
+    /// x axis. See [`Plot::set_y_label_position`] to show it along the left edge instead.
+    pub fn set_y_label(&mut self, label: &str) -> &mut Self {
+        self.y_label = String::from(label);
+        self
+    }
+
+    /// Set where the label of the y axis is shown.
+    ///
+    /// With [`Position::Left`], the label is written vertically (one character per row) along
+    /// the left edge of the figure. Characters that do not fit in the height of the figure are
+    /// not shown. The other positions show the label below the figure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-0.3..1.2))
+    ///     .set_y_label("Y axis")
+    ///     .set_y_label_position(Position::Left)
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_y_label_position(&mut self, position: Position) -> &mut Self {
+        self.y_label_position = position;
+        self
+    }
+
+    /// Set a caption written below the figure, under the labels and the legend, to describe the
+    /// source of the data or its units for example.
+    ///
+    /// The caption is left aligned, breaks on `\n` and is wrapped to the width of the figure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..24.0))
+    ///     .set_codomain(Domain(-5.0..30.0))
+    ///     .set_x_label("Hour")
+    ///     .set_caption("Temperatures in °C, measured every hour at the weather station.")
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| 12.0 - 8.0 * (x / 3.82).cos())));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_caption(&mut self, caption: &str) -> &mut Self {
+        self.caption = String::from(caption);
+        self
+    }
+
+    /// Write faint text over the view, like a timestamp or the name of the host, after every plot
+    /// and graph was drawn.
+    ///
+    /// The watermark goes in a corner of the view with [`Position::TopLeft`],
+    /// [`Position::TopRight`], [`Position::BottomLeft`] and [`Position::BottomRight`],
+    /// centered at the bottom with [`Position::Bottom`] and at the middle of the left and right
+    /// edges with [`Position::Left`] and [`Position::Right`]. It is written on a single line, cut
+    /// to the width of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..60.0))
+    ///     .set_codomain(Domain(0.0..100.0))
+    ///     .set_title("CPU usage (%)")
+    ///     .set_watermark("web-01 12:30:00", Position::TopRight)
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| 50.0 + 30.0 * (x / 5.0).sin())));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_watermark(&mut self, watermark: &str, position: Position) -> &mut Self {
+        self.view.watermark = Some((String::from(watermark), position));
+        self
+    }
+
+    /// Set the size of the view. This does not include decorations around the plot.
+    ///
+    /// The size is not the number of chars but the number of pixels. Pixels are smaller than
+    /// chars. A char in the terminal is 2 by 4 pixels. Use [`Plot::set_char_size`] to size the
+    /// whole figure in chars instead.
+    pub fn set_size(&mut self, size: Size) -> &mut Self {
+        self.view.size = size;
+        self
+    }
+
+    /// Set the size of the whole figure to `columns` by `rows` characters of the terminal,
+    /// decorations included: the view gets what is left after the border, the title, the ticks,
+    /// the labels, the legend and the caption.
+    ///
+    /// The decorations are measured when this method is called, so call it after setting them
+    /// and adding the plots. The view keeps at least one character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-0.3..1.2))
+    ///     .set_title("Graph title")
+    ///     .set_x_label("X axis")
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)))
+    ///     .set_char_size(80, 24);
+    ///
+    /// let rendered = plot.to_string();
+    /// assert_eq!(rendered.lines().count(), 24);
+    /// assert!(rendered.lines().all(|line| line.chars().count() <= 80));
+    /// ```
+    pub fn set_char_size(&mut self, columns: usize, rows: usize) -> &mut Self {
+        let backend = std::mem::take(&mut self.backend);
+        let mut size = (columns, rows);
+        // The wrapped title and labels can take more lines on a narrower view, so the figure is
+        // measured again after each change.
+        for _ in 0..3 {
+            self.view.size = Size::chars(size.0, size.1);
+            let Ok(rendered) = self.try_render() else {
+                break;
+            };
+            let width = rendered.lines().map(text::width).max().unwrap_or(0);
+            let height = rendered.lines().count();
+            if width <= columns && height <= rows {
+                break;
+            }
+            size.0 = size.0.saturating_sub(width.saturating_sub(columns)).max(1);
+            size.1 = size.1.saturating_sub(height.saturating_sub(rows)).max(1);
+        }
+        self.backend = backend;
+        self
+    }
+
+    /// Set the space added around the domain and the codomain, so that the data does not touch
+    /// the edges of the view. See [`Padding`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..6.0))
+    ///     .set_codomain(Domain(0.0..9.0))
+    ///     .set_padding(Padding::percent(10.0, 0.0, 0.0, 5.0))
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Bars::new(vec![2.0, 5.0, 1.0, 8.0, 9.0, 1.0])));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_padding(&mut self, padding: Padding) -> &mut Self {
+        self.view.padding = padding;
+        self
+    }
+
+    /// Set how the units of the x and y axis are scaled relatively to each other.
+    ///
+    /// With [`Aspect::Equal`], either the domain or the codomain is extended around its center so
+    /// that a unit has the same length on both axis. The size of the view does not change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-1.0..1.0))
+    ///     .set_codomain(Domain(-1.0..1.0))
+    ///     .set_aspect(Aspect::Equal)
+    ///     .set_size(Size::new(80, 40))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| (1.0 - x * x).sqrt())))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| -(1.0 - x * x).sqrt())));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_aspect(&mut self, aspect: Aspect) -> &mut Self {
+        self.view.aspect = aspect;
+        self
+    }
+
+    /// Set what the plots do with the points outside of the view. The default is
+    /// [`OutOfView::Clamp`]: a point far above the view is drawn on its top edge.
+    ///
+    /// With [`OutOfView::Skip`], such points are not drawn, so that outliers do not look like
+    /// values at the edge of the view. A plot can still choose its own policy, see
+    /// [`ViewCanvas::set_out_of_view`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..10.0))
+    ///     .set_codomain(Domain(0.0..10.0))
+    ///     .set_out_of_view(OutOfView::Skip)
+    ///     .set_size(Size::new(40, 20))
+    ///     .add_plot(Box::new(plot::Scatter::new(vec![(1.0, 2.0), (5.0, 1e6), (8.0, 7.0)])));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_out_of_view(&mut self, out_of_view: OutOfView) -> &mut Self {
+        self.view.out_of_view = out_of_view;
+        self
+    }
+
+    /// Set what fills the empty area of the view. The default is [`Fill::None`]: the view is
+    /// drawn on the background of the terminal.
+    ///
+    /// A faint pattern ([`Fill::Char`]) or a colored background ([`Fill::Color`]) shows the
+    /// extent of the view and keeps the plots readable on light terminals. With the image
+    /// backends, only [`Fill::Color`] is drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-1.5..1.5))
+    ///     .set_background(Fill::Char('·'))
+    ///     .set_size(Size::new(40, 12))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin())));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_background(&mut self, background: Fill) -> &mut Self {
+        self.view.background = background;
+        self
+    }
+
+    /// The layout of the plot (title, labels, domain, codomain and size).
+    ///
+    /// The plots and graphs that were added are not part of the returned spec. See [`PlotSpec`]
+    /// for more informations.
+    pub fn spec(&self) -> PlotSpec {
+        PlotSpec {
+            title: self.title.clone(),
+            x_label: self.x_label.clone(),
+            y_label: self.y_label.clone(),
+            domain: self.view.domain.clone(),
+            codomain: self.view.codomain.clone(),
+            size: self.view.size.clone(),
+            series: Vec::new(),
+        }
+    }
+
+    /// Render the plot as an HTML `<pre>` block, with the colors and styles of the terminal
+    /// written as inline CSS, for reports, CI summaries or web pages.
+    ///
+    /// Colors are written even when the output is not a terminal. Like in the terminal, true
+    /// colors are replaced by the closest color of the palette unless the `COLORTERM` environment
+    /// variable is `truecolor` or `24bit`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-0.3..1.2))
+    ///     .set_title("Graph title")
+    ///     .set_theme(Theme::dark())
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
+    ///
+    /// let html = plot.to_html().unwrap();
+    /// assert!(html.starts_with("<pre"));
+    /// ```
+    pub fn to_html(&self) -> Result<String, RenderError> {
+        let mut rendered = String::new();
+        self.render_to(self.decorations, true, &mut rendered)?;
+        Ok(html::from_ansi(&rendered))
+    }
+
+    /// Render the plot without any ANSI escape sequence, whatever the theme and the colors of
+    /// the plots, for files, logs or tools that do not support them.
+    ///
+    /// Formatting the plot still writes its colors when the output supports them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-0.3..1.2))
+    ///     .set_theme(Theme::dark())
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
+    ///
+    /// let plain = plot.render_plain().unwrap();
+    /// assert!(!plain.contains('\x1b'));
+    /// ```
+    pub fn render_plain(&self) -> Result<String, RenderError> {
+        Ok(text::strip_escapes(&self.try_render()?))
+    }
+
+    /// Render the plot as it is written when formatted.
+    ///
+    /// Formatting a plot that cannot be rendered writes the reason instead of the plot, on a
+    /// single line: use this method to handle the error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-1.0..1.0))
+    ///     .set_size(Size::new(0, 25))
+    ///     .add_plot(Box::new(plot::Graph::new(|x| x.sin())));
+    ///
+    /// assert_eq!(plot.try_render(), Err(RenderError::EmptyView));
+    /// ```
+    pub fn try_render(&self) -> Result<String, RenderError> {
+        self.render(self.decorations)
+    }
+
+    /// Render the plot into `buffer`, replacing its content. Fails like [`Plot::try_render`].
+    ///
+    /// The memory of `buffer` is reused: rendering every frame of a live plot into the same
+    /// buffer avoids growing a new string for the whole frame each time. The rows of the view are
+    /// still drawn in their own strings before being written into `buffer`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut renderer = Renderer::new(std::io::stdout());
+    /// let mut frame = String::new();
+    /// for step in 0..10 {
+    ///     let mut plot = Plot::default();
+    ///     plot.set_domain(Domain(-10.0..10.0))
+    ///         .set_size(Size::new(50, 25))
+    ///         .add_plot(Box::new(plot::Graph::new(move |x| (x + step as f64).sin())));
+    ///     plot.render_into(&mut frame).unwrap();
+    ///     renderer.draw(&frame).unwrap();
+    /// }
+    /// ```
+    pub fn render_into(&self, buffer: &mut String) -> Result<(), RenderError> {
+        buffer.clear();
+        self.render_to(self.decorations, color::enabled(), buffer)?;
+        Ok(())
+    }
+
+    /// Render the plot with `decorations` instead of the ones of the plot.
+    pub(crate) fn render(&self, decorations: Decorations) -> Result<String, RenderError> {
+        let mut out = String::new();
+        self.render_to(decorations, color::enabled(), &mut out)?;
+        Ok(out)
+    }
+
+    /// Write the plot drawn with `decorations` at the end of `out`, with its colors when `colors`
+    /// is `true`. Returns the column and the line, from the start of what was written, of the
+    /// first character of the view.
+    fn render_to(
+        &self,
+        decorations: Decorations,
+        colors: bool,
+        out: &mut String,
+    ) -> Result<(usize, usize), RenderError> {
+        match self.backend {
+            Backend::Braille => {}
+            #[cfg(feature = "sixel")]
+            Backend::Sixel => {
+                let image = sixel::encode(&self.view.raster(sixel::RESOLUTION)?);
+                return Ok(self.around_image(image, decorations, colors, out));
+            }
+            #[cfg(feature = "kitty")]
+            Backend::Kitty if kitty::supported() => {
+                let pixels = self.view.raster(raster::RESOLUTION)?;
+                let (columns, rows) = self.view.size.cells();
+                let image = kitty::encode(&pixels, columns, rows);
+                return Ok(self.around_image(image, decorations, colors, out));
+            }
+            #[cfg(feature = "iterm")]
+            Backend::ITerm2 if iterm::supported() => {
+                let pixels = self.view.raster(raster::RESOLUTION)?;
+                let (columns, rows) = self.view.size.cells();
+                let image = iterm::encode(&pixels, columns, rows);
+                return Ok(self.around_image(image, decorations, colors, out));
+            }
+            // Fall back to braille characters in the other terminals.
+            #[cfg(any(feature = "kitty", feature = "iterm"))]
+            _ => {}
+        }
+        let rows = self
+            .view
+            .drawing(decorations.x_ticks, decorations.y_ticks, colors)?;
+        let width = rows.first().map_or(0, |row| text::width(row));
+        let sides = match decorations.border {
+            true => self.border_sides,
+            false => BorderSides::NONE,
+        };
+        let frame = border::Frame::new(self.view.theme.border, sides);
+        let style = &self.title_style;
+        let embedded = style.embedded && frame.sides().top;
+        let title = self.title(decorations).unwrap_or_default();
+        let mut title = text::wrap(title, width.saturating_sub(2 * style.padding));
+        // An embedded title ends in the top border, and its first lines are written above it.
+        let top = match embedded {
+            true => style.render(
+                &title.pop().unwrap_or_default(),
+                width,
+                frame.horizontal(),
+                colors,
+            ),
+            false => frame.horizontal().to_string().repeat(width),
+        };
+        // With the y label on the left, every line of the frame starts with a column holding the
+        // label.
+        let left = match self.y_label_position {
+            Position::Left if decorations.y_label => {
+                text::vertical(&self.y_label.replace('\n', " "), frame.height(rows.len()))
+            }
+            _ => Vec::new(),
+        };
+        // Lines outside of the frame are shifted by the y label and the left side of the border.
+        let left_width = left.first().map_or(0, |column| text::width(column));
+        let margin = " ".repeat(left_width + frame.sides().left as usize);
+        let line = |out: &mut String, line: &str| {
+            out.push_str(&margin);
+            out.push_str(line);
+            out.push('\n');
+        };
+        let mut origin = (
+            left_width + frame.sides().left as usize,
+            frame.sides().top as usize,
+        );
+        if decorations.y_ticks {
+            origin.0 += self.view.y_ticks_width();
+        }
+        if self.title(decorations).is_some() {
+            for title in &title {
+                line(out, &style.render(title, width, ' ', colors));
+            }
+            origin.1 += title.len();
+        }
+        let y_label = decorations.y_label && self.y_label_position != Position::Left;
+        let footer = self.footer(width, decorations, y_label, colors);
+        frame.write(out, &top, &rows, width, &left);
+        for footer in &footer {
+            line(out, footer);
+        }
+        Ok(origin)
+    }
+
+    /// The title, when there is one and it is drawn with `decorations`.
+    fn title(&self, decorations: Decorations) -> Option<&str> {
+        Some(self.title.as_str()).filter(|title| decorations.title && !title.is_empty())
+    }
+
+    /// The lines below the view, `width` characters wide: the x label and the legend when they
+    /// are drawn with `decorations`, the y label when `y_label` is `true`, and the caption. The
+    /// legend has its colors when `colors` is `true`.
+    fn footer(
+        &self,
+        width: usize,
+        decorations: Decorations,
+        y_label: bool,
+        colors: bool,
+    ) -> Vec<String> {
+        let mut out = Vec::new();
+        if decorations.x_label {
+            for line in text::wrap(&self.x_label, width) {
+                out.push(text::center(&line, width));
+            }
+        }
+        if y_label {
+            for line in text::wrap(&self.y_label, width) {
+                out.push(text::center(&line, width));
+            }
+        }
+        if decorations.legend {
+            for row in legend::rows(&self.view.legend(), width, colors) {
+                out.push(text::center(&row, width));
+            }
+        }
+        if !self.caption.is_empty() {
+            for line in text::wrap(&self.caption, width) {
+                out.push(text::align(&line, width, Align::Left, ' '));
+            }
+        }
+        out
+    }
+
+    /// Write the plot drawn with an image backend to `out`: the `image` of the view, with the
+    /// title above it and the labels, the legend and the caption below it. Returns the position
+    /// of the image, like [`Plot::render_to`]. See [`Plot::set_backend`].
+    #[cfg(any(feature = "sixel", feature = "kitty", feature = "iterm"))]
+    fn around_image(
+        &self,
+        image: String,
+        decorations: Decorations,
+        colors: bool,
+        out: &mut String,
+    ) -> (usize, usize) {
+        // The image is about as wide as the braille characters would be.
+        let width = self.view.size.w.div_ceil(2);
+        let style = &self.title_style;
+        let mut lines = Vec::new();
+        if let Some(title) = self.title(decorations) {
+            for line in text::wrap(title, width.saturating_sub(2 * style.padding)) {
+                lines.push(style.render(&line, width, ' ', colors));
+            }
+        }
+        let origin = (0, lines.len());
+        lines.push(image);
+        lines.extend(self.footer(width, decorations, decorations.y_label, colors));
+        for line in lines {
+            out.push_str(&line);
+            out.push('\n');
+        }
+        origin
+    }
+}
+
+impl fmt::Display for Plot {
+    /// Write the plot, or the reason it cannot be rendered on a single line, see
+    /// [`Plot::try_render`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.try_render() {
+            Ok(rendered) => f.write_str(&rendered),
+            Err(error) => writeln!(f, "{error}"),
+        }
+    }
+}
+
+/// The reason a plot cannot be rendered. See [`Plot::try_render`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RenderError {
+    /// The view has no pixels: its width or its height is zero.
+    EmptyView,
+    /// The domain (or the domain requested by a plot) is not finite.
+    InvalidDomain,
+    /// The codomain is not finite.
+    InvalidCodomain,
+    /// The scale of an axis cannot place values, see [`Scale::SymLog`].
+    InvalidScale,
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyView => write!(f, "the plot has an empty view"),
+            Self::InvalidDomain => write!(f, "the domain of the plot is not finite"),
+            Self::InvalidCodomain => write!(f, "the codomain of the plot is not finite"),
+            Self::InvalidScale => write!(f, "the scale of an axis of the plot is invalid"),
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+/// A `View` is where the graph and plots are drawn. The view does not includes decorations around
+/// the plot (labels, title, border, etc..).
+#[derive(Default)]
+pub struct View {
+    /// Domain (range of the x axis) of the plot or graph.
+    pub domain: Domain,
+    /// Codomain (range of the y axis) of the plot or graph.
+    pub codomain: Domain,
+
+    /// The size of the view. This does not include decorations around the plot.
+    ///
+    /// The size is not the number of chars but the number of pixels. Pixels are smaller than
+    /// chars. A char in the terminal is 2 by 4 pixels.
+    pub size: Size,
+    /// The plots and graphs that were added, with their layer.
+    plots: Vec<(i32, Box<dyn DrawView>)>,
+    /// The index in `plots` of the plots that are hidden.
+    hidden: std::collections::BTreeSet<usize>,
+    /// The style of the plots, by index in `plots`.
+    styles: std::collections::BTreeMap<usize, Style>,
+    /// The plots and graphs that do not change between renders, with their layer.
+    static_plots: Vec<(i32, Box<dyn DrawView>)>,
+    /// The canvas with the axis and the static plots drawn, and the view it was drawn for.
+    static_layer: std::cell::RefCell<Option<(LayerKey, braille::Canvas)>>,
+    /// What is drawn over the plots and the axis without being one of the plots, like the data
+    /// cursor of the interactive mode.
+    overlays: Vec<Box<dyn DrawView>>,
+    /// The layer of the axis.
+    axis_order: i32,
+    axis_mode: AxisMode,
+    x_scale: Scale,
+    y_scale: Scale,
+    x_format: TickFormat,
+    y_format: TickFormat,
+    tick_counts: ticks::Counts,
+    /// The number of minor ticks between two major ticks.
+    minor_ticks: usize,
+    minor_grid: bool,
+    /// Whether the major ticks are marked on the axis lines.
+    tick_marks: bool,
+    antialiasing: bool,
+    out_of_view: OutOfView,
+    background: Fill,
+    padding: Padding,
+    aspect: Aspect,
+    theme: Theme,
+    /// Faint text written over the view, and where.
+    watermark: Option<(String, Position)>,
+    /// The minimum width of the column of the y ticks, so that the views of stacked plots line
+    /// up.
+    y_ticks_width: usize,
+}
+
+impl View {
+    /// Draw x and y axis onto the view, with a mark at each minor tick, and at each major tick
+    /// when the tick marks are drawn.
+    fn draw_axis(&self, canvas: &mut ViewCanvas) {
+        canvas.set_color(self.theme.axis);
+        let (x_min, x_max) = (self.domain.min(), self.domain.max());
+        let (y_min, y_max) = (self.codomain.min(), self.codomain.max());
+        let (x_count, y_count) = (self.tick_counts.x, self.tick_counts.y);
+        let minor_xs = ticks::minor(&self.domain, self.x_scale, x_count, self.minor_ticks);
+        let minor_ys = ticks::minor(&self.codomain, self.y_scale, y_count, self.minor_ticks);
+        if self.minor_grid {
+            grid::draw_faint(canvas, &minor_xs, &minor_ys);
+        }
+        let origin = match self.axis_mode {
+            AxisMode::Box => {
+                canvas.line(x_min, y_min, x_max, y_min);
+                canvas.line(x_max, y_min, x_max, y_max);
+                canvas.line(x_max, y_max, x_min, y_max);
+                canvas.line(x_min, y_max, x_min, y_min);
+                (x_min, y_min)
+            }
+            AxisMode::Zero => {
+                canvas.line(x_min, 0.0, x_max, 0.0);
+                canvas.line(0.0, y_min, 0.0, y_max);
+                (0.0, 0.0)
+            }
+            AxisMode::None => return,
+        };
+        ticks::draw_marks(canvas, origin, &minor_xs, &minor_ys, 2.0);
+        if !self.tick_marks {
+            return;
+        }
+        let major_xs = ticks::major(&self.domain, self.x_scale, x_count);
+        let major_ys = ticks::major(&self.codomain, self.y_scale, y_count);
+        ticks::draw_marks(canvas, origin, &major_xs, &major_ys, 4.0);
+    }
+
+    /// The static plots and the plots that were added, with their layer, without the hidden
+    /// plots.
+    fn all_plots(&self) -> impl Iterator<Item = &(i32, Box<dyn DrawView>)> {
+        self.styled_plots().map(|(_, plot)| plot)
+    }
+
+    /// The plots of [`View::all_plots`] with their style. Static plots have the default style.
+    fn styled_plots(&self) -> impl Iterator<Item = (Style, &(i32, Box<dyn DrawView>))> {
+        self.static_plots
+            .iter()
+            .map(|plot| (Style::default(), plot))
+            .chain(self.shown_plots())
+    }
+
+    /// The plots that were added and are not hidden, with their style and their layer.
+    fn shown_plots(&self) -> impl Iterator<Item = (Style, &(i32, Box<dyn DrawView>))> {
+        self.plots
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !self.hidden.contains(index))
+            .map(|(index, plot)| (self.styles.get(&index).copied().unwrap_or_default(), plot))
+    }
+
+    /// Draw the plots and graphs that were added and the axis, from the lowest layer to the
+    /// highest. `view` is the view fitted to the aspect ratio (see [`View::fitted`]).
+    fn draw_plots(&self, view: &View, canvas: &mut ViewCanvas) {
+        self.draw_layer(view, canvas, self.styled_plots(), true);
+    }
+
+    /// Draw the static plots and the axis, or reuse their pixels when they were already drawn
+    /// for the same view, then draw the other plots over them. See [`Plot::add_static_plot`].
+    ///
+    /// The overlays are drawn last, over everything else.
+    fn draw_cached(&self, view: &View, canvas: &mut ViewCanvas) {
+        if self.static_plots.is_empty() {
+            self.draw_plots(view, canvas);
+        } else {
+            let key = LayerKey::new(view, self.static_plots.len());
+            let mut layer = self.static_layer.borrow_mut();
+            match &*layer {
+                Some((cached, pixels)) if *cached == key => canvas.canvas = pixels.clone(),
+                _ => {
+                    let plots = self
+                        .static_plots
+                        .iter()
+                        .map(|plot| (Style::default(), plot));
+                    self.draw_layer(view, canvas, plots, true);
+                    *layer = Some((key, canvas.canvas.clone()));
+                }
+            }
+            self.draw_layer(view, canvas, self.shown_plots(), false);
+        }
+        for overlay in &self.overlays {
+            canvas.set_color(None);
+            overlay.draw(view, canvas);
+        }
+    }
+
+    /// Draw `plots` from the lowest layer to the highest, with the axis in their layer when
+    /// `axis` is `true`.
+    fn draw_layer<'a>(
+        &self,
+        view: &View,
+        canvas: &mut ViewCanvas,
+        plots: impl Iterator<Item = (Style, &'a (i32, Box<dyn DrawView>))>,
+        axis: bool,
+    ) {
+        let mut plots = plots.collect::<Vec<_>>();
+        plots.sort_by_key(|(_, (z, _))| *z);
+        let mut with_axis = !axis;
+        for (style, (z, plot)) in plots {
+            canvas.style = Style::default();
+            if !with_axis && *z >= self.axis_order {
+                view.draw_axis(canvas);
+                with_axis = true;
+            }
+            canvas.style = style;
+            canvas.set_color(None);
+            canvas.set_out_of_view(view.out_of_view);
+            plot.draw(view, canvas);
+        }
+        canvas.style = Style::default();
+        if !with_axis {
+            view.draw_axis(canvas);
+        }
+        canvas.set_color(None);
+    }
+
+    /// The pixels of the view without decorations, row by row from the top.
+    #[cfg(any(feature = "image", test))]
+    pub(crate) fn pixels(&self) -> Result<Vec<Vec<bool>>, RenderError> {
+        let view = self.fitted();
+        view.validate()?;
+        let mut canvas = ViewCanvas::new(&view);
+        self.draw_plots(&view, &mut canvas);
+        let pixels = (0..view.size.h as u32)
+            .map(|y| {
+                (0..view.size.w as u32)
+                    .map(|x| canvas.canvas.get(x, y))
+                    .collect()
+            })
+            .collect();
+        Ok(pixels)
+    }
+
+    /// The colors of the pixels of the view without decorations, row by row from the top, drawn
+    /// with `resolution` times more pixels on each axis. `None` is a pixel that is not set.
+    ///
+    /// The default color of the terminal cannot be known, and is drawn light gray, like in most
+    /// dark themes.
+    #[cfg(any(feature = "sixel", feature = "kitty", feature = "iterm"))]
+    pub(crate) fn raster(
+        &self,
+        resolution: usize,
+    ) -> Result<Vec<Vec<Option<color::Rgb>>>, RenderError> {
+        let mut view = self.fitted();
+        view.size = Size::new(view.size.w * resolution, view.size.h * resolution);
+        view.validate()?;
+        let mut canvas = ViewCanvas::new(&view);
+        canvas.recorded = Some(std::collections::HashMap::new());
+        self.draw_plots(&view, &mut canvas);
+        let recorded = canvas.recorded.unwrap_or_default();
+        let background = match view.background {
+            Fill::Color(color) => Some(color.rgb()),
+            _ => None,
+        };
+        let pixels = (0..view.size.h as u32)
+            .map(|y| {
+                (0..view.size.w as u32)
+                    .map(|x| {
+                        recorded
+                            .get(&(x, y))
+                            .map(|color| color.unwrap_or(Color::White).rgb())
+                            .or(background)
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(pixels)
+    }
+
+    /// A copy of the view, without its plots, where the domain and the codomain are extended by
+    /// the padding and to respect the aspect ratio. The domain is the one requested by the first
+    /// plot requesting a domain (see [`DrawView::domain`]), if any.
+    fn fitted(&self) -> View {
+        let padding = &self.padding;
+        let domain = self.all_plots().find_map(|(_, plot)| plot.domain());
+        let domain = domain.as_ref().unwrap_or(&self.domain);
+        let mut domain = padding.apply(domain, padding.left, padding.right);
+        let mut codomain = padding.apply(&self.codomain, padding.bottom, padding.top);
+        if self.aspect == Aspect::Equal {
+            // Braille pixels are about as wide as they are tall in most terminals, where a
+            // character is twice as tall as it is wide.
+            let x_scale = domain.range() / self.size.w as f64;
+            let y_scale = codomain.range() / self.size.h as f64;
+            let scale = x_scale.max(y_scale);
+            domain = domain.extended(scale * self.size.w as f64);
+            codomain = codomain.extended(scale * self.size.h as f64);
+        }
+        View {
+            domain,
+            codomain,
+            size: self.size.clone(),
+            plots: Vec::new(),
+            hidden: Default::default(),
+            styles: Default::default(),
+            static_plots: Vec::new(),
+            static_layer: Default::default(),
+            overlays: Vec::new(),
+            axis_order: self.axis_order,
+            axis_mode: self.axis_mode,
+            x_scale: self.x_scale,
+            y_scale: self.y_scale,
+            x_format: self.x_format,
+            y_format: self.y_format,
+            tick_counts: self.tick_counts,
+            minor_ticks: self.minor_ticks,
+            minor_grid: self.minor_grid,
+            tick_marks: self.tick_marks,
+            antialiasing: self.antialiasing,
+            out_of_view: self.out_of_view,
+            background: self.background,
+            padding: Padding::default(),
+            aspect: self.aspect,
+            theme: self.theme.clone(),
+            watermark: self.watermark.clone(),
+            y_ticks_width: self.y_ticks_width,
+        }
+    }
+
+    /// The ticks of the y axis of the view.
+    fn y_ticks(&self) -> ticks::YTicks {
+        let view = self.fitted();
+        let rows = view.size.h.div_ceil(4);
+        let categories = self
+            .all_plots()
+            .flat_map(|(_, plot)| plot.y_labels())
+            .collect::<Vec<_>>();
+        match categories.is_empty() {
+            true => ticks::YTicks::new(
+                &view.codomain,
+                view.y_scale,
+                view.y_format,
+                rows,
+                view.tick_counts.y,
+            ),
+            false => ticks::YTicks::categories(&view.codomain, view.y_scale, rows, &categories),
+        }
+    }
+
+    /// The width of the column of the y ticks written left of the view.
+    pub(crate) fn y_ticks_width(&self) -> usize {
+        self.y_ticks().display_width().max(self.y_ticks_width)
+    }
+
+    /// The entries of the legend of every plot and graph that were added.
+    fn legend(&self) -> Vec<LegendEntry> {
+        self.styled_plots()
+            .flat_map(|(style, (_, plot))| {
+                let mut entries = plot.legend(self);
+                if let Some(color) = style.color {
+                    entries
+                        .iter_mut()
+                        .for_each(|entry| entry.color = Some(color));
+                }
+                entries
+            })
+            .collect()
+    }
+
+    /// The color of the theme for the series at `index`. Components drawing several series
+    /// should use it for the series that were not given a color.
+    ///
+    /// Returns `None` when the series should use the default color of the terminal.
+    pub fn series_color(&self, index: usize) -> Option<Color> {
+        self.theme.series_color(index)
+    }
+
+    /// Draw the axis and the plots on a [`ViewCanvas`] and return its rows, with the values of
+    /// the x axis below them when `x_ticks_shown` is `true`, the values of the y axis left of
+    /// them when `y_ticks_shown` is `true`, and the colorbar right of them when a plot has one.
+    pub(crate) fn drawing(
+        &self,
+        x_ticks_shown: bool,
+        y_ticks_shown: bool,
+        colors: bool,
+    ) -> Result<Vec<String>, RenderError> {
+        let view = self.fitted();
+        view.validate()?;
+        let mut canvas = ViewCanvas::new(&view);
+        self.draw_cached(&view, &mut canvas);
+        let mut watermarked = Vec::new();
+        let rows = match &self.watermark {
+            Some((watermark, position)) => {
+                watermarked.extend_from_slice(canvas.rows(colors));
+                watermark::overlay(&mut watermarked, watermark, *position, colors);
+                &watermarked
+            }
+            None => canvas.rows(colors),
+        };
+        let width = rows.first().map_or(0, |row| text::width(row));
+        let y_ticks = self.y_ticks();
+        let offset = match y_ticks_shown {
+            true => self.y_ticks_width(),
+            false => 0,
+        };
+        let categories = self
+            .all_plots()
+            .flat_map(|(_, plot)| plot.x_labels())
+            .collect::<Vec<_>>();
+        let x_ticks = match categories.is_empty() {
+            true => ticks::XTicks::new(
+                &view.domain,
+                view.x_scale,
+                view.x_format,
+                width,
+                view.tick_counts.x,
+            ),
+            false => ticks::XTicks::categories(&view.domain, view.x_scale, width, &categories),
+        };
+        let paint = |ticks: String| match self.theme.ticks {
+            Some(color) => color.paint(&ticks, colors),
+            None => ticks,
+        };
+        let colorbar = self
+            .all_plots()
+            .find_map(|(_, plot)| plot.colorbar())
+            .map(|(colormap, range)| {
+                colorbar::Colorbar::new(colormap, range).rows(
+                    rows.len(),
+                    view.tick_counts.y,
+                    colors,
+                )
+            })
+            .unwrap_or_default();
+        let colorbar_width = colorbar.first().map_or(0, |row| text::width(row));
+        // Each line is written once, with the room for the ticks and the colorbar.
+        let mut lines = Vec::with_capacity(rows.len() + 1);
+        for (index, row) in rows.iter().enumerate() {
+            let colorbar = colorbar.get(index).map_or("", String::as_str);
+            let mut line = String::with_capacity(offset + row.len() + colorbar.len());
+            if y_ticks_shown {
+                line.push_str(&paint(text::align(
+                    y_ticks.get(index),
+                    offset,
+                    Align::Right,
+                    ' ',
+                )));
+            }
+            line.push_str(row);
+            line.push_str(colorbar);
+            lines.push(line);
+        }
+        if x_ticks_shown {
+            let x_ticks = paint(x_ticks.to_string());
+            lines.push(format!("{: >offset$}{x_ticks}{: >colorbar_width$}", "", ""));
+        }
+        Ok(lines)
+    }
+
+    /// Check that the view can be drawn: it has pixels, and its domain and codomain are finite.
+    fn validate(&self) -> Result<(), RenderError> {
+        let finite = |domain: &Domain| domain.min().is_finite() && domain.max().is_finite();
+        if self.size.w == 0 || self.size.h == 0 {
+            Err(RenderError::EmptyView)
+        } else if !finite(&self.domain) {
+            Err(RenderError::InvalidDomain)
+        } else if !finite(&self.codomain) {
+            Err(RenderError::InvalidCodomain)
+        } else if !self.x_scale.is_valid() || !self.y_scale.is_valid() {
+            Err(RenderError::InvalidScale)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// What the pixels of the axis and of the static plots depend on. See
+/// [`Plot::add_static_plot`].
+#[derive(Clone, Debug, PartialEq)]
+struct LayerKey {
+    domain: Domain,
+    codomain: Domain,
+    size: Size,
+    axis_order: i32,
+    axis_mode: AxisMode,
+    scales: (Scale, Scale),
+    tick_counts: ticks::Counts,
+    minor_ticks: usize,
+    minor_grid: bool,
+    tick_marks: bool,
+    antialiasing: bool,
+    out_of_view: OutOfView,
+    theme: Theme,
+    static_plots: usize,
+}
+
+impl LayerKey {
+    fn new(view: &View, static_plots: usize) -> Self {
+        Self {
+            domain: view.domain.clone(),
+            codomain: view.codomain.clone(),
+            size: view.size.clone(),
+            axis_order: view.axis_order,
+            axis_mode: view.axis_mode,
+            scales: (view.x_scale, view.y_scale),
+            tick_counts: view.tick_counts,
+            minor_ticks: view.minor_ticks,
+            minor_grid: view.minor_grid,
+            tick_marks: view.tick_marks,
+            antialiasing: view.antialiasing,
+            out_of_view: view.out_of_view,
+            theme: view.theme.clone(),
+            static_plots,
+        }
+    }
+}
+
+/// Domain or codomain of a graph.
+///
+/// [`Domain`] is needed for implementing [`DrawView`] for a new type of plot.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Domain(pub std::ops::Range<f64>);
+
+impl Default for Domain {
+    fn default() -> Self {
+        Self(-10.0..10.0)
+    }
+}
+
+impl Domain {
+    /// Create a domain from `min` to `max`.
+    ///
+    /// Unlike `Domain(min..max)`, the bounds are checked: they must be finite and `min` must be
+    /// smaller than `max`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use termplot::{Domain, DomainError};
+    ///
+    /// assert_eq!(Domain::new(-1.0, 1.0), Ok(Domain(-1.0..1.0)));
+    /// assert_eq!(Domain::new(1.0, -1.0), Err(DomainError::Reversed));
+    /// assert_eq!(Domain::new(f64::NAN, 1.0), Err(DomainError::NotFinite));
+    /// ```
+    pub fn new(min: f64, max: f64) -> Result<Self, DomainError> {
+        if !min.is_finite() || !max.is_finite() {
+            Err(DomainError::NotFinite)
+        } else if min == max {
+            Err(DomainError::Empty)
+        } else if min > max {
+            Err(DomainError::Reversed)
+        } else {
+            Ok(Self(min..max))
+        }
+    }
+
+    /// The smallest domain containing every finite value of `values`.
+    ///
+    /// When every value is the same, the domain is centered on the value and 1 unit wide. Returns
+    /// [`DomainError::Empty`] when there is no finite value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use termplot::Domain;
+    ///
+    /// let domain = Domain::from_values(&[3.0, -2.0, f64::NAN, 8.0]).unwrap();
+    /// assert_eq!(domain, Domain(-2.0..8.0));
+    /// assert_eq!(Domain::from_values(&[4.0]).unwrap(), Domain(3.5..4.5));
+    /// ```
+    pub fn from_values(values: &[f64]) -> Result<Self, DomainError> {
+        let (min, max) = values
+            .iter()
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            });
+        if min > max {
+            Err(DomainError::Empty)
+        } else if min == max {
+            Ok(Self(min - 0.5..max + 0.5))
+        } else {
+            Ok(Self(min..max))
+        }
+    }
+
+    /// The domain extended by `percent` percent of its range on both sides, so that the values on
+    /// its edges do not touch the edges of the view.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use termplot::Domain;
+    ///
+    /// assert_eq!(Domain(0.0..10.0).with_margin(5.0), Domain(-0.5..10.5));
+    /// ```
+    pub fn with_margin(&self, percent: f64) -> Self {
+        let margin = (self.0.end - self.0.start) * percent / 100.0;
+        Self(self.0.start - margin..self.0.end + margin)
+    }
+
+    /// The smallest value of the domain.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use termplot::Domain;
+    ///
+    /// let domain = Domain(-10.0..10.0);
+    /// assert_eq!(domain.min(), -10.0);
+    pub fn min(&self) -> f64 {
+        self.0.start
+    }
+
+    /// The largest value of the domain.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use termplot::Domain;
+    ///
+    /// let domain = Domain(-10.0..10.0);
+    /// assert_eq!(domain.max(), 10.0);
+    /// ```
+    pub fn max(&self) -> f64 {
+        self.0.end
+    }
+
+    /// The range of the domain as an absolute value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use termplot::Domain;
+    ///
+    /// let domain = Domain(-10.0..10.0);
+    /// assert_eq!(domain.range(), 20.0);
+    ///
+    /// let domain = Domain(8.0..-8.0);
+    /// assert_eq!(domain.range(), 16.0);
+    /// ```
+    pub fn range(&self) -> f64 {
+        (self.0.end - self.0.start).abs()
+    }
+
+    /// The domain with the same center and a range of `range`, in the same direction.
+    fn extended(&self, range: f64) -> Self {
+        let center = (self.0.start + self.0.end) / 2.0;
+        let half = (self.0.end - self.0.start).signum() * range / 2.0;
+        Self(center - half..center + half)
+    }
+
+    /// An iterator over the range where a number of steps.
+    ///
+    /// For example, use this if it's needed to compute the value of each pixel for a plot. The
+    /// iterator gives `steps + 1` values evenly spaced from the start of the domain to its end,
+    /// both included.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use termplot::{Domain};
+    ///
+    /// let domain = Domain(-10.0..10.0);
+    /// let view_width = 100;
+    ///
+    /// domain
+    ///     .iter(view_width)
+    ///     .for_each(|x| {
     ///         // draw into the canvas
     ///     });
     /// ```
     /// See how [`plot::Graph`] is implemented for an in depth example.
     pub fn iter(&self, steps: usize) -> DomainIterator {
-        DomainIterator::new(self.0.clone(), self.range() / steps as f64)
+        self.linspace(steps + 1)
+    }
+
+    /// An iterator over exactly `count` values evenly spaced from the start of the domain to its
+    /// end, both included. A single value is the start of the domain.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use termplot::Domain;
+    ///
+    /// let xs = Domain(0.0..1.0).linspace(11).collect::<Vec<_>>();
+    /// assert_eq!(xs.len(), 11);
+    /// assert_eq!(xs[3], 0.3);
+    /// assert_eq!(xs[10], 1.0);
+    /// ```
+    pub fn linspace(&self, count: usize) -> DomainIterator {
+        DomainIterator {
+            start: self.0.start,
+            span: self.0.end - self.0.start,
+            count,
+            index: 0,
+            last: Some(self.0.end).filter(|_| count > 1),
+        }
+    }
+}
+
+/// The reason a domain is invalid. See [`Domain::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DomainError {
+    /// A bound is not a finite number.
+    NotFinite,
+    /// The domain contains a single value, or no value at all.
+    Empty,
+    /// The start of the domain is after its end.
+    Reversed,
+}
+
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFinite => write!(f, "the bounds of the domain are not finite"),
+            Self::Empty => write!(f, "the domain is empty"),
+            Self::Reversed => write!(f, "the start of the domain is after its end"),
+        }
     }
 }
 
+impl std::error::Error for DomainError {}
+
 /// An iterator over the domain with a number of steps.
 ///
-/// This is used to only compute specific points of a continuous graph.
+/// This is used to only compute specific points of a continuous graph. Each value is computed
+/// from its index, so that rounding errors do not add up along the domain.
 ///
 /// See [`Domain`] for more informations.
 pub struct DomainIterator {
-    current: f64,
-    domain: ops::Range<f64>,
-    step_by: f64,
+    start: f64,
+    /// The distance from the first value to the last one.
+    span: f64,
+    /// The number of values.
+    count: usize,
+    index: usize,
+    /// The last value, when it is the end of the domain.
+    last: Option<f64>,
+}
+
+impl DomainIterator {
+    /// Iterate over `domain` from its start, `step_by` apart. The end of the domain is included
+    /// when the range of the domain is a multiple of `step_by`, despite rounding errors.
+    pub fn new(domain: ops::Range<f64>, step_by: f64) -> Self {
+        let steps = (domain.end - domain.start) / step_by;
+        let rounded = steps.round();
+        let exact = (steps - rounded).abs() <= 1e-9 * rounded.max(1.0);
+        let steps = match steps {
+            steps if !steps.is_finite() || steps < 0.0 => -1.0,
+            _ if exact => rounded,
+            steps => steps.floor(),
+        };
+        let count = (steps + 1.0) as usize;
+        Self {
+            start: domain.start,
+            span: steps * step_by,
+            count,
+            index: 0,
+            last: Some(domain.end).filter(|_| exact && count > 1),
+        }
+    }
+}
+
+impl Iterator for DomainIterator {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let value = match self.last {
+            Some(last) if self.index + 1 == self.count => last,
+            _ => {
+                let steps = (self.count - 1).max(1) as f64;
+                self.start + self.span * self.index as f64 / steps
+            }
+        };
+        self.index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for DomainIterator {}
+
+/// The view where graphs are graphed and plots are plotted.
+///
+/// Braille characters are use to draw on the canvas: each character holds 2 by 4 pixels.
+pub struct ViewCanvas<'view> {
+    canvas: braille::Canvas,
+    view: &'view View,
+    color: Option<Color>,
+    /// The style of the plot being drawn, see [`Plot::set_style`].
+    style: Style,
+    out_of_view: OutOfView,
+    /// The color of every pixel set, when they are recorded to draw an image.
+    recorded: Option<std::collections::HashMap<(u32, u32), Option<Color>>>,
 }
 
-impl DomainIterator {
-    pub fn new(domain: ops::Range<f64>, step_by: f64) -> Self {
-        Self {
-            current: domain.start,
-            domain,
-            step_by,
+impl<'view> ViewCanvas<'view> {
+    pub(crate) fn new(view: &'view View) -> Self {
+        Self {
+            canvas: braille::Canvas::new(view.size.w, view.size.h),
+            view,
+            color: None,
+            style: Style::default(),
+            out_of_view: view.out_of_view,
+            recorded: None,
+        }
+    }
+
+    /// Set the color used by the next drawing operations. `None` uses the default color of the
+    /// terminal.
+    ///
+    /// Colors are applied to whole characters of the canvas (2 by 4 pixels): when two series
+    /// share a character, the last one drawn gives its color to the character. The color of the
+    /// style of the plot, if any, is used instead (see [`Plot::set_style`]).
+    pub fn set_color(&mut self, color: Option<Color>) {
+        self.color = self.style.color.or(color);
+    }
+
+    /// Set what the next drawing operations do with the points outside of the view. The default
+    /// is the policy of the plot (see [`Plot::set_out_of_view`]), which is [`OutOfView::Clamp`]
+    /// unless changed.
+    ///
+    /// The policy applies to [`ViewCanvas::point`], [`ViewCanvas::shade`] and the rectangles
+    /// ([`ViewCanvas::fill_rect`], [`ViewCanvas::rect`]). The lines and polygons are always
+    /// clipped to the view.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// struct Samples;
+    ///
+    /// impl DrawView for Samples {
+    ///     fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+    ///         // The outlier is not drawn on the top edge of the view.
+    ///         canvas.set_out_of_view(OutOfView::Skip);
+    ///         for (x, y) in [(1.0, 0.5), (2.0, 1e6), (3.0, 0.2)] {
+    ///             canvas.point(x, y);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..4.0))
+    ///     .set_codomain(Domain(0.0..1.0))
+    ///     .set_size(Size::new(40, 12))
+    ///     .add_plot(Box::new(Samples));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn set_out_of_view(&mut self, out_of_view: OutOfView) {
+        self.out_of_view = out_of_view;
+    }
+
+    /// The edges of the view in the plotting space, smallest first even when the domain or the
+    /// codomain is reversed.
+    fn bounds(&self) -> clip::ClipRect {
+        let (domain, codomain) = (&self.view.domain, &self.view.codomain);
+        clip::ClipRect {
+            x_min: domain.min().min(domain.max()),
+            x_max: domain.min().max(domain.max()),
+            y_min: codomain.min().min(codomain.max()),
+            y_max: codomain.min().max(codomain.max()),
         }
     }
-}
 
-impl Iterator for DomainIterator {
-    type Item = f64;
+    /// Whether the point (`x`, `y`) of the plotting space is inside of the view, edges included.
+    fn contains(&self, x: f64, y: f64) -> bool {
+        let bounds = self.bounds();
+        (bounds.x_min..=bounds.x_max).contains(&x) && (bounds.y_min..=bounds.y_max).contains(&y)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current >= self.domain.end {
-            return None;
-        }
-        let result = self.current;
-        self.current += self.step_by;
-        Some(result)
+    /// Whether no part of the rectangle from (`x0`, `y0`) to (`x1`, `y1`) is inside of the view.
+    fn outside(&self, x0: f64, y0: f64, x1: f64, y1: f64) -> bool {
+        let bounds = self.bounds();
+        x0.max(x1) < bounds.x_min
+            || x0.min(x1) > bounds.x_max
+            || y0.max(y1) < bounds.y_min
+            || y0.min(y1) > bounds.y_max
     }
-}
 
-/// The view where graphs are graphed and plots are plotted.
-///
-/// Braille characters are use to draw on the canvas. `termplot` uses [`drawille::Canvas`] for
-/// darwing.
-pub struct ViewCanvas<'view> {
-    canvas: drawille::Canvas,
-    view: &'view View,
-}
+    /// Whether a point drawn at (`x`, `y`) is skipped, see [`ViewCanvas::set_out_of_view`].
+    fn skips(&self, x: f64, y: f64) -> bool {
+        self.out_of_view == OutOfView::Skip && !self.contains(x, y)
+    }
 
-impl<'view> ViewCanvas<'view> {
-    pub(crate) fn new(view: &'view View) -> Self {
-        Self {
-            canvas: drawille::Canvas::new(view.size.w as u32, view.size.h as u32),
-            view,
+    /// The view drawn on the canvas.
+    pub(crate) fn view(&self) -> &'view View {
+        self.view
+    }
+
+    fn set_pixel(&mut self, x: u32, y: u32) {
+        self.canvas.set(x, y, self.color);
+        if let Some(recorded) = &mut self.recorded {
+            recorded.insert((x, y), self.color);
         }
     }
 
-    pub(crate) fn rows(&self) -> Vec<String> {
-        let rows = self.canvas.rows();
-        // println!("{:?}", rows[0].chars().count());
-        rows
+    /// The rows of the canvas, with their colors when `colors` is `true`.
+    pub(crate) fn rows(&mut self, colors: bool) -> &[String] {
+        self.canvas.rows(self.view.background, colors)
     }
 
     fn project_on_canvas(&self, x: f64, y: f64) -> (u32, u32) {
+        let (x, y) = self.project_exact(x, y);
         let height = self.view.size.h as f64;
-        let y_tmp = (y - self.view.codomain.min()) / self.view.codomain.range();
-        let y = (height - y_tmp * height).round().clamp(0.0, height - 1.0);
-
+        let y = y.round().clamp(0.0, (height - 1.0).max(0.0));
         let width = self.view.size.w as f64;
-        let x_tmp = (x - self.view.domain.min()) / self.view.domain.range();
-        let x = (x_tmp * width).round().clamp(0.0, width - 1.0);
+        let x = x.round().clamp(0.0, (width - 1.0).max(0.0));
 
         (x as u32, y as u32)
     }
 
+    /// The position of (`x`, `y`) in pixels, before it is rounded to a pixel of the canvas.
+    fn project_exact(&self, x: f64, y: f64) -> (f64, f64) {
+        let height = self.view.size.h as f64;
+        let y_tmp = self.view.y_scale.fraction(&self.view.codomain, y);
+        let width = self.view.size.w as f64;
+        let x_tmp = self.view.x_scale.fraction(&self.view.domain, x);
+        (x_tmp * width, height - y_tmp * height)
+    }
+
+    /// Fill the polygon with the given `vertices`, setting every pixel whose center is inside of
+    /// it. The polygon can be concave, and its edges can cross each other: a pixel is inside when
+    /// a line going right from it crosses an odd number of edges.
+    ///
+    /// The coordinates are of the plotting space, like [`ViewCanvas::line`]. The parts of the
+    /// polygon outside of the view are not drawn. Use [`ViewCanvas::polygon`] to draw its
+    /// outline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// struct Band;
+    ///
+    /// impl DrawView for Band {
+    ///     fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+    ///         let upper = (0..=20).map(|x| (x as f64, (x as f64 / 3.0).sin() + 0.5));
+    ///         let lower = (0..=20).rev().map(|x| (x as f64, (x as f64 / 3.0).sin() - 0.5));
+    ///         canvas.fill_polygon(&upper.chain(lower).collect::<Vec<_>>());
+    ///     }
+    /// }
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..20.0))
+    ///     .set_codomain(Domain(-2.0..2.0))
+    ///     .set_size(Size::new(60, 24))
+    ///     .add_plot(Box::new(Band));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn fill_polygon(&mut self, vertices: &[(f64, f64)]) {
+        let points = vertices
+            .iter()
+            .map(|&(x, y)| self.project_exact(x, y))
+            .collect::<Vec<_>>();
+        self.fill_pixels(&points);
+    }
+
+    /// Fill the polygon with the given vertices in pixels. See [`ViewCanvas::fill_polygon`].
+    fn fill_pixels(&mut self, points: &[(f64, f64)]) {
+        if points.len() < 3 {
+            return;
+        }
+        let last_column = self.view.size.w as f64 - 1.0;
+        for row in 0..self.view.size.h as u32 {
+            let y = row as f64;
+            let mut crossings = Vec::new();
+            for (index, &(x0, y0)) in points.iter().enumerate() {
+                let (x1, y1) = points[(index + 1) % points.len()];
+                // The edges include their lower end and exclude their upper end, so that a
+                // vertex on the row is crossed once.
+                if (y0 <= y) != (y1 <= y) {
+                    crossings.push(x0 + (y - y0) * (x1 - x0) / (y1 - y0));
+                }
+            }
+            crossings.sort_by(f64::total_cmp);
+            for span in crossings.chunks_exact(2) {
+                let (start, end) = (span[0].ceil().max(0.0), span[1].floor().min(last_column));
+                if start <= end {
+                    for x in start as u32..=end as u32 {
+                        self.set_pixel(x, row);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fill the rectangle with the corners (`x0`, `y0`) and (`x1`, `y1`).
+    ///
+    /// The pixels of the right and top edges are not set, so that adjacent rectangles (like the
+    /// bars of a histogram) do not overlap. A rectangle thinner than a pixel still sets a pixel.
+    pub fn fill_rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64) {
+        self.shade_rect(x0, y0, x1, y1, 1.0);
+    }
+
     /// Draw a line from the point (`x0`, `y0`) to (`x1`, `y1`).
     ///
     /// The coordinate are of the plotting space, and **not the actual pixel's coordinate.**
@@ -506,10 +2680,227 @@ impl<'view> ViewCanvas<'view> {
     /// This function uses the domain and codomain of the [`View`] to determine which pixels should
     /// be drawn. Therefor the drawn shape is relative to the position of the domain and codomain
     /// of the plotting space.
+    ///
+    /// The parts of the line outside of the domain and codomain are not drawn. The line is
+    /// thicker when the style of the plot has a line width (see [`Plot::set_style`]).
     pub fn line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64) {
+        match self.style.line_width {
+            width if width > 1.0 => self.line_with_width(x0, y0, x1, y1, width),
+            _ => self.thin_line(x0, y0, x1, y1),
+        }
+    }
+
+    /// Draw a line one pixel thick. See [`ViewCanvas::line`].
+    fn thin_line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64) {
+        let Some(((x0, y0), (x1, y1))) = self.bounds().line((x0, y0), (x1, y1)) else {
+            return;
+        };
+        if self.view.antialiasing {
+            let (from, to) = (self.project_exact(x0, y0), self.project_exact(x1, y1));
+            self.smooth_line(from, to);
+            return;
+        }
         let (x0, y0) = self.project_on_canvas(x0, y0);
         let (x1, y1) = self.project_on_canvas(x1, y1);
-        self.canvas.line(x0, y0, x1, y1);
+        if self.recorded.is_some() {
+            self.pixel_line((x0, y0), (x1, y1));
+            return;
+        }
+        self.canvas.line((x0, y0), (x1, y1), self.color);
+    }
+
+    /// Draw a line between two pixels one pixel at a time, so that each of them is recorded.
+    fn pixel_line(&mut self, from: (u32, u32), to: (u32, u32)) {
+        let (dx, dy) = (to.0 as f64 - from.0 as f64, to.1 as f64 - from.1 as f64);
+        let steps = dx.abs().max(dy.abs()).max(1.0);
+        for step in 0..=steps as u32 {
+            let fraction = step as f64 / steps;
+            let x = from.0 as f64 + dx * fraction;
+            let y = from.1 as f64 + dy * fraction;
+            self.set_pixel(x.round() as u32, y.round() as u32);
+        }
+    }
+
+    /// Draw a line between two points in pixels without rounding them first. See
+    /// [`Plot::set_antialiasing`].
+    fn smooth_line(&mut self, from: (f64, f64), to: (f64, f64)) {
+        let last = (
+            (self.view.size.w as f64 - 1.0).max(0.0),
+            (self.view.size.h as f64 - 1.0).max(0.0),
+        );
+        let clamp = |(x, y): (f64, f64)| (x.clamp(0.0, last.0), y.clamp(0.0, last.1));
+        let (from, to) = (clamp(from), clamp(to));
+        // The line is walked along its major axis, one pixel at a time.
+        let steep = (to.1 - from.1).abs() > (to.0 - from.0).abs();
+        let swap = |(x, y): (f64, f64)| if steep { (y, x) } else { (x, y) };
+        let (mut from, mut to) = (swap(from), swap(to));
+        if from.0 > to.0 {
+            std::mem::swap(&mut from, &mut to);
+        }
+        let slope = match to.0 - from.0 {
+            run if run > 0.0 => (to.1 - from.1) / run,
+            _ => 0.0,
+        };
+        for major in from.0.round() as u32..=to.0.round() as u32 {
+            let minor = from.1 + (major as f64 - from.0).clamp(0.0, to.0 - from.0) * slope;
+            let fraction = minor - minor.floor();
+            // A dot is set when the line covers at least a third of it.
+            let mut minors = Vec::with_capacity(2);
+            if fraction <= 2.0 / 3.0 {
+                minors.push(minor.floor());
+            }
+            if fraction >= 1.0 / 3.0 {
+                minors.push((minor.floor() + 1.0).min(if steep { last.0 } else { last.1 }));
+            }
+            for minor in minors {
+                let (x, y) = swap((major as f64, minor));
+                self.set_pixel(x as u32, y as u32);
+            }
+        }
+    }
+
+    /// Draw a line `width` pixels thick from the point (`x0`, `y0`) to (`x1`, `y1`), to emphasize
+    /// it or to keep it visible on a small view.
+    ///
+    /// The thickness is measured across the line, in pixels of the canvas (a character is 2 by 4
+    /// pixels). A width of 1 pixel or less draws the same line as [`ViewCanvas::line`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// struct Fit;
+    ///
+    /// impl DrawView for Fit {
+    ///     fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+    ///         canvas.line_with_width(0.0, 1.0, 10.0, 6.0, 3.0);
+    ///     }
+    /// }
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..10.0))
+    ///     .set_codomain(Domain(0.0..8.0))
+    ///     .set_size(Size::new(50, 24))
+    ///     .add_plot(Box::new(Fit));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn line_with_width(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, width: f64) {
+        self.thin_line(x0, y0, x1, y1);
+        let width = width.max(self.style.line_width);
+        if width <= 1.0 {
+            return;
+        }
+        let (from, to) = (self.project_exact(x0, y0), self.project_exact(x1, y1));
+        let length = (to.0 - from.0).hypot(to.1 - from.1);
+        if length == 0.0 || !length.is_finite() {
+            return;
+        }
+        // Half of the width along the normal of the line, and along the line itself to square
+        // the ends so that the lines of a polyline join without gaps.
+        let normal = (
+            (from.1 - to.1) / length * width / 2.0,
+            (to.0 - from.0) / length * width / 2.0,
+        );
+        let along = (normal.1, -normal.0);
+        self.fill_pixels(&[
+            (from.0 - along.0 + normal.0, from.1 - along.1 + normal.1),
+            (to.0 + along.0 + normal.0, to.1 + along.1 + normal.1),
+            (to.0 + along.0 - normal.0, to.1 + along.1 - normal.1),
+            (from.0 - along.0 - normal.0, from.1 - along.1 - normal.1),
+        ]);
+    }
+
+    /// Draw the lines joining `points` in order.
+    ///
+    /// The coordinates are of the plotting space, like [`ViewCanvas::line`].
+    pub fn polyline(&mut self, points: &[(f64, f64)]) {
+        for line in points.windows(2) {
+            self.line(line[0].0, line[0].1, line[1].0, line[1].1);
+        }
+    }
+
+    /// Draw the outline of the polygon with the given `vertices`: the lines joining them in order,
+    /// and the line joining the last vertex to the first one.
+    pub fn polygon(&mut self, vertices: &[(f64, f64)]) {
+        self.polyline(vertices);
+        if let (Some(&(x0, y0)), Some(&(x1, y1))) = (vertices.last(), vertices.first()) {
+            self.line(x0, y0, x1, y1);
+        }
+    }
+
+    /// Draw the outline of the rectangle with the corners (`x0`, `y0`) and (`x1`, `y1`).
+    pub fn rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64) {
+        self.polygon(&[(x0, y0), (x1, y0), (x1, y1), (x0, y1)]);
+    }
+
+    /// Draw the arc of the ellipse centered on (`x`, `y`) with the radius `rx` along the x axis
+    /// and `ry` along the y axis, from the angle `start` to `end` (in radians, counterclockwise
+    /// from the x axis).
+    ///
+    /// Angles are measured on the circle stretched into the ellipse: with `start = 0` and
+    /// `end = PI`, the arc is the top half of the ellipse. The arc is drawn with short lines,
+    /// about one for each pixel along it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// struct Smiley;
+    ///
+    /// impl DrawView for Smiley {
+    ///     fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+    ///         canvas.circle(0.0, 0.0, 4.0);
+    ///         canvas.circle(-1.5, 1.5, 0.5);
+    ///         canvas.circle(1.5, 1.5, 0.5);
+    ///         canvas.arc(0.0, 0.0, 2.5, 2.0, PI * 1.15, PI * 1.85);
+    ///     }
+    /// }
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-5.0..5.0))
+    ///     .set_codomain(Domain(-5.0..5.0))
+    ///     .set_aspect(Aspect::Equal)
+    ///     .set_axis_mode(AxisMode::None)
+    ///     .set_size(Size::new(40, 40))
+    ///     .add_plot(Box::new(Smiley));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn arc(&mut self, x: f64, y: f64, rx: f64, ry: f64, start: f64, end: f64) {
+        let pixels = (
+            rx.abs() / self.view.domain.range() * self.view.size.w as f64,
+            ry.abs() / self.view.codomain.range() * self.view.size.h as f64,
+        );
+        let segments = (pixels.0.max(pixels.1) * (end - start).abs()).ceil();
+        let segments = match segments.is_finite() {
+            true => segments.clamp(1.0, 10_000.0) as usize,
+            false => 1,
+        };
+        let points = (0..=segments)
+            .map(|segment| {
+                let angle = start + (end - start) * segment as f64 / segments as f64;
+                (x + rx * angle.cos(), y + ry * angle.sin())
+            })
+            .collect::<Vec<_>>();
+        self.polyline(&points);
+    }
+
+    /// Draw the outline of the ellipse centered on (`x`, `y`) with the radius `rx` along the x
+    /// axis and `ry` along the y axis. See [`ViewCanvas::arc`].
+    pub fn ellipse(&mut self, x: f64, y: f64, rx: f64, ry: f64) {
+        self.arc(x, y, rx, ry, 0.0, std::f64::consts::TAU);
+    }
+
+    /// Draw the outline of the circle centered on (`x`, `y`).
+    ///
+    /// The radius is in units of both axis, so the circle is only round on the screen when the
+    /// units of the x and y axis have the same length (see [`Aspect::Equal`]).
+    pub fn circle(&mut self, x: f64, y: f64, radius: f64) {
+        self.ellipse(x, y, radius, radius);
     }
 
     /// Draw a point at (`x`, `y`).
@@ -520,8 +2911,166 @@ impl<'view> ViewCanvas<'view> {
     /// be drawn. Therefor the drawn shape is relative to the position of the domain and codomain
     /// of the plotting space.
     pub fn point(&mut self, x: f64, y: f64) {
+        if self.skips(x, y) {
+            return;
+        }
+        let (x, y) = self.project_on_canvas(x, y);
+        self.set_pixel(x, y);
+    }
+
+    /// Write `text` on the row holding (`x`, `y`): starting at `x` with [`Align::Left`], centered
+    /// on `x` with [`Align::Center`] and ending at `x` with [`Align::Right`].
+    ///
+    /// The coordinates are of the plotting space, like [`ViewCanvas::line`]. Each character
+    /// takes a whole cell of the canvas (2 by 4 pixels) and replaces what was drawn in that cell,
+    /// so the text stays readable over the plots. Characters that would fall after the right edge
+    /// of the view are not drawn, and text that would start before the left edge is moved to
+    /// start at the edge.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// struct Annotated;
+    ///
+    /// impl DrawView for Annotated {
+    ///     fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+    ///         for x in 0..20 {
+    ///             let x = x as f64 * 0.5;
+    ///             canvas.line(x, x.sin(), x + 0.5, (x + 0.5).sin());
+    ///         }
+    ///         canvas.text(1.57, 1.2, "maximum", Align::Center);
+    ///         canvas.text(4.71, -1.2, "minimum", Align::Center);
+    ///     }
+    /// }
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..10.0))
+    ///     .set_codomain(Domain(-1.5..1.5))
+    ///     .set_size(Size::new(60, 24))
+    ///     .add_plot(Box::new(Annotated));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn text(&mut self, x: f64, y: f64, text: &str, align: Align) {
+        let (x, y) = self.project_on_canvas(x, y);
+        // The text is aligned on the columns it takes in the terminal, one pixel per half column.
+        let width = text::width(text) as u32;
+        let start = match align {
+            Align::Left => x,
+            Align::Center => x.saturating_sub(width),
+            Align::Right => x.saturating_sub(2 * width),
+        };
+        self.write_text(start, y, text);
+    }
+
+    fn write_text(&mut self, start: u32, y: u32, text: &str) {
+        for (index, c) in text.chars().enumerate() {
+            let x = start + index as u32 * 2;
+            if x >= self.view.size.w as u32 {
+                break;
+            }
+            self.canvas.set_char(x, y, c);
+        }
+    }
+
+    /// Shade the character cell (2 by 4 pixels) holding the point (`x`, `y`) by setting from 0 to
+    /// 8 of its dots. `level` goes from `0.0` (no dots) to `1.0` (all dots), so that intensities
+    /// can be shown without colors.
+    ///
+    /// The dots are set in an order spreading them evenly over the cell, and the dots already
+    /// set in the cell are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// struct Gradient;
+    ///
+    /// impl DrawView for Gradient {
+    ///     fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+    ///         // A cell of a view 40 pixels wide is 0.05 wide.
+    ///         for cell in 0..20 {
+    ///             let x = (cell as f64 + 0.5) * 0.05;
+    ///             canvas.shade(x, 0.5, x);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..1.0))
+    ///     .set_codomain(Domain(0.0..1.0))
+    ///     .set_size(Size::new(40, 12))
+    ///     .add_plot(Box::new(Gradient));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn shade(&mut self, x: f64, y: f64, level: f64) {
+        if self.skips(x, y) {
+            return;
+        }
         let (x, y) = self.project_on_canvas(x, y);
-        self.canvas.set(x, y);
+        let (x, y) = (x - x % 2, y - y % 4);
+        self.shade_pixels(x..x + 2, y..y + 4, level);
+    }
+
+    /// Highlight the characters covering the rectangle from (`x0`, `y0`) to (`x1`, `y1`): their
+    /// background is the color set with [`ViewCanvas::set_color`], or they are drawn in reverse
+    /// video when the color is `None`. The dots of the characters are kept.
+    ///
+    /// Highlights apply to whole characters (2 by 4 pixels), and are only drawn with the braille
+    /// backend. A rectangle entirely outside of the view is not highlighted. See
+    /// [`plot::Highlight`].
+    pub fn highlight_rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64) {
+        if self.outside(x0, y0, x1, y1) {
+            return;
+        }
+        // The characters partially covered are highlighted, and at least one of them.
+        let (size, (x0, y0), (x1, y1)) = (
+            &self.view.size,
+            self.project_exact(x0, y0),
+            self.project_exact(x1, y1),
+        );
+        let cells = |start: f64, end: f64, pixels: usize, cell: f64| {
+            let clamp = |value: f64| value.clamp(0.0, pixels as f64) / cell;
+            let start = clamp(start).floor() as usize;
+            start..(clamp(end).ceil() as usize).max(start + 1)
+        };
+        let columns = cells(x0.min(x1), x0.max(x1), size.w, 2.0);
+        let rows = cells(y0.min(y1), y0.max(y1), size.h, 4.0);
+        self.canvas.highlight(columns, rows, self.color);
+    }
+
+    /// Shade the rectangle from (`x0`, `y0`) to (`x1`, `y1`) by setting a proportion of its
+    /// pixels. `level` goes from `0.0` (no pixels) to `1.0` (all pixels).
+    pub(crate) fn shade_rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, level: f64) {
+        // A rectangle crossing the view is clipped by clamping its corners.
+        if self.out_of_view == OutOfView::Skip && self.outside(x0, y0, x1, y1) {
+            return;
+        }
+        let (x0, y0) = self.project_on_canvas(x0, y0);
+        let (x1, y1) = self.project_on_canvas(x1, y1);
+        // The far edges are excluded so that adjacent rectangles do not overlap.
+        self.shade_pixels(
+            x0.min(x1)..x0.max(x1).max(x0.min(x1) + 1),
+            y0.min(y1)..y0.max(y1).max(y0.min(y1) + 1),
+            level,
+        );
+    }
+
+    fn shade_pixels(&mut self, xs: ops::Range<u32>, ys: ops::Range<u32>, level: f64) {
+        /// The order in which the dots of a cell are set as the level increases.
+        const THRESHOLDS: [[u8; 2]; 4] = [[0, 4], [6, 2], [1, 5], [7, 3]];
+        let dots = (level.clamp(0.0, 1.0) * 8.0).round() as u8;
+        for y in ys {
+            for x in xs.clone() {
+                if THRESHOLDS[y as usize % 4][x as usize % 2] < dots {
+                    self.set_pixel(x, y);
+                }
+            }
+        }
     }
 }
 
@@ -544,6 +3093,398 @@ mod tests {
         println!("{plot}");
     }
 
+    #[test]
+    fn render_errors() {
+        let render = |size: Size, domain: Domain| {
+            let mut plot = Plot::default();
+            plot.set_domain(domain)
+                .set_size(size)
+                .add_plot(Box::new(plot::Graph::new(|x| x.sin())));
+            plot.try_render()
+        };
+        for size in [Size::new(0, 0), Size::new(0, 10), Size::new(10, 0)] {
+            assert_eq!(render(size, Domain(0.0..1.0)), Err(RenderError::EmptyView));
+        }
+        assert_eq!(
+            render(Size::new(10, 10), Domain(f64::NAN..1.0)),
+            Err(RenderError::InvalidDomain)
+        );
+        assert!(render(Size::new(1, 1), Domain(0.0..1.0)).is_ok());
+        let mut plot = Plot::default();
+        plot.set_y_scale(Scale::SymLog { linthresh: 0.0 });
+        assert_eq!(plot.try_render(), Err(RenderError::InvalidScale));
+    }
+
+    #[test]
+    fn reversed_domain() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(8.0..-8.0))
+            .set_codomain(Domain(0.0..1.0))
+            .set_size(Size::new(40, 8))
+            .set_x_ticks(3)
+            .set_decorations(Decorations {
+                x_ticks: true,
+                ..Decorations::NONE
+            })
+            .add_plot(Box::new(plot::Graph::new(|x| (x > 0.0) as u8 as f64)));
+        let rendered = plot.try_render().unwrap();
+        let ticks = rendered
+            .lines()
+            .last()
+            .unwrap()
+            .split_whitespace()
+            .collect::<Vec<_>>();
+        assert_eq!(ticks, ["8.0", "0.0", "-8.0"]);
+        // The positive values are on the left.
+        assert!(rendered.lines().next().unwrap().starts_with('⠉'));
+    }
+
+    #[test]
+    fn layers() {
+        struct Dot(Color);
+
+        impl DrawView for Dot {
+            fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+                canvas.set_color(Some(self.0));
+                canvas.point(0.5, 0.5);
+            }
+        }
+
+        // The plot drawn last gives its color to the character.
+        let colored = |red: i32, blue: i32| {
+            let mut plot = Plot::default();
+            plot.set_size(Size::new(2, 4))
+                .set_axis_mode(AxisMode::None)
+                .set_decorations(Decorations::NONE)
+                .add_plot_with_order(Box::new(Dot(Color::Red)), red)
+                .add_plot_with_order(Box::new(Dot(Color::Blue)), blue);
+            let mut rendered = String::new();
+            plot.render_to(plot.decorations, true, &mut rendered)
+                .unwrap();
+            rendered
+        };
+        assert!(colored(1, 0).contains("\x1b[31m"));
+        assert!(colored(0, 1).contains("\x1b[34m"));
+        assert!(colored(0, 0).contains("\x1b[34m"));
+    }
+
+    #[test]
+    fn display_error() {
+        let mut plot = Plot::default();
+        plot.set_size(Size::new(0, 8));
+        assert_eq!(plot.to_string(), "the plot has an empty view\n");
+    }
+
+    #[test]
+    fn axis_modes() {
+        let render = |domain, mode| {
+            let mut plot = Plot::default();
+            plot.set_domain(domain)
+                .set_codomain(Domain(-1.0..1.0))
+                .set_size(Size::new(8, 8))
+                .set_axis_mode(mode)
+                .set_decorations(Decorations::NONE);
+            plot.try_render().unwrap()
+        };
+        assert_eq!(render(Domain(-1.0..1.0), AxisMode::Zero), "  ⡇ \n⠉⠉⡏⠉\n");
+        // Only the x axis goes through zero in the view.
+        assert_eq!(render(Domain(1.0..2.0), AxisMode::Zero), "    \n⠉⠉⠉⠉\n");
+        assert_eq!(render(Domain(1.0..2.0), AxisMode::Box), "⡏⠉⠉⢹\n⣇⣀⣀⣸\n");
+        assert_eq!(render(Domain(-1.0..1.0), AxisMode::None), "    \n    \n");
+    }
+
+    #[test]
+    fn tick_marks() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..1.0))
+            .set_codomain(Domain(0.0..1.0))
+            .set_size(Size::new(12, 8))
+            .set_axis_mode(AxisMode::Box)
+            .set_x_ticks(3)
+            .set_y_ticks(3)
+            .set_decorations(Decorations::NONE);
+        assert_eq!(plot.try_render().unwrap(), "⡏⠉⠉⠉⠉⢹\n⣇⣀⣀⣀⣀⣸\n");
+        plot.set_tick_marks(true);
+        assert_eq!(plot.try_render().unwrap(), "⡏⠉⠉⠉⠉⢹\n⣏⣁⣀⣄⣀⣸\n");
+    }
+
+    #[test]
+    fn shaded_cells() {
+        let view = View {
+            domain: Domain(0.0..4.0),
+            codomain: Domain(0.0..4.0),
+            size: Size::new(8, 4),
+            ..View::default()
+        };
+        let mut canvas = ViewCanvas::new(&view);
+        canvas.shade(0.5, 2.0, 0.0);
+        canvas.shade(1.5, 2.0, 0.125);
+        canvas.shade(2.5, 2.0, 0.5);
+        canvas.shade(3.5, 2.0, 1.0);
+        assert_eq!(canvas.rows(false), vec![" ⠁⢕⣿"]);
+    }
+
+    #[test]
+    fn shapes() {
+        let view = View {
+            domain: Domain(-4.0..4.0),
+            codomain: Domain(-4.0..4.0),
+            size: Size::new(16, 16),
+            ..View::default()
+        };
+        let draw = |shape: &dyn Fn(&mut ViewCanvas)| {
+            let mut canvas = ViewCanvas::new(&view);
+            shape(&mut canvas);
+            canvas.rows(false).to_vec()
+        };
+        assert_eq!(
+            draw(&|canvas| canvas.rect(-3.0, -3.0, 3.0, 3.0)),
+            [" ⡤⠤⠤⠤⠤⠤⡄", " ⡇     ⡇", " ⡇     ⡇", " ⠧⠤⠤⠤⠤⠤⠇"]
+        );
+        // The polygon is closed, the polyline is not.
+        let points = [(-3.0, -3.0), (3.0, -3.0), (0.0, 3.0)];
+        assert_eq!(
+            draw(&|canvas| canvas.polygon(&points)),
+            ["    ⣄   ", "   ⡜⠈⢆  ", "  ⡜  ⠈⢆ ", " ⠼⠤⠤⠤⠤⠬⠆"]
+        );
+        assert_eq!(
+            draw(&|canvas| canvas.polyline(&points)),
+            ["    ⢄   ", "    ⠈⢆  ", "     ⠈⢆ ", " ⠤⠤⠤⠤⠤⠬⠆"]
+        );
+        assert_eq!(
+            draw(&|canvas| canvas.circle(0.0, 0.0, 3.0)),
+            ["  ⢀⡠⠤⣀  ", " ⡜⠁   ⠙⡄", " ⢇    ⢀⠇", " ⠈⠑⠢⠤⠒⠉ "]
+        );
+        assert_eq!(
+            draw(&|canvas| canvas.ellipse(0.0, 0.0, 3.0, 1.5)),
+            ["        ", " ⣠⠔⠒⠒⠒⢤⡀", " ⠳⢄⣀⣀⣀⠴⠃", "        "]
+        );
+        // The top half of the circle.
+        let arc =
+            |canvas: &mut ViewCanvas| canvas.arc(0.0, 0.0, 3.0, 3.0, 0.0, std::f64::consts::PI);
+        assert_eq!(draw(&arc), ["  ⢀⡠⠤⣀  ", " ⡜⠁   ⠙⡄", " ⠁     ⠁", "        "]);
+    }
+
+    #[test]
+    fn aligned_text() {
+        let view = View {
+            domain: Domain(0.0..12.0),
+            codomain: Domain(0.0..1.0),
+            size: Size::new(12, 4),
+            ..View::default()
+        };
+        let mut canvas = ViewCanvas::new(&view);
+        canvas.text(12.0, 0.0, "ab", Align::Right);
+        assert_eq!(canvas.rows(false), vec!["   ab "]);
+        // Wide characters take two columns each.
+        let mut canvas = ViewCanvas::new(&view);
+        canvas.text(12.0, 0.0, "日", Align::Right);
+        assert_eq!(canvas.rows(false), vec!["   日  "]);
+        let mut canvas = ViewCanvas::new(&view);
+        canvas.text(6.0, 0.0, "日", Align::Center);
+        assert_eq!(canvas.rows(false), vec!["  日   "]);
+    }
+
+    #[test]
+    fn filled_polygon() {
+        let view = View {
+            domain: Domain(0.0..4.0),
+            codomain: Domain(0.0..4.0),
+            size: Size::new(4, 4),
+            ..View::default()
+        };
+        let mut canvas = ViewCanvas::new(&view);
+        canvas.fill_polygon(&[(-1.0, -1.0), (1.9, -1.0), (1.9, 5.0), (-1.0, 5.0)]);
+        assert_eq!(canvas.rows(false), vec!["⣿ "]);
+        canvas.fill_polygon(&[(-1.0, -1.0), (5.0, -1.0), (5.0, 5.0)]);
+        assert_eq!(canvas.rows(false), vec!["⣿⣴"]);
+    }
+
+    #[test]
+    fn antialiased_lines() {
+        let view = View {
+            domain: Domain(0.0..4.0),
+            codomain: Domain(0.0..4.0),
+            size: Size::new(4, 4),
+            antialiasing: true,
+            ..View::default()
+        };
+        // Halfway between two rows of dots, both rows are set.
+        let mut canvas = ViewCanvas::new(&view);
+        canvas.line(0.0, 2.5, 4.0, 2.5);
+        assert_eq!(canvas.rows(false), vec!["⠶⠶"]);
+        let mut canvas = ViewCanvas::new(&view);
+        canvas.line(0.0, 2.1, 4.0, 2.1);
+        assert_eq!(canvas.rows(false), vec!["⠤⠤"]);
+    }
+
+    #[test]
+    fn domain_iterator_includes_end() {
+        let xs = Domain(-10.0..10.0).iter(30).collect::<Vec<_>>();
+        assert_eq!(xs.len(), 31);
+        assert_eq!((xs[0], xs[30]), (-10.0, 10.0));
+        // 0.1 is not exact in binary: adding it 10 times does not give 1.0.
+        assert_eq!(DomainIterator::new(0.0..1.0, 0.1).count(), 11);
+        assert_eq!(DomainIterator::new(0.0..1.0, 0.1).last(), Some(1.0));
+        assert_eq!(
+            DomainIterator::new(0.0..1.0, 0.3).last(),
+            Some(0.8999999999999999)
+        );
+        assert_eq!(Domain(2.0..3.0).linspace(1).collect::<Vec<_>>(), vec![2.0]);
+        assert_eq!(Domain(2.0..3.0).linspace(0).count(), 0);
+    }
+
+    #[test]
+    fn points_out_of_view() {
+        let view = View {
+            domain: Domain(0.0..4.0),
+            codomain: Domain(0.0..4.0),
+            size: Size::new(4, 4),
+            ..View::default()
+        };
+        let mut canvas = ViewCanvas::new(&view);
+        canvas.point(1.0, 1e6);
+        canvas.fill_rect(5.0, 0.0, 6.0, 4.0);
+        assert_eq!(canvas.rows(false), vec!["⠈⠸"]);
+        // Only the point on the edge and the part of the rectangle inside of the view are drawn.
+        let mut canvas = ViewCanvas::new(&view);
+        canvas.set_out_of_view(OutOfView::Skip);
+        canvas.point(1.0, 1e6);
+        canvas.fill_rect(5.0, 0.0, 6.0, 4.0);
+        canvas.point(1.0, 4.0);
+        canvas.fill_rect(3.5, 3.5, 6.0, 6.0);
+        assert_eq!(canvas.rows(false), vec!["⠈⠈"]);
+    }
+
+    #[test]
+    fn cached_static_plots() {
+        struct Counted(std::rc::Rc<std::cell::Cell<usize>>);
+        impl DrawView for Counted {
+            fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+                self.0.set(self.0.get() + 1);
+                canvas.line(0.0, 0.0, 1.0, 1.0);
+            }
+        }
+        let draws = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..1.0))
+            .set_codomain(Domain(0.0..1.0))
+            .set_size(Size::new(8, 8))
+            .add_static_plot(Box::new(Counted(draws.clone())), 0)
+            .add_plot(Box::new(plot::Graph::new(|x| 1.0 - x)));
+        let first = plot.try_render().unwrap();
+        plot.clear_plots()
+            .add_plot(Box::new(plot::Graph::new(|x| 1.0 - x)));
+        assert_eq!(plot.try_render().unwrap(), first);
+        assert_eq!(draws.get(), 1);
+        plot.set_domain(Domain(0.0..2.0));
+        plot.try_render().unwrap();
+        assert_eq!(draws.get(), 2);
+    }
+
+    #[test]
+    fn series_visibility_and_style() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..1.0))
+            .set_codomain(Domain(0.0..1.0))
+            .set_size(Size::new(8, 8))
+            .set_axis_mode(AxisMode::None)
+            .set_decorations(Decorations::NONE)
+            .add_plot(Box::new(plot::Graph::new(|x| x)))
+            .add_plot(Box::new(plot::Graph::new(|_| 0.5)));
+        let both = plot.to_string();
+        plot.set_visible(SeriesId(1), false);
+        assert_eq!(plot.to_string(), "  ⢀⠜\n⣀⠔⠁ \n");
+        plot.set_style(
+            SeriesId(0),
+            Style {
+                color: Some(Color::Red),
+                line_width: 3.0,
+            },
+        );
+        let wide = plot.to_string();
+        assert!(text::strip_escapes(&wide).matches(' ').count() < 4);
+        plot.set_visible(SeriesId(1), true)
+            .set_style(SeriesId(0), Style::default());
+        assert_eq!(plot.to_string(), both);
+    }
+
+    #[test]
+    fn render_into_buffer() {
+        let mut plot = Plot::default();
+        plot.set_title("Title")
+            .set_y_label("y")
+            .set_y_label_position(Position::Left)
+            .set_domain(Domain(0.0..1.0))
+            .set_size(Size::new(8, 8))
+            .add_plot(Box::new(plot::Graph::new(|x| x)));
+        let mut buffer = String::from("previous frame");
+        plot.render_into(&mut buffer).unwrap();
+        assert_eq!(buffer, plot.try_render().unwrap());
+        let capacity = buffer.capacity();
+        plot.render_into(&mut buffer).unwrap();
+        assert_eq!(buffer.capacity(), capacity);
+    }
+
+    #[test]
+    fn html_colors_are_not_global() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..1.0))
+            .set_size(Size::new(8, 8))
+            .set_style(
+                SeriesId(0),
+                Style {
+                    color: Some(Color::Red),
+                    ..Style::default()
+                },
+            )
+            .add_plot(Box::new(plot::Graph::new(|x| x)));
+        let rendered = plot.try_render().unwrap();
+        assert!(plot
+            .to_html()
+            .unwrap()
+            .contains("<span style=\"color: #cd0000\">"));
+        // The cached rows of the canvas are not reused with other colors.
+        assert_eq!(plot.try_render().unwrap(), rendered);
+    }
+
+    #[test]
+    fn hidden_decorations() {
+        let mut plot = Plot::default();
+        plot.set_title("Title")
+            .set_x_label("x")
+            .set_domain(Domain(0.0..1.0))
+            .set_codomain(Domain(0.0..1.0))
+            .set_size(Size::new(8, 8))
+            .set_decorations(Decorations::NONE);
+        let rendered = plot.try_render().unwrap();
+        assert_eq!(rendered.lines().count(), 2);
+        assert!(rendered.lines().all(|line| text::width(line) == 4));
+        plot.set_decorations(Decorations {
+            x_ticks: true,
+            ..Decorations::NONE
+        });
+        assert_eq!(plot.try_render().unwrap().lines().count(), 3);
+    }
+
+    #[test]
+    fn wrapped_title_and_labels() {
+        let mut plot = Plot::default();
+        plot.set_title("A title much longer than the figure")
+            .set_x_label("first\nsecond")
+            .set_size(Size::new(20, 8))
+            .add_plot(Box::new(plot::Graph::new(|x| x.sin())));
+        let rendered = plot.try_render().unwrap();
+        let lines = rendered.lines().collect::<Vec<_>>();
+        let width = text::width(lines[2]);
+        assert!(lines.iter().all(|line| text::width(line) <= width));
+        assert_eq!(lines[0].trim(), "A title much");
+        assert_eq!(lines[1].trim(), "longer than the");
+        assert!(lines[2].contains("figure"));
+        assert!(lines.iter().any(|line| line.trim() == "first"));
+        assert!(lines.iter().any(|line| line.trim() == "second"));
+    }
+
     #[test]
     fn histogram() {
         let mut rng = rand::thread_rng();
@@ -562,6 +3503,44 @@ mod tests {
         println!("{plot}");
     }
 
+    #[test]
+    fn horizontal_histogram() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..2.0))
+            .set_codomain(Domain(0.0..2.0))
+            .set_size(Size::new(8, 8))
+            .set_axis_mode(AxisMode::None)
+            .set_decorations(Decorations::NONE)
+            .add_plot(Box::new(
+                plot::Histogram::new(vec![0.5, 0.5, 1.5], vec![0.0..1.0, 1.0..2.0])
+                    .with_style(plot::BarStyle::Filled)
+                    .horizontal(),
+            ));
+        let rendered = text::strip_escapes(&plot.try_render().unwrap());
+        let rows = rendered.lines().collect::<Vec<_>>();
+        assert_eq!(rows, vec!["⣿⣿⡇ ", "⣿⣿⣿⣿"]);
+    }
+
+    #[test]
+    fn weighted_histogram() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..2.0))
+            .set_codomain(Domain(0.0..4.0))
+            .set_size(Size::new(4, 8))
+            .set_axis_mode(AxisMode::None)
+            .set_decorations(Decorations::NONE)
+            .add_plot(Box::new(
+                plot::Histogram::weighted(
+                    vec![(0.5, 1.5), (0.7, 0.5), (1.5, 4.0), (5.0, 10.0)],
+                    vec![0.0..1.0, 1.0..2.0],
+                )
+                .with_style(plot::BarStyle::Filled),
+            ));
+        let rendered = text::strip_escapes(&plot.try_render().unwrap());
+        let rows = rendered.lines().collect::<Vec<_>>();
+        assert_eq!(rows, vec![" ⣿", "⡿⣿"]);
+    }
+
     #[test]
     fn composition() {
         let mut rng = rand::thread_rng();
@@ -582,4 +3561,18 @@ mod tests {
             })));
         println!("{plot}");
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn view_state_round_trip() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..11.0))
+            .set_codomain(Domain(-1.0..1000.0))
+            .set_y_scale(Scale::SymLog { linthresh: 1.0 })
+            .set_size(Size::new(100, 25));
+        let json = serde_json::to_string(&plot.view_state()).unwrap();
+        let mut restored = Plot::default();
+        restored.apply_view_state(serde_json::from_str(&json).unwrap());
+        assert_eq!(restored.view_state(), plot.view_state());
+    }
 }
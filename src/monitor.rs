@@ -0,0 +1,347 @@
+use crate::{color, plot, Domain, Plot, Renderer, Size};
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path;
+
+/// A live monitor of the metrics of a training job.
+///
+/// Metrics (loss, accuracy, etc...) are recorded at each epoch and a plot is drawn for each
+/// metric. The plots are automatically scaled to the recorded values. The raw values are drawn
+/// as points and a smoothed line (an exponential moving average) is drawn through them.
+///
+/// Use [`TrainingMonitor::refresh`] to redraw the plots in place in the terminal after each
+/// epoch, and [`TrainingMonitor::log_to_file`] to also keep the recorded values in a CSV file.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut monitor = TrainingMonitor::default();
+/// monitor.set_smoothing(0.6).set_size(Size::new(60, 20));
+///
+/// for epoch in 0..20 {
+///     let loss = 1.0 / (epoch as f64 + 1.0);
+///     let accuracy = 1.0 - loss / 2.0;
+///     monitor
+///         .record_epoch(epoch, &[("loss", loss), ("accuracy", accuracy)])
+///         .unwrap();
+/// }
+///
+/// println!("{monitor}");
+/// ```
+pub struct TrainingMonitor {
+    metrics: Vec<Metric>,
+    smoothing: f64,
+    size: Size,
+    log: Option<fs::File>,
+    renderer: Renderer<io::Stdout>,
+    /// The drawing of the plots, kept between refreshes to reuse its memory.
+    frame: String,
+    /// Whether the plots are fitted to the terminal.
+    #[cfg(feature = "interactive")]
+    fit_terminal: bool,
+    /// The size of the terminal (columns and rows) the plots were last fitted to.
+    #[cfg(feature = "interactive")]
+    terminal: Option<(u16, u16)>,
+}
+
+impl Default for TrainingMonitor {
+    fn default() -> Self {
+        Self {
+            metrics: Vec::new(),
+            smoothing: 0.0,
+            size: Size::new(100, 40),
+            log: None,
+            renderer: Renderer::new(io::stdout()),
+            frame: String::new(),
+            #[cfg(feature = "interactive")]
+            fit_terminal: false,
+            #[cfg(feature = "interactive")]
+            terminal: None,
+        }
+    }
+}
+
+impl TrainingMonitor {
+    /// Set the smoothing factor of the smoothed line, from `0.0` (no smoothing) to `1.0`
+    /// (excluded).
+    ///
+    /// The smoothed value at an epoch is `smoothing * previous + (1 - smoothing) * value`.
+    pub fn set_smoothing(&mut self, smoothing: f64) -> &mut Self {
+        self.smoothing = smoothing.clamp(0.0, 0.999);
+        self
+    }
+
+    /// Set the size of the plot of each metric. See [`Plot::set_size`] for more informations.
+    pub fn set_size(&mut self, size: Size) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Fit the plots to the terminal: when `fit` is `true`, the plots share the whole terminal
+    /// (see [`Plot::set_char_size`]) instead of using the size set with
+    /// [`TrainingMonitor::set_size`].
+    ///
+    /// The size of the terminal is read at each [`TrainingMonitor::refresh`], so that the plots
+    /// adapt when the terminal is resized during a long training. The plots are measured again
+    /// only when the terminal is resized or a new metric is recorded.
+    ///
+    /// Requires the `interactive` feature.
+    #[cfg(feature = "interactive")]
+    pub fn set_fit_terminal(&mut self, fit: bool) -> &mut Self {
+        self.fit_terminal = fit;
+        self.terminal = None;
+        self.metrics
+            .iter_mut()
+            .for_each(|metric| metric.fitted = None);
+        self
+    }
+
+    /// Write every recorded value to a CSV file, with the `epoch,metric,value` columns.
+    ///
+    /// The file is created, or truncated if it already exists.
+    pub fn log_to_file<P: AsRef<path::Path>>(&mut self, path: P) -> io::Result<&mut Self> {
+        let mut file = fs::File::create(path)?;
+        writeln!(file, "epoch,metric,value")?;
+        self.log = Some(file);
+        Ok(self)
+    }
+
+    /// Record the value of a metric at an epoch.
+    ///
+    /// An error is only returned if the value could not be written to the log file.
+    pub fn record(&mut self, epoch: usize, metric: &str, value: f64) -> io::Result<()> {
+        let index = match self.metrics.iter().position(|m| m.name == metric) {
+            Some(index) => index,
+            None => {
+                // The plots share the terminal: each one is fitted again to its new share.
+                #[cfg(feature = "interactive")]
+                self.metrics
+                    .iter_mut()
+                    .for_each(|metric| metric.fitted = None);
+                self.metrics.push(Metric::new(metric));
+                self.metrics.len() - 1
+            }
+        };
+        self.metrics[index].values.push((epoch as f64, value));
+        if let Some(file) = self.log.as_mut() {
+            writeln!(file, "{epoch},{metric},{value}")?;
+        }
+        Ok(())
+    }
+
+    /// Record the values of multiple metrics at an epoch.
+    pub fn record_epoch(&mut self, epoch: usize, metrics: &[(&str, f64)]) -> io::Result<()> {
+        for &(metric, value) in metrics.iter() {
+            self.record(epoch, metric, value)?;
+        }
+        Ok(())
+    }
+
+    /// The values recorded for a metric as `(epoch, value)`.
+    pub fn values(&self, metric: &str) -> Option<&[(f64, f64)]> {
+        self.metric(metric).map(|m| m.values.as_slice())
+    }
+
+    /// The smoothed values of a metric as `(epoch, value)`.
+    pub fn smoothed(&self, metric: &str) -> Option<Vec<(f64, f64)>> {
+        self.metric(metric).map(|m| m.smoothed(self.smoothing))
+    }
+
+    /// Create the plot of a metric, scaled to its values.
+    pub fn plot(&self, metric: &str) -> Option<Plot> {
+        self.metric(metric).map(|m| self.metric_plot(m))
+    }
+
+    /// Draw the plots of every metric in the terminal, replacing the plots drawn by the previous
+    /// call.
+    pub fn refresh(&mut self) -> io::Result<()> {
+        #[cfg(feature = "interactive")]
+        if self.fit_terminal {
+            let size = crossterm::terminal::size()?;
+            if self.terminal.is_some_and(|terminal| terminal != size) {
+                // The lines of the previous plots were rewrapped by the terminal: they are
+                // cleared instead of being overwritten.
+                print!("\x1b[2J\x1b[H");
+                self.renderer.reset();
+            }
+            self.fit(size);
+        }
+        self.frame.clear();
+        for metric in self.metrics.iter() {
+            let plot = self.metric_plot(metric);
+            plot.render_to(plot.decorations, color::enabled(), &mut self.frame)
+                .map_err(io::Error::other)?;
+        }
+        self.renderer.draw(&self.frame)
+    }
+
+    /// Fit the plots of the metrics to a terminal of `columns` by `rows` characters. Only the
+    /// plots that were not fitted to this size yet are measured.
+    #[cfg(feature = "interactive")]
+    fn fit(&mut self, (columns, rows): (u16, u16)) {
+        if self.terminal != Some((columns, rows)) {
+            self.terminal = Some((columns, rows));
+            self.metrics
+                .iter_mut()
+                .for_each(|metric| metric.fitted = None);
+        }
+        // A line is left for the cursor below the plots.
+        let rows = (rows as usize).saturating_sub(1) / self.metrics.len().max(1);
+        for index in 0..self.metrics.len() {
+            if self.metrics[index].fitted.is_none() {
+                let mut plot = self.metric_plot(&self.metrics[index]);
+                plot.set_char_size(columns as usize, rows);
+                self.metrics[index].fitted = Some(plot.view.size);
+            }
+        }
+    }
+
+    fn metric(&self, name: &str) -> Option<&Metric> {
+        self.metrics.iter().find(|m| m.name == name)
+    }
+
+    fn metric_plot(&self, metric: &Metric) -> Plot {
+        let smoothed = metric.smoothed(self.smoothing);
+        let (domain, codomain) = metric.bounds();
+        let mut plot = Plot::default();
+        plot.set_domain(domain)
+            .set_codomain(codomain)
+            .set_title(&metric.name)
+            .set_x_label("epoch")
+            .set_size(self.size.clone())
+            .add_plot(Box::new(plot::Scatter::new(metric.values.clone())))
+            .add_plot(Box::new(plot::Lines::new(smoothed)));
+        #[cfg(feature = "interactive")]
+        if let Some(size) = &metric.fitted {
+            plot.set_size(size.clone());
+        }
+        plot
+    }
+}
+
+impl fmt::Display for TrainingMonitor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for metric in self.metrics.iter() {
+            write!(f, "{}", self.metric_plot(metric))?;
+        }
+        Ok(())
+    }
+}
+
+/// The values recorded for a metric.
+struct Metric {
+    name: String,
+    values: Vec<(f64, f64)>,
+    /// The size of the view fitted to the terminal, when the plots are fitted.
+    #[cfg(feature = "interactive")]
+    fitted: Option<Size>,
+}
+
+impl Metric {
+    fn new(name: &str) -> Self {
+        Self {
+            name: String::from(name),
+            values: Vec::new(),
+            #[cfg(feature = "interactive")]
+            fitted: None,
+        }
+    }
+
+    fn smoothed(&self, smoothing: f64) -> Vec<(f64, f64)> {
+        let mut previous = None;
+        self.values
+            .iter()
+            .map(|&(epoch, value)| {
+                let smoothed = match previous {
+                    Some(previous) => smoothing * previous + (1.0 - smoothing) * value,
+                    None => value,
+                };
+                previous = Some(smoothed);
+                (epoch, smoothed)
+            })
+            .collect()
+    }
+
+    /// The domain and codomain containing all the values, with a small margin on the codomain.
+    fn bounds(&self) -> (Domain, Domain) {
+        let (mut min_x, mut max_x) = (f64::INFINITY, f64::NEG_INFINITY);
+        let (mut min_y, mut max_y) = (f64::INFINITY, f64::NEG_INFINITY);
+        for &(x, y) in self.values.iter().filter(|(_, y)| y.is_finite()) {
+            min_x = min_x.min(x);
+            max_x = max_x.max(x);
+            min_y = min_y.min(y);
+            max_y = max_y.max(y);
+        }
+        if min_x > max_x {
+            return (Domain(0.0..1.0), Domain(0.0..1.0));
+        }
+        if max_x <= min_x {
+            max_x = min_x + 1.0;
+        }
+        let margin = match max_y - min_y {
+            range if range <= 0.0 => 1.0,
+            range => range * 0.05,
+        };
+        (Domain(min_x..max_x), Domain(min_y - margin..max_y + margin))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoothing() {
+        let mut monitor = TrainingMonitor::default();
+        monitor.set_smoothing(0.5);
+        monitor.record_epoch(0, &[("loss", 1.0)]).unwrap();
+        monitor.record_epoch(1, &[("loss", 0.0)]).unwrap();
+        monitor.record_epoch(2, &[("loss", 0.0)]).unwrap();
+        assert_eq!(
+            monitor.smoothed("loss").unwrap(),
+            vec![(0.0, 1.0), (1.0, 0.5), (2.0, 0.25)]
+        );
+        assert!(monitor.smoothed("accuracy").is_none());
+    }
+
+    #[test]
+    fn log_file() {
+        let path = std::env::temp_dir().join("termplot-training-monitor.csv");
+        let mut monitor = TrainingMonitor::default();
+        monitor.log_to_file(&path).unwrap();
+        monitor
+            .record_epoch(0, &[("loss", 0.5), ("accuracy", 0.75)])
+            .unwrap();
+        drop(monitor);
+        let log = fs::read_to_string(&path).unwrap();
+        assert_eq!(log, "epoch,metric,value\n0,loss,0.5\n0,accuracy,0.75\n");
+        fs::remove_file(path).unwrap();
+    }
+
+    #[cfg(feature = "interactive")]
+    #[test]
+    fn fitted_once() {
+        use crate::text;
+
+        let mut monitor = TrainingMonitor::default();
+        monitor.set_fit_terminal(true);
+        monitor.record_epoch(0, &[("loss", 0.5)]).unwrap();
+        monitor.fit((40, 21));
+        let rendered = monitor.to_string();
+        assert_eq!(rendered.lines().count(), 20);
+        assert!(rendered.lines().all(|line| text::width(line) <= 40));
+        // The plots are measured again only when they share the terminal differently.
+        let fitted = monitor.metrics[0].fitted.clone();
+        monitor.record_epoch(1, &[("loss", 0.25)]).unwrap();
+        assert_eq!(monitor.metrics[0].fitted, fitted);
+        monitor
+            .record_epoch(2, &[("loss", 0.2), ("accuracy", 0.5)])
+            .unwrap();
+        assert_eq!(monitor.metrics[0].fitted, None);
+        monitor.fit((40, 21));
+        assert_eq!(monitor.to_string().lines().count(), 20);
+    }
+}
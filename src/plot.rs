@@ -1,9 +1,88 @@
 //! Different types of plots and graphs that can be plotted or graphed onto the view.
 //!
 //! If a given type of plot is not present, creat it with [`DrawView`].
-use crate::{DrawView, View, ViewCanvas};
+use crate::{Align, Color, DrawView, View, ViewCanvas};
+use stacked::interpolate;
 use std::ops;
 
+mod bullet;
+mod calculus;
+mod calendar;
+mod confusion;
+mod critical;
+mod density2d;
+mod diff;
+#[cfg(feature = "complex")]
+mod domain_coloring;
+mod dot;
+mod dumbbell;
+mod fan;
+mod gantt;
+mod gauge;
+mod heatmap;
+mod hexbin;
+mod highlight;
+mod implicit;
+mod lines;
+mod multilines;
+mod ohlc;
+mod phase;
+mod radar;
+mod ribbon;
+mod roc;
+mod rolling;
+mod scree;
+mod slope;
+mod spectrogram;
+#[cfg(feature = "dsp")]
+mod spectrum;
+mod stacked;
+mod stats;
+mod stream;
+mod streamlines;
+mod strip;
+mod treemap;
+mod waterfall;
+
+pub use bullet::Bullet;
+pub use calculus::{Derivative, Integral};
+pub use calendar::CalendarHeatmap;
+pub use confusion::ConfusionMatrix;
+pub use critical::{Critical, CriticalPoints};
+pub use density2d::Density2D;
+pub use diff::Diff;
+#[cfg(feature = "complex")]
+pub use domain_coloring::DomainColoring;
+pub use dot::DotPlot;
+pub use dumbbell::Dumbbell;
+pub use fan::FanChart;
+pub use gantt::{Gantt, Task};
+pub use gauge::Gauge;
+pub use heatmap::Heatmap;
+pub use hexbin::Hexbin;
+pub use highlight::Highlight;
+pub use implicit::Implicit;
+pub use lines::{Lines, Scatter};
+pub use multilines::MultiLines;
+pub use ohlc::{Ohlc, Quote};
+pub use phase::PhasePortrait;
+pub use radar::Radar;
+pub use ribbon::Ribbon;
+pub use roc::{PrecisionRecall, Roc};
+pub use rolling::RollingSeries;
+pub use scree::Scree;
+pub use slope::SlopeField;
+pub use spectrogram::Spectrogram;
+#[cfg(feature = "dsp")]
+pub use spectrum::Spectrum;
+pub use stacked::StackedArea;
+pub use stats::StatsOverlay;
+pub use stream::{StreamLines, StreamScatter};
+pub use streamlines::Streamlines;
+pub use strip::Strip;
+pub use treemap::Treemap;
+pub use waterfall::Waterfall;
+
 /// A continuous function to be graphed on the figure.
 ///
 /// Use this struct to plot continuous functions on the graph.
@@ -31,6 +110,8 @@ where
     F: Fn(f64) -> f64,
 {
     function: F,
+    gradient: Option<Gradient>,
+    width: f64,
 }
 
 impl<F> Graph<F>
@@ -39,7 +120,48 @@ where
 {
     /// Create a new continuous function to be added to the plot.
     pub fn new(function: F) -> Self {
-        Self { function }
+        Self {
+            function,
+            gradient: None,
+            width: 1.0,
+        }
+    }
+
+    /// Color each segment of the graph with the color given by `gradient` at the middle `(x, y)`
+    /// of the segment, to show the magnitude of the function along a single series.
+    ///
+    /// Colors are applied to whole characters of the canvas (see [`ViewCanvas::set_color`]). See
+    /// [`Colormap::gradient`](crate::color::Colormap::gradient) to color by the y value with a
+    /// colormap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let graph = plot::Graph::new(|x| x.sin() / x).with_gradient(|_, y| {
+    ///     let t = ((y + 0.3) / 1.5).clamp(0.0, 1.0);
+    ///     Color::Rgb((255.0 * t) as u8, 64, (255.0 * (1.0 - t)) as u8)
+    /// });
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(-10.0..10.0))
+    ///     .set_codomain(Domain(-0.3..1.2))
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(graph));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn with_gradient(mut self, gradient: impl Fn(f64, f64) -> Color + 'static) -> Self {
+        self.gradient = Some(Box::new(gradient));
+        self
+    }
+
+    /// Draw the graph with lines `width` pixels thick, to emphasize it. See
+    /// [`ViewCanvas::line_with_width`].
+    pub fn with_line_width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
     }
 }
 
@@ -48,6 +170,16 @@ where
     F: Fn(f64) -> f64,
 {
     fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        segments(
+            canvas,
+            &self.points(view),
+            self.gradient.as_ref(),
+            self.width,
+        );
+    }
+
+    /// The function sampled at every pixel of the width of the view, where it is finite.
+    fn points(&self, view: &View) -> Vec<(f64, f64)> {
         view.domain
             .iter(view.size.w)
             .filter_map(|x| {
@@ -57,12 +189,7 @@ where
                     false => None,
                 }
             })
-            .collect::<Vec<_>>()
-            .windows(2)
-            .into_iter()
-            .for_each(|line| {
-                canvas.line(line[0].0, line[0].1, line[1].0, line[1].1);
-            });
+            .collect()
     }
 }
 
@@ -81,6 +208,51 @@ impl Bar {
     }
 }
 
+impl Bar {
+    /// Draw the bar with the given style.
+    fn draw_styled(&self, view: &View, canvas: &mut ViewCanvas, style: BarStyle) {
+        if style == BarStyle::Filled {
+            canvas.fill_rect(self.x, 0.0, self.x + self.width, self.height);
+        }
+        self.draw(view, canvas);
+    }
+
+    /// Write the height of the bar above it, or inside of it when there is no room above.
+    fn draw_value_label(&self, view: &View, canvas: &mut ViewCanvas) {
+        let row_height = 4.0 * view.codomain.range() / view.size.h as f64;
+        let y = match self.height + row_height {
+            y if y <= view.codomain.max() => y,
+            _ => self.height - row_height,
+        };
+        let label = ((self.height * 100.0).round() / 100.0).to_string();
+        canvas.text(self.x + self.width / 2.0, y, &label, Align::Center);
+    }
+
+    /// Draw the bar along the x axis, from `x = 0`: its range is on the y axis.
+    fn draw_horizontal(&self, canvas: &mut ViewCanvas, style: BarStyle) {
+        let (y0, y1) = (self.x, self.x + self.width);
+        if style == BarStyle::Filled {
+            canvas.fill_rect(0.0, y0, self.height, y1);
+        }
+        canvas.line(0.0, y0, self.height, y0);
+        canvas.line(0.0, y1, self.height, y1);
+        canvas.line(self.height, y0, self.height, y1);
+    }
+
+    /// Write the length of the horizontal bar after it, or inside of it when there is no room
+    /// after.
+    fn draw_horizontal_value_label(&self, view: &View, canvas: &mut ViewCanvas) {
+        let label = ((self.height * 100.0).round() / 100.0).to_string();
+        let column_width = 2.0 * view.domain.range() / view.size.w as f64;
+        let length = column_width * (label.chars().count() + 1) as f64;
+        let y = self.x + self.width / 2.0;
+        match self.height + length <= view.domain.max() {
+            true => canvas.text(self.height + column_width, y, &label, Align::Left),
+            false => canvas.text(self.height - column_width, y, &label, Align::Right),
+        }
+    }
+}
+
 impl DrawView for Bar {
     fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
         canvas.line(self.x, 0.0, self.x, self.height);
@@ -89,9 +261,20 @@ impl DrawView for Bar {
     }
 }
 
+/// How the bars of a [`Bars`] graph or an [`Histogram`] are drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BarStyle {
+    /// Only the outline of the bars is drawn.
+    #[default]
+    Outlined,
+    /// The bars are filled with braille dots.
+    Filled,
+}
+
 /// A bars graph.
 ///
-/// All bars are 1 unit wide.
+/// By default, the bars are 1 unit wide and the first bar starts at `x = 0`. Use
+/// [`Bars::with_positions`] to place the bars at given x coordinates.
 ///
 /// # Examples
 ///
@@ -108,10 +291,35 @@ impl DrawView for Bar {
 ///     .set_size(Size::new(50, 25))
 ///     .add_plot(Box::new(plot::Bars::new(
 ///         vec![2.0, 5.0, 1.0, 8.0, 9.0, 1.0],
-///     )));
+///     ).with_style(plot::BarStyle::Filled)));
+/// ```
+///
+/// Bars at given x coordinates:
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut plot = Plot::default();
+///
+/// plot.set_domain(Domain(1990.0..2030.0))
+///     .set_codomain(Domain(0.0..10.0))
+///     .set_size(Size::new(50, 25))
+///     .add_plot(Box::new(
+///         plot::Bars::with_positions(vec![(2000.0, 4.0), (2010.0, 7.0), (2020.0, 9.0)])
+///             .with_gap(2.0),
+///     ));
+///
+/// println!("{plot}");
 /// ```
 pub struct Bars {
-    bars: Vec<Bar>,
+    /// The center and the height of each bar.
+    bars: Vec<(f64, f64)>,
+    /// The name of the category of each bar.
+    labels: Vec<String>,
+    width: f64,
+    gap: f64,
+    value_labels: bool,
+    style: BarStyle,
 }
 
 impl Bars {
@@ -123,15 +331,127 @@ impl Bars {
         let bars = bars_height
             .into_iter()
             .enumerate()
-            .map(|(x, height)| Bar::new(x as f64, 1.0, height))
+            .map(|(x, height)| (x as f64 + 0.5, height))
             .collect::<Vec<_>>();
-        Self { bars }
+        Self {
+            bars,
+            labels: Vec::new(),
+            width: 1.0,
+            gap: 0.0,
+            value_labels: false,
+            style: BarStyle::default(),
+        }
+    }
+
+    /// Create a new bars graph from the `(x, height)` of each bar, where `x` is the center of the
+    /// bar.
+    ///
+    /// By default, the bars are as wide as the smallest distance between two bars (or 1 unit
+    /// wide when there is a single bar).
+    pub fn with_positions(bars: Vec<(f64, f64)>) -> Self {
+        let mut positions = bars.iter().map(|&(x, _)| x).collect::<Vec<_>>();
+        positions.sort_by(f64::total_cmp);
+        let width = positions
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .filter(|&distance| distance > 0.0)
+            .fold(f64::INFINITY, f64::min);
+        Self {
+            bars,
+            labels: Vec::new(),
+            width: if width.is_finite() { width } else { 1.0 },
+            gap: 0.0,
+            value_labels: false,
+            style: BarStyle::default(),
+        }
+    }
+
+    /// Create a new bars graph from the name of a category and the height of its bar.
+    ///
+    /// The bars are placed like with [`Bars::new`] and the names are written below the x axis,
+    /// centered under each bar, instead of the values of the x axis. Names that would overlap
+    /// another name are not written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// let mut plot = Plot::default();
+    ///
+    /// plot.set_domain(Domain(0.0..3.0))
+    ///     .set_codomain(Domain(0.0..10.0))
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(
+    ///         plot::Bars::labeled(vec![
+    ///             ("apples".to_string(), 4.0),
+    ///             ("pears".to_string(), 7.0),
+    ///             ("kiwis".to_string(), 2.0),
+    ///         ])
+    ///         .with_gap(0.2),
+    ///     ));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn labeled(bars: Vec<(String, f64)>) -> Self {
+        let (labels, heights) = bars.into_iter().unzip();
+        Self {
+            labels,
+            ..Self::new(heights)
+        }
+    }
+
+    /// Set the width of the bars, including the gap. See [`Bars::with_gap`].
+    #[doc(alias = "set_bar_width")]
+    pub fn with_bar_width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the space left empty between two adjacent bars. The gap is taken from the width of
+    /// the bars. By default, there is no gap.
+    #[doc(alias = "set_gap")]
+    pub fn with_gap(mut self, gap: f64) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Write the value of each bar above it (or inside of it when there is no room above).
+    pub fn with_value_labels(mut self) -> Self {
+        self.value_labels = true;
+        self
+    }
+
+    /// Set how the bars are drawn. By default, only their outline is drawn.
+    pub fn with_style(mut self, style: BarStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn bars(&self) -> impl Iterator<Item = Bar> + '_ {
+        let width = (self.width - self.gap).max(0.0);
+        self.bars
+            .iter()
+            .map(move |&(x, height)| Bar::new(x - width / 2.0, width, height))
     }
 }
 
 impl DrawView for Bars {
     fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
-        self.bars.iter().for_each(|bar| bar.draw(view, canvas));
+        self.bars()
+            .for_each(|bar| bar.draw_styled(view, canvas, self.style));
+        if self.value_labels {
+            self.bars()
+                .for_each(|bar| bar.draw_value_label(view, canvas));
+        }
+    }
+
+    fn x_labels(&self) -> Vec<(f64, String)> {
+        self.bars
+            .iter()
+            .zip(self.labels.iter())
+            .map(|(&(x, _), label)| (x, label.clone()))
+            .collect()
     }
 }
 
@@ -162,8 +482,29 @@ impl DrawView for Bars {
 ///
 /// println!("{plot}");
 /// ```
+///
+/// With many narrow buckets, horizontal bars are easier to read:
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let values = (0..1000).map(|x| (x as f64 / 100.0).sin()).collect::<Vec<_>>();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..100.0))
+///     .set_codomain(Domain(-1.0..1.0))
+///     .set_size(Size::new(50, 60))
+///     .add_plot(Box::new(
+///         plot::Histogram::new_with_buckets_count(values, 30).horizontal(),
+///     ));
+///
+/// println!("{plot}");
+/// ```
 pub struct Histogram {
     buckets: Vec<Bar>,
+    value_labels: bool,
+    style: BarStyle,
+    horizontal: bool,
 }
 
 impl Histogram {
@@ -172,15 +513,55 @@ impl Histogram {
     /// For each given value, the value will increment the count of the bucket in which it resides
     /// inside.
     pub fn new(values: Vec<f64>, buckets_range: Vec<ops::Range<f64>>) -> Self {
+        let values = values.into_iter().map(|value| (value, 1.0)).collect();
+        Self::weighted(values, buckets_range)
+    }
+
+    /// Create an histogram from `(value, weight)` samples and buckets in which the samples will
+    /// be sorted.
+    ///
+    /// The weight of each sample is added to the bucket in which its value resides, instead of
+    /// counting 1 like [`Histogram::new`]. This is useful for pre-aggregated data, where each
+    /// sample stands for several values, or for importance-weighted samples.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    ///
+    /// // The number of requests per latency, already counted by the server.
+    /// let latencies = vec![(5.0, 120.0), (15.0, 300.0), (25.0, 80.0), (45.0, 12.0)];
+    ///
+    /// let mut plot = Plot::default();
+    /// plot.set_domain(Domain(0.0..50.0))
+    ///     .set_codomain(Domain(0.0..350.0))
+    ///     .set_size(Size::new(50, 25))
+    ///     .add_plot(Box::new(plot::Histogram::weighted(
+    ///         latencies,
+    ///         vec![0.0..10.0, 10.0..20.0, 20.0..30.0, 30.0..40.0, 40.0..50.0],
+    ///     )));
+    ///
+    /// println!("{plot}");
+    /// ```
+    pub fn weighted(values: Vec<(f64, f64)>, buckets_range: Vec<ops::Range<f64>>) -> Self {
         let buckets = buckets_range
             .into_iter()
             .map(|range| Bar {
                 x: range.start,
                 width: range.end - range.start,
-                height: values.iter().filter(|v| range.contains(v)).count() as f64,
+                height: values
+                    .iter()
+                    .filter(|(value, _)| range.contains(value))
+                    .map(|(_, weight)| weight)
+                    .sum(),
             })
             .collect::<Vec<_>>();
-        Self { buckets }
+        Self {
+            buckets,
+            value_labels: false,
+            style: BarStyle::default(),
+            horizontal: false,
+        }
     }
 
     /// Create an histogram from data and a number of buckets.
@@ -195,17 +576,191 @@ impl Histogram {
         let min = values.iter().copied().fold(f64::INFINITY, f64::min);
         let width = (max - min) / count as f64;
         let buckets = (0..count)
-            .into_iter()
             .map(|idx| (min + width * idx as f64)..(min + width * (idx as f64 + 1.0)))
             .collect::<Vec<ops::Range<f64>>>();
         Self::new(values, buckets)
     }
+
+    /// Write the count of each bucket above its bar (or inside of it when there is no room
+    /// above).
+    pub fn with_value_labels(mut self) -> Self {
+        self.value_labels = true;
+        self
+    }
+
+    /// Set how the bars are drawn. By default, only their outline is drawn.
+    pub fn with_style(mut self, style: BarStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Draw the buckets as horizontal bars: the ranges of the buckets are on the y axis and the
+    /// bars extend along the x axis, from `x = 0`. The domain then holds the counts and the
+    /// codomain the values.
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
 }
 
 impl DrawView for Histogram {
     fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        if self.horizontal {
+            self.buckets
+                .iter()
+                .for_each(|bucket| bucket.draw_horizontal(canvas, self.style));
+            if self.value_labels {
+                self.buckets
+                    .iter()
+                    .for_each(|bucket| bucket.draw_horizontal_value_label(view, canvas));
+            }
+            return;
+        }
         self.buckets
             .iter()
-            .for_each(|bucket| bucket.draw(view, canvas));
+            .for_each(|bucket| bucket.draw_styled(view, canvas, self.style));
+        if self.value_labels {
+            self.buckets
+                .iter()
+                .for_each(|bucket| bucket.draw_value_label(view, canvas));
+        }
+    }
+}
+
+/// The color of a line at `(x, y)`, see [`Graph::with_gradient`].
+type Gradient = Box<dyn Fn(f64, f64) -> Color>;
+
+/// Join the `points` with lines `width` pixels thick, each colored by `gradient` at its middle
+/// when there is one.
+fn segments(
+    canvas: &mut ViewCanvas,
+    points: &[(f64, f64)],
+    gradient: Option<&Gradient>,
+    width: f64,
+) {
+    for line in points.windows(2) {
+        let ((x0, y0), (x1, y1)) = (line[0], line[1]);
+        if let Some(gradient) = gradient {
+            canvas.set_color(Some(gradient((x0 + x1) / 2.0, (y0 + y1) / 2.0)));
+        }
+        canvas.line_with_width(x0, y0, x1, y1, width);
+    }
+}
+
+/// Draw a dotted line, with one dot every few pixels.
+fn dotted_line(view: &View, canvas: &mut ViewCanvas, from: (f64, f64), to: (f64, f64)) {
+    let width = (to.0 - from.0).abs() / view.domain.range() * view.size.w as f64;
+    let height = (to.1 - from.1).abs() / view.codomain.range() * view.size.h as f64;
+    let dots = (width.max(height) / 3.0).round().max(1.0) as usize;
+    for index in 0..=dots {
+        let t = index as f64 / dots as f64;
+        canvas.point(from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t);
+    }
+}
+
+/// Shade the band between the `(xs, lower)` and `(xs, upper)` curves, column by column, with
+/// `level` (see [`ViewCanvas::shade`]). `xs` must be sorted.
+fn shade_between(
+    view: &View,
+    canvas: &mut ViewCanvas,
+    xs: &[f64],
+    lower: &[f64],
+    upper: &[f64],
+    level: f64,
+) {
+    let (Some(&first), Some(&last)) = (xs.first(), xs.last()) else {
+        return;
+    };
+    let pixel_width = view.domain.range() / view.size.w as f64;
+    for column in 0..view.size.w {
+        let x = view.domain.min() + (column as f64 + 0.5) * pixel_width;
+        if x < first || x > last {
+            continue;
+        }
+        let (y0, y1) = (interpolate(xs, lower, x), interpolate(xs, upper, x));
+        canvas.shade_rect(x, y0, x, y1, level);
+    }
+}
+
+/// Draw a small square marker centered on (`x`, `y`).
+fn marker(view: &View, canvas: &mut ViewCanvas, x: f64, y: f64) {
+    let dx = 2.0 * view.domain.range() / view.size.w as f64;
+    let dy = 2.0 * view.codomain.range() / view.size.h as f64;
+    canvas.rect(x - dx, y - dy, x + dx, y + dy);
+}
+
+/// Advance `point` by a step `h` along the solution of `point' = derivative(point)`, with the
+/// classic [Runge-Kutta method](https://en.wikipedia.org/wiki/Runge%E2%80%93Kutta_methods).
+fn rk4(derivative: impl Fn((f64, f64)) -> (f64, f64), point: (f64, f64), h: f64) -> (f64, f64) {
+    let at = |(dx, dy): (f64, f64), scale: f64| (point.0 + dx * scale, point.1 + dy * scale);
+    let k1 = derivative(point);
+    let k2 = derivative(at(k1, h / 2.0));
+    let k3 = derivative(at(k2, h / 2.0));
+    let k4 = derivative(at(k3, h));
+    (
+        point.0 + h / 6.0 * (k1.0 + 2.0 * k2.0 + 2.0 * k3.0 + k4.0),
+        point.1 + h / 6.0 * (k1.1 + 2.0 * k2.1 + 2.0 * k3.1 + k4.1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AxisMode, Decorations, Domain, Plot, Size};
+
+    /// The rows of `plot` drawn alone, without the axis or decorations, on a view of 20 by 16
+    /// pixels.
+    fn render(plot: impl DrawView + 'static, domain: f64, codomain: f64) -> String {
+        let mut figure = Plot::default();
+        figure
+            .set_domain(Domain(0.0..domain))
+            .set_codomain(Domain(0.0..codomain))
+            .set_size(Size::new(20, 16))
+            .set_axis_mode(AxisMode::None)
+            .set_decorations(Decorations::NONE)
+            .add_plot(Box::new(plot));
+        figure.try_render().unwrap()
+    }
+
+    #[test]
+    fn value_labels() {
+        // The label goes above the bar, or inside of it when there is no room above.
+        let bars = Bars::new(vec![2.0, 10.0]).with_value_labels();
+        assert_eq!(
+            render(bars, 2.0, 10.0),
+            "     ⡏⠉⠉⠉⢹\n     ⡇10 ⢸\n  2  ⡇   ⢸\n⡖⠒⠒⠒⠒⡇   ⢸\n"
+        );
+        // The label goes after the horizontal bar, or inside of it when there is no room after.
+        let histogram = Histogram::new(vec![0.5, 1.5, 1.6], vec![0.0..1.0, 1.0..2.0])
+            .horizontal()
+            .with_value_labels();
+        assert_eq!(
+            render(histogram, 4.0, 2.0),
+            "⠉⠉⠉⠉⠉⡇    \n     ⡇2   \n⠉⠉⢹⠉⠉⠁    \n⣀⣀⣸1      \n"
+        );
+    }
+
+    #[test]
+    fn positions() {
+        // The bars are 2 units wide, as the distance between them, and 1 unit is left empty.
+        let bars = Bars::with_positions(vec![(1.0, 5.0), (3.0, 10.0)]).with_gap(1.0);
+        assert_eq!(
+            render(bars, 4.0, 10.0),
+            "      ⢸⠉⠉⡇\n      ⢸  ⡇\n ⢸⠉⠉⡇ ⢸  ⡇\n ⢸  ⡇ ⢸  ⡇\n"
+        );
+        let bars = Bars::with_positions(vec![(1.0, 5.0), (3.0, 10.0)]).with_bar_width(0.5);
+        assert_eq!(
+            render(bars, 4.0, 10.0),
+            "       ⡏⡇ \n       ⡇⡇ \n  ⡏⡇   ⡇⡇ \n  ⡇⡇   ⡇⡇ \n"
+        );
+    }
+
+    #[test]
+    fn filled_bars() {
+        let bars = Bars::new(vec![2.0, 10.0]).with_style(BarStyle::Filled);
+        assert_eq!(
+            render(bars, 2.0, 10.0),
+            "     ⣿⣿⣿⣿⣿\n     ⣿⣿⣿⣿⣿\n     ⣿⣿⣿⣿⣿\n⡶⠶⠶⠶⠶⡿⠿⠿⠿⢿\n"
+        );
     }
 }
@@ -0,0 +1,106 @@
+use crate::{DrawView, View, ViewCanvas};
+
+/// A [bullet chart](https://en.wikipedia.org/wiki/Bullet_graph): a measure drawn as a bar along
+/// the x axis, against qualitative ranges shaded in the background and a target drawn as a tick.
+///
+/// The bar, the ranges and the target all start at `x = 0`. The chart takes the whole height of
+/// the view: the ranges fill it, the bar takes its middle third and the target its middle two
+/// thirds. Hiding the axis (see [`AxisMode::None`](crate::AxisMode::None)) and using a small height
+/// gives a compact chart for dashboards.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let bullet = plot::Bullet::new(270.0, 250.0).with_ranges(vec![150.0, 225.0, 300.0]);
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..300.0))
+///     .set_codomain(Domain(0.0..1.0))
+///     .set_axis_mode(AxisMode::None)
+///     .set_title("Revenue (k$)")
+///     .set_size(Size::new(80, 12))
+///     .add_plot(Box::new(bullet));
+///
+/// println!("{plot}");
+/// ```
+pub struct Bullet {
+    measure: f64,
+    target: f64,
+    ranges: Vec<f64>,
+}
+
+impl Bullet {
+    /// Create a new bullet chart of `measure` against `target`.
+    pub fn new(measure: f64, target: f64) -> Self {
+        Self {
+            measure,
+            target,
+            ranges: Vec::new(),
+        }
+    }
+
+    /// Set the end of each qualitative range (for example poor, satisfactory and good), in
+    /// increasing order. The first range starts at `x = 0` and each following range starts at the
+    /// end of the previous one. The ranges are shaded from the darkest to the lightest. By
+    /// default, there are no ranges.
+    pub fn with_ranges(mut self, ranges: Vec<f64>) -> Self {
+        self.ranges = ranges;
+        self
+    }
+}
+
+impl DrawView for Bullet {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let (bottom, top) = (view.codomain.min(), view.codomain.max());
+        let height = top - bottom;
+        let count = self.ranges.len();
+        let mut start = 0.0;
+        for (index, &end) in self.ranges.iter().enumerate() {
+            let level = 0.5 * (count - index) as f64 / (count + 1) as f64;
+            canvas.shade_rect(start, bottom, end, top, level);
+            start = end;
+        }
+
+        let (bar_bottom, bar_top) = (bottom + height / 3.0, top - height / 3.0);
+        canvas.fill_rect(0.0, bar_bottom, self.measure, bar_top);
+        canvas.line(0.0, bar_bottom, self.measure, bar_bottom);
+        canvas.line(self.measure, bar_bottom, self.measure, bar_top);
+        canvas.line(self.measure, bar_top, 0.0, bar_top);
+
+        // A tick two pixels wide.
+        let dx = view.domain.range() / view.size.w as f64;
+        for x in [self.target - dx / 2.0, self.target + dx / 2.0] {
+            canvas.line(x, bottom + height / 6.0, x, top - height / 6.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AxisMode, Decorations, Domain, Plot, Size};
+
+    #[test]
+    fn measure_target_and_ranges() {
+        let render = |bullet| {
+            let mut plot = Plot::default();
+            plot.set_domain(Domain(0.0..10.0))
+                .set_codomain(Domain(0.0..3.0))
+                .set_size(Size::new(20, 12))
+                .set_axis_mode(AxisMode::None)
+                .set_decorations(Decorations::NONE)
+                .add_plot(Box::new(bullet));
+            plot.try_render().unwrap()
+        };
+        // The measure is a bar in the middle third, the target a tick across it.
+        assert_eq!(
+            render(Bullet::new(6.0, 8.0)),
+            "        ⣤ \n⣿⣿⣿⣿⣿⣿⡇ ⣿ \n⠉⠉⠉⠉⠉⠉⠁ ⠿ \n"
+        );
+        // The first range is shaded darker than the second one.
+        let bullet = Bullet::new(6.0, 8.0).with_ranges(vec![4.0, 10.0]);
+        assert_eq!(render(bullet), "⠕⠕⠕⠕⠁⠁⠁⠁⣥⠁\n⣿⣿⣿⣿⣿⣿⡇⠁⣿⠁\n⠝⠝⠝⠝⠉⠉⠁⠁⠿⠁\n");
+    }
+}
@@ -0,0 +1,181 @@
+use super::segments;
+use crate::{DrawView, View, ViewCanvas};
+
+/// The derivative of a function, computed numerically and graphed like [`Graph`].
+///
+/// The derivative is estimated with a central difference at every pixel of the width of the view,
+/// with a step much smaller than a pixel. Add the [`Graph`] of the function next to it to compare
+/// them.
+///
+/// [`Graph`]: crate::plot::Graph
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let f = |x: f64| x.sin() * x;
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-10.0..10.0))
+///     .set_codomain(Domain(-10.0..10.0))
+///     .set_size(Size::new(60, 30))
+///     .add_plot(Box::new(plot::Graph::new(f)))
+///     .add_plot(Box::new(plot::Derivative::of(f)));
+///
+/// println!("{plot}");
+/// ```
+pub struct Derivative<F>
+where
+    F: Fn(f64) -> f64,
+{
+    function: F,
+}
+
+impl<F> Derivative<F>
+where
+    F: Fn(f64) -> f64,
+{
+    /// Graph the derivative of `function`.
+    pub fn of(function: F) -> Self {
+        Self { function }
+    }
+}
+
+impl<F> DrawView for Derivative<F>
+where
+    F: Fn(f64) -> f64,
+{
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        segments(canvas, &self.points(view), None, 1.0);
+    }
+
+    /// The derivative at every pixel of the width of the view, where it is finite.
+    fn points(&self, view: &View) -> Vec<(f64, f64)> {
+        let step = view.domain.range() * 1e-6;
+        view.domain
+            .iter(view.size.w)
+            .map(|x| {
+                let y = ((self.function)(x + step) - (self.function)(x - step)) / (2.0 * step);
+                (x, y)
+            })
+            .filter(|(_, y)| y.is_finite())
+            .collect()
+    }
+}
+
+/// The integral of a function, computed numerically and graphed like [`Graph`].
+///
+/// The integral is accumulated from the start of the domain, or from the origin set with
+/// [`Integral::with_origin`], with [Simpson's rule](https://en.wikipedia.org/wiki/Simpson%27s_rule)
+/// between every pixel of the width of the view. The function is expected to be finite: the
+/// pixels where it is not add nothing to the integral.
+///
+/// [`Graph`]: crate::plot::Graph
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let f = |x: f64| (-x * x / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-4.0..4.0))
+///     .set_codomain(Domain(0.0..1.1))
+///     .set_size(Size::new(60, 30))
+///     .add_plot(Box::new(plot::Graph::new(f)))
+///     .add_plot(Box::new(plot::Integral::of(f)));
+///
+/// println!("{plot}");
+/// ```
+pub struct Integral<F>
+where
+    F: Fn(f64) -> f64,
+{
+    function: F,
+    origin: Option<f64>,
+}
+
+impl<F> Integral<F>
+where
+    F: Fn(f64) -> f64,
+{
+    /// Graph the integral of `function` from the start of the domain.
+    pub fn of(function: F) -> Self {
+        Self {
+            function,
+            origin: None,
+        }
+    }
+
+    /// Integrate from `origin` instead of the start of the domain: the graph is `0` at `origin`,
+    /// and does not move when the domain changes.
+    pub fn with_origin(mut self, origin: f64) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// The integral of the function from `a` to `b`, with Simpson's rule over `steps` intervals.
+    fn integrate(&self, a: f64, b: f64, steps: usize) -> f64 {
+        let width = (b - a) / steps as f64;
+        (0..steps)
+            .map(|step| {
+                let x0 = a + step as f64 * width;
+                let (x1, x2) = (x0 + width / 2.0, x0 + width);
+                let f = &self.function;
+                width / 6.0 * (f(x0) + 4.0 * f(x1) + f(x2))
+            })
+            .filter(|area| area.is_finite())
+            .sum()
+    }
+}
+
+impl<F> DrawView for Integral<F>
+where
+    F: Fn(f64) -> f64,
+{
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        segments(canvas, &self.points(view), None, 1.0);
+    }
+
+    /// The integral at every pixel of the width of the view.
+    fn points(&self, view: &View) -> Vec<(f64, f64)> {
+        let start = view.domain.min();
+        let mut integral = match self.origin {
+            Some(origin) => self.integrate(origin, start, view.size.w.max(1)),
+            None => 0.0,
+        };
+        let mut previous = start;
+        view.domain
+            .iter(view.size.w)
+            .map(|x| {
+                integral += self.integrate(previous, x, 1);
+                previous = x;
+                (x, integral)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Domain, Plot, Size};
+
+    #[test]
+    fn derivative_and_integral() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(-2.0..2.0)).set_size(Size::new(8, 4));
+        let close = |points: Vec<(f64, f64)>, expected: fn(f64) -> f64| {
+            assert_eq!(points.len(), 9);
+            points.iter().all(|&(x, y)| (y - expected(x)).abs() < 1e-6)
+        };
+        let derivative = Derivative::of(|x| x * x * x);
+        assert!(close(derivative.points(&plot.view), |x| 3.0 * x * x));
+        let integral = Integral::of(|x| 2.0 * x);
+        assert!(close(integral.points(&plot.view), |x| x * x - 4.0));
+        let integral = integral.with_origin(1.0);
+        assert!(close(integral.points(&plot.view), |x| x * x - 1.0));
+    }
+}
@@ -0,0 +1,88 @@
+use super::Heatmap;
+use crate::{DrawView, View, ViewCanvas};
+
+/// A calendar heatmap of daily values, like the contributions graph of GitHub.
+///
+/// Each column is a week and each row is a day of the week, Monday at the top and Sunday at the
+/// bottom. Each day is a cell 1 unit wide and 1 unit tall shaded according to its value, so a
+/// domain from `0` to the number of weeks and a codomain from `0` to `7` show the whole
+/// calendar. Use a size of 2 by 4 pixels per cell to draw each day as a single character.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// // 20 weeks of commits, starting on a Wednesday.
+/// let commits = (0..140).map(|day| ((day * 7) % 11) as f64).collect();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..21.0))
+///     .set_codomain(Domain(0.0..7.0))
+///     .set_title("Commits")
+///     .set_size(Size::new(42, 28))
+///     .add_plot(Box::new(
+///         plot::CalendarHeatmap::new(commits).with_first_weekday(2),
+///     ));
+///
+/// println!("{plot}");
+/// ```
+pub struct CalendarHeatmap {
+    values: Vec<f64>,
+    first_weekday: usize,
+}
+
+impl CalendarHeatmap {
+    /// Create a new calendar heatmap from consecutive daily values. The first value is a Monday,
+    /// see [`CalendarHeatmap::with_first_weekday`] to start on another day.
+    pub fn new(values: Vec<f64>) -> Self {
+        Self {
+            values,
+            first_weekday: 0,
+        }
+    }
+
+    /// Set the day of the week of the first value, from `0` (Monday) to `6` (Sunday).
+    pub fn with_first_weekday(mut self, weekday: usize) -> Self {
+        self.first_weekday = weekday % 7;
+        self
+    }
+
+    /// The number of weeks (columns) of the calendar.
+    pub fn weeks(&self) -> usize {
+        (self.first_weekday + self.values.len()).div_ceil(7)
+    }
+
+    /// The grid of the calendar, with Sunday as the first row. Days before the first value and
+    /// after the last value are missing.
+    fn grid(&self) -> Vec<Vec<f64>> {
+        let mut grid = vec![vec![f64::NAN; self.weeks()]; 7];
+        for (index, value) in self.values.iter().enumerate() {
+            let day = self.first_weekday + index;
+            grid[6 - day % 7][day / 7] = *value;
+        }
+        grid
+    }
+}
+
+impl DrawView for CalendarHeatmap {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        Heatmap::new(self.grid()).draw(view, canvas);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid() {
+        let calendar =
+            CalendarHeatmap::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).with_first_weekday(5);
+        assert_eq!(calendar.weeks(), 2);
+        let grid = calendar.grid();
+        // Saturday and Sunday of the first week, Monday of the second week.
+        assert_eq!((grid[1][0], grid[0][0], grid[6][1]), (1.0, 2.0, 3.0));
+        assert!(grid[6][0].is_nan());
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{Align, DrawView, View, ViewCanvas};
+
+/// A [confusion matrix](https://en.wikipedia.org/wiki/Confusion_matrix) of a classifier.
+///
+/// Each row of the matrix is an actual class and each column is a predicted class. Every cell
+/// shows its count and its percentage of all the samples, and is shaded according to its count.
+/// The totals of each row and column are shown in an extra row and column.
+///
+/// The matrix fills the whole view, independently of the domain and codomain. Use a domain and
+/// codomain starting at zero so that the axis are drawn on the edges of the view.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let matrix = plot::ConfusionMatrix::new(
+///     vec![vec![50, 3, 2], vec![5, 40, 5], vec![1, 4, 45]],
+///     vec!["cat", "dog", "bird"],
+/// );
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..1.0))
+///     .set_codomain(Domain(0.0..1.0))
+///     .set_title("Confusion matrix")
+///     .set_x_label("Predicted class")
+///     .set_y_label("Actual class")
+///     .set_size(Size::new(100, 60))
+///     .add_plot(Box::new(matrix));
+///
+/// println!("{plot}");
+/// ```
+pub struct ConfusionMatrix {
+    matrix: Vec<Vec<usize>>,
+    class_labels: Vec<String>,
+}
+
+impl ConfusionMatrix {
+    /// Create a new confusion matrix.
+    ///
+    /// `matrix[actual][predicted]` is the number of samples of the class `actual` that were
+    /// classified as `predicted`. `class_labels` are the names of the classes, in the same order
+    /// as the rows and columns of the matrix.
+    pub fn new(matrix: Vec<Vec<usize>>, class_labels: Vec<&str>) -> Self {
+        Self {
+            matrix,
+            class_labels: class_labels.into_iter().map(String::from).collect(),
+        }
+    }
+
+    fn class_count(&self) -> usize {
+        self.matrix
+            .iter()
+            .map(|row| row.len())
+            .chain(std::iter::once(self.matrix.len()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn count(&self, actual: usize, predicted: usize) -> usize {
+        self.matrix
+            .get(actual)
+            .and_then(|row| row.get(predicted))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn label(&self, class: usize) -> String {
+        match self.class_labels.get(class) {
+            Some(label) => label.clone(),
+            None => class.to_string(),
+        }
+    }
+}
+
+impl DrawView for ConfusionMatrix {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let classes = self.class_count();
+        if classes == 0 {
+            return;
+        }
+        // The grid has a column for the labels, one per class and one for the totals. Same for
+        // the rows.
+        let grid = Grid::new(view, classes + 2);
+        let total = self.matrix.iter().flatten().sum::<usize>().max(1);
+        let max = self
+            .matrix
+            .iter()
+            .flatten()
+            .copied()
+            .max()
+            .unwrap_or(0)
+            .max(1);
+
+        for actual in 0..classes {
+            for predicted in 0..classes {
+                let count = self.count(actual, predicted);
+                let (x0, y0, x1, y1) = grid.cell(predicted + 1, actual + 1);
+                canvas.shade_rect(x0, y0, x1, y1, count as f64 / max as f64);
+            }
+        }
+
+        let (x0, y0, _, _) = grid.cell(1, 1);
+        let (_, _, x1, y1) = grid.cell(classes + 1, classes + 1);
+        for index in 0..=classes + 1 {
+            let (x, y, _, _) = grid.cell(index + 1, index + 1);
+            canvas.line(x, y0, x, y1);
+            canvas.line(x0, y, x1, y);
+        }
+
+        for class in 0..classes {
+            let label = self.label(class);
+            grid.text(canvas, class + 1, 0, &[&label]);
+            grid.text(canvas, 0, class + 1, &[&label]);
+
+            let row_total = (0..classes).map(|p| self.count(class, p)).sum::<usize>();
+            let column_total = (0..classes).map(|a| self.count(a, class)).sum::<usize>();
+            grid.count(canvas, classes + 1, class + 1, row_total, total);
+            grid.count(canvas, class + 1, classes + 1, column_total, total);
+            for predicted in 0..classes {
+                let count = self.count(class, predicted);
+                grid.count(canvas, predicted + 1, class + 1, count, total);
+            }
+        }
+        grid.text(canvas, classes + 1, 0, &["Total"]);
+        grid.text(canvas, 0, classes + 1, &["Total"]);
+        grid.count(canvas, classes + 1, classes + 1, total, total);
+    }
+}
+
+/// A grid of cells filling the whole view.
+struct Grid {
+    x: f64,
+    y: f64,
+    cell_width: f64,
+    cell_height: f64,
+    /// The width of a cell, in characters.
+    cell_chars: usize,
+    /// The height of a cell, in rows of characters.
+    cell_rows: usize,
+    /// The height of a row of characters.
+    row_height: f64,
+}
+
+impl Grid {
+    fn new(view: &View, cells: usize) -> Self {
+        let cell_width = view.domain.range() / cells as f64;
+        let cell_height = view.codomain.range() / cells as f64;
+        Self {
+            x: view.domain.min(),
+            y: view.codomain.max(),
+            cell_width,
+            cell_height,
+            cell_chars: view.size.w / 2 / cells,
+            cell_rows: view.size.h / 4 / cells,
+            row_height: 4.0 * view.codomain.range() / view.size.h as f64,
+        }
+    }
+
+    /// The corners of a cell, from the top left to the bottom right.
+    fn cell(&self, column: usize, row: usize) -> (f64, f64, f64, f64) {
+        let x = self.x + column as f64 * self.cell_width;
+        let y = self.y - row as f64 * self.cell_height;
+        (x, y, x + self.cell_width, y - self.cell_height)
+    }
+
+    /// Write the lines of text centered inside of a cell. Lines that do not fit are skipped and
+    /// lines that are too wide are truncated.
+    fn text(&self, canvas: &mut ViewCanvas, column: usize, row: usize, lines: &[&str]) {
+        let (x0, y0, x1, y1) = self.cell(column, row);
+        let lines = &lines[..lines.len().min(self.cell_rows.max(1))];
+        let offset = (lines.len() as f64 - 1.0) / 2.0;
+        for (index, line) in lines.iter().enumerate() {
+            let line = line
+                .chars()
+                .take(self.cell_chars.saturating_sub(1).max(1))
+                .collect::<String>();
+            let y = (y0 + y1) / 2.0 + (offset - index as f64) * self.row_height;
+            canvas.text((x0 + x1) / 2.0, y, &line, Align::Center);
+        }
+    }
+
+    /// Write the count and the percentage of the total inside of a cell.
+    fn count(
+        &self,
+        canvas: &mut ViewCanvas,
+        column: usize,
+        row: usize,
+        count: usize,
+        total: usize,
+    ) {
+        let count_text = count.to_string();
+        let percent_text = format!("{:.1}%", count as f64 / total as f64 * 100.0);
+        self.text(canvas, column, row, &[&count_text, &percent_text]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AxisMode, Decorations, Domain, Plot, Size};
+
+    #[test]
+    fn counts_and_totals() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..1.0))
+            .set_codomain(Domain(0.0..1.0))
+            .set_size(Size::new(64, 32))
+            .set_axis_mode(AxisMode::None)
+            .set_decorations(Decorations::NONE)
+            .add_plot(Box::new(ConfusionMatrix::new(
+                vec![vec![3, 1], vec![0, 4]],
+                vec!["a", "b"],
+            )));
+        // The text of each line, without the shading and the grid.
+        let words = plot
+            .try_render()
+            .unwrap()
+            .lines()
+            .map(|line| {
+                line.replace(|c| ('⠀'..='⣿').contains(&c), " ")
+                    .split_whitespace()
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|words| !words.is_empty())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            words,
+            [
+                vec!["a", "b", "Total"],
+                vec!["3", "1", "4"],
+                vec!["a", "37.5%", "12.5%", "50.0%"],
+                vec!["0", "4", "4"],
+                vec!["b", "0.0%", "50.0%", "50.0%"],
+                vec!["3", "5", "8"],
+                vec!["Total", "37.5%", "62.5%", "100.0%"],
+            ]
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{Align, Domain, DrawView, View, ViewCanvas};
+
+/// The kind of a point found by [`CriticalPoints`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Critical {
+    /// The function is zero: it changes sign.
+    Root,
+    /// The function is smaller than around it.
+    Minimum,
+    /// The function is larger than around it.
+    Maximum,
+}
+
+/// The roots and the local extrema of a function, marked on its curve with their coordinates.
+///
+/// The function is sampled at every pixel of the width of the view: a root is found where two
+/// samples have different signs, and an extremum where a sample is larger (or smaller) than both
+/// of its neighbours. Each one is then refined between the samples, so the marks are precise, but
+/// the roots and extrema closer than a pixel from each other may be missed. Sign changes across a
+/// discontinuity (like the poles of `tan`) are not roots and are ignored, and neither are the
+/// jumps of the function around them.
+///
+/// Add the [`Graph`] of the function with it to see the curve.
+///
+/// [`Graph`]: crate::plot::Graph
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let f = |x: f64| x.powi(3) - 3.0 * x;
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-2.5..2.5))
+///     .set_codomain(Domain(-4.0..4.0))
+///     .set_size(Size::new(80, 30))
+///     .add_plot(Box::new(plot::Graph::new(f)))
+///     .add_plot(Box::new(plot::CriticalPoints::of(f)));
+///
+/// println!("{plot}");
+/// ```
+pub struct CriticalPoints<F>
+where
+    F: Fn(f64) -> f64,
+{
+    function: F,
+}
+
+impl<F> CriticalPoints<F>
+where
+    F: Fn(f64) -> f64,
+{
+    /// Find the roots and the extrema of `function`.
+    pub fn of(function: F) -> Self {
+        Self { function }
+    }
+
+    /// The roots and the extrema of the function over `domain` sampled `steps` times, from the
+    /// start of the domain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use termplot::*;
+    /// use termplot::plot::Critical;
+    ///
+    /// let points = plot::CriticalPoints::of(|x| x * x - 1.0).find(&Domain(-2.0..2.0), 100);
+    /// let kinds = points.iter().map(|&(kind, _)| kind).collect::<Vec<_>>();
+    /// assert_eq!(kinds, [Critical::Root, Critical::Minimum, Critical::Root]);
+    /// ```
+    pub fn find(&self, domain: &Domain, steps: usize) -> Vec<(Critical, (f64, f64))> {
+        let f = &self.function;
+        let samples = domain.iter(steps).map(|x| (x, f(x))).collect::<Vec<_>>();
+        let mut found = Vec::new();
+        for (index, pair) in samples.windows(2).enumerate() {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            if y0 == 0.0 {
+                found.push((Critical::Root, (x0, 0.0)));
+            } else if y0 * y1 < 0.0 {
+                // Both samples are finite, and the root is bracketed between them.
+                let x = self.bisect(x0, x1);
+                if f(x).abs() <= y0.abs().min(y1.abs()) {
+                    found.push((Critical::Root, (x, 0.0)));
+                }
+            }
+            if let Some(&(x2, y2)) = samples.get(index + 2) {
+                let kind = match (y0 < y1, y1 < y2) {
+                    (true, false) if y1 > y2 => Critical::Maximum,
+                    (false, true) if y0 > y1 => Critical::Minimum,
+                    _ => continue,
+                };
+                let x = self.extremum(x0, x2, kind);
+                // Around a discontinuity, the search goes to the discontinuity instead.
+                if (f(x) - y1).abs() <= (y1 - y0).abs() + (y1 - y2).abs() {
+                    found.push((kind, (x, f(x))));
+                }
+            }
+        }
+        if let Some(&(x, y)) = samples.last().filter(|(_, y)| *y == 0.0) {
+            found.push((Critical::Root, (x, y)));
+        }
+        found
+    }
+
+    /// The point where the function changes sign between `a` and `b`, found by bisection.
+    fn bisect(&self, mut a: f64, mut b: f64) -> f64 {
+        let sign = (self.function)(a).signum();
+        for _ in 0..64 {
+            let middle = (a + b) / 2.0;
+            match (self.function)(middle).signum() == sign {
+                true => a = middle,
+                false => b = middle,
+            }
+        }
+        (a + b) / 2.0
+    }
+
+    /// The point where the function reaches its `kind` of extremum between `a` and `b`, found with
+    /// a [golden-section search](https://en.wikipedia.org/wiki/Golden-section_search).
+    fn extremum(&self, mut a: f64, mut b: f64, kind: Critical) -> f64 {
+        let value = |x| match kind {
+            Critical::Maximum => -(self.function)(x),
+            _ => (self.function)(x),
+        };
+        let ratio = (5f64.sqrt() - 1.0) / 2.0;
+        for _ in 0..64 {
+            let (c, d) = (b - ratio * (b - a), a + ratio * (b - a));
+            match value(c) < value(d) {
+                true => b = d,
+                false => a = c,
+            }
+        }
+        (a + b) / 2.0
+    }
+}
+
+/// A value rounded to 2 decimals for the labels.
+fn rounded(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0 + 0.0
+}
+
+impl<F> DrawView for CriticalPoints<F>
+where
+    F: Fn(f64) -> f64,
+{
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let pixel_width = view.domain.range() / view.size.w as f64;
+        let pixel_height = view.codomain.range() / view.size.h as f64;
+        for (kind, (x, y)) in self.find(&view.domain, view.size.w) {
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    canvas.point(x + dx as f64 * pixel_width, y + dy as f64 * pixel_height);
+                }
+            }
+            // The labels are written on the row above the mark, or below it for the minima.
+            let (label, row) = match kind {
+                Critical::Root => (format!("{}", rounded(x)), 1.0),
+                Critical::Minimum => (format!("min ({}, {})", rounded(x), rounded(y)), -1.0),
+                Critical::Maximum => (format!("max ({}, {})", rounded(x), rounded(y)), 1.0),
+            };
+            canvas.text(x, y + row * 4.0 * pixel_height, &label, Align::Center);
+        }
+    }
+
+    /// The roots and the extrema in the view.
+    fn points(&self, view: &View) -> Vec<(f64, f64)> {
+        self.find(&view.domain, view.size.w)
+            .into_iter()
+            .map(|(_, point)| point)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roots_and_extrema() {
+        let points = CriticalPoints::of(|x| x.powi(3) - 3.0 * x).find(&Domain(-2.5..2.5), 50);
+        let expected = [
+            (Critical::Root, -3f64.sqrt()),
+            (Critical::Maximum, -1.0),
+            (Critical::Root, 0.0),
+            (Critical::Minimum, 1.0),
+            (Critical::Root, 3f64.sqrt()),
+        ];
+        assert_eq!(points.len(), expected.len());
+        for ((kind, (x, _)), (expected_kind, expected_x)) in points.into_iter().zip(expected) {
+            assert_eq!(kind, expected_kind);
+            assert!((x - expected_x).abs() < 1e-6, "{x} != {expected_x}");
+        }
+        // The poles of `tan` are neither roots nor extrema.
+        let roots = CriticalPoints::of(f64::tan).find(&Domain(0.1..6.0), 100);
+        let roots = roots.iter().map(|&(_, (x, _))| x).collect::<Vec<_>>();
+        assert_eq!(roots.len(), 1);
+        assert!((roots[0] - std::f64::consts::PI).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::color::Colormap;
+use crate::{Domain, DrawView, View, ViewCanvas};
+use std::f64::consts::PI;
+
+/// The density of a cloud of points, estimated with a 2D
+/// [kernel density estimate](https://en.wikipedia.org/wiki/Kernel_density_estimation).
+///
+/// The density is computed for every character of the view and each character is shaded
+/// according to it, from empty (no density) to completely filled (the largest density in the
+/// view). Large clouds, where the points of a scatter plot would cover each other, show where the
+/// points are the most concentrated.
+///
+/// The kernel is a gaussian, as wide as the bandwidth on each axis. By default, the bandwidths
+/// follow [Scott's rule](https://en.wikipedia.org/wiki/Multivariate_kernel_density_estimation),
+/// see [`Density2D::with_bandwidth`] to change them.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let points = (0..2000)
+///     .map(|i| {
+///         let (angle, radius) = (i as f64 * 2.4, (i % 97) as f64 / 40.0);
+///         (radius * angle.cos() + 1.0, radius * angle.sin() * 0.6)
+///     })
+///     .collect();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-3.0..5.0))
+///     .set_codomain(Domain(-2.0..2.0))
+///     .set_size(Size::new(60, 30))
+///     .add_plot(Box::new(
+///         plot::Density2D::new(points).with_colormap(color::Colormap::Magma),
+///     ));
+///
+/// println!("{plot}");
+/// ```
+pub struct Density2D {
+    points: Vec<(f64, f64)>,
+    bandwidth: Option<(f64, f64)>,
+    colormap: Option<Colormap>,
+}
+
+impl Density2D {
+    /// Create a new density estimate of `points`. Points that are not finite are ignored.
+    pub fn new(mut points: Vec<(f64, f64)>) -> Self {
+        points.retain(|(x, y)| x.is_finite() && y.is_finite());
+        Self {
+            points,
+            bandwidth: None,
+            colormap: None,
+        }
+    }
+
+    /// Set the bandwidth of the kernel on the x axis and on the y axis, in the units of the
+    /// axis. Larger bandwidths give smoother densities.
+    pub fn with_bandwidth(mut self, x: f64, y: f64) -> Self {
+        self.bandwidth = Some((x, y));
+        self
+    }
+
+    /// Fill the characters with a density and color them with `colormap`, from the start of the
+    /// map for the smallest density to its end for the largest one, instead of shading them. A
+    /// colorbar on the right of the view shows the density relative to the largest one.
+    pub fn with_colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = Some(colormap);
+        self
+    }
+
+    /// The bandwidths on the x and y axis: the ones that were set, or the ones of Scott's rule.
+    fn bandwidth(&self) -> (f64, f64) {
+        if let Some(bandwidth) = self.bandwidth {
+            return bandwidth;
+        }
+        let count = self.points.len() as f64;
+        let deviation = |value: fn(&(f64, f64)) -> f64| {
+            let mean = self.points.iter().map(value).sum::<f64>() / count;
+            let variance = self
+                .points
+                .iter()
+                .map(|point| (value(point) - mean).powi(2))
+                .sum::<f64>()
+                / count;
+            match variance.sqrt() {
+                deviation if deviation > 0.0 => deviation,
+                _ => 1.0,
+            }
+        };
+        let factor = count.powf(-1.0 / 6.0);
+        (
+            deviation(|point| point.0) * factor,
+            deviation(|point| point.1) * factor,
+        )
+    }
+
+    /// The estimated density at (`x`, `y`) with the `(bx, by)` bandwidths.
+    fn density(&self, x: f64, y: f64, (bx, by): (f64, f64)) -> f64 {
+        let sum = self
+            .points
+            .iter()
+            .map(|&(px, py)| ((x - px) / bx, (y - py) / by))
+            // Beyond 4 bandwidths, the kernel is negligible.
+            .filter(|(dx, dy)| dx.abs() < 4.0 && dy.abs() < 4.0)
+            .map(|(dx, dy)| (-0.5 * (dx * dx + dy * dy)).exp())
+            .sum::<f64>();
+        sum / (self.points.len() as f64 * 2.0 * PI * bx * by)
+    }
+}
+
+impl DrawView for Density2D {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        if self.points.is_empty() {
+            return;
+        }
+        let bandwidth = self.bandwidth();
+        let (columns, rows) = (view.size.w.div_ceil(2), view.size.h.div_ceil(4));
+        let cell_width = view.domain.range() / columns as f64;
+        let cell_height = view.codomain.range() / rows as f64;
+        let cells = (0..rows)
+            .flat_map(|row| (0..columns).map(move |column| (column, row)))
+            .map(|(column, row)| {
+                let x0 = view.domain.min() + column as f64 * cell_width;
+                let y0 = view.codomain.min() + row as f64 * cell_height;
+                let density =
+                    self.density(x0 + cell_width / 2.0, y0 + cell_height / 2.0, bandwidth);
+                (x0, y0, density)
+            })
+            .collect::<Vec<_>>();
+        let max = cells
+            .iter()
+            .map(|&(_, _, density)| density)
+            .fold(0.0, f64::max);
+        if max <= 0.0 {
+            return;
+        }
+        for (x0, y0, density) in cells {
+            let level = density / max;
+            let (x1, y1) = (x0 + cell_width, y0 + cell_height);
+            match self.colormap {
+                // The characters without a density are left empty.
+                Some(_) if level < 0.01 => {}
+                Some(colormap) => {
+                    canvas.set_color(Some(colormap.color(level)));
+                    canvas.fill_rect(x0, y0, x1, y1);
+                }
+                None => canvas.shade_rect(x0, y0, x1, y1, level),
+            }
+        }
+    }
+
+    fn colorbar(&self) -> Option<(Colormap, Domain)> {
+        Some((self.colormap?, Domain(0.0..1.0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scott_bandwidth() {
+        let density = Density2D::new(vec![(-1.0, 0.0), (1.0, 0.0), (f64::NAN, 0.0)]);
+        let (bx, by) = density.bandwidth();
+        assert!((bx - 2f64.powf(-1.0 / 6.0)).abs() < 1e-12);
+        assert!((by - 2f64.powf(-1.0 / 6.0)).abs() < 1e-12);
+        let at = |x| density.density(x, 0.0, (0.5, 0.5));
+        assert!(at(1.0) > at(0.0) && at(0.0) > at(3.0));
+    }
+}
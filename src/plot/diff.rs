@@ -0,0 +1,101 @@
+use super::stacked::interpolate;
+use crate::{Color, DrawView, View, ViewCanvas};
+
+/// The difference `a - b` between two series, drawn as an area filled from zero.
+///
+/// Where `a` is above `b`, the area is filled in green; where it is below, the area is filled
+/// more sparsely and in red, so both regions can be told apart without colors. Use
+/// [`Diff::with_colors`] for other colors.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let a = (0..100).map(|x| (x as f64, (x as f64 / 10.0).sin())).collect();
+/// let b = (0..100).map(|x| (x as f64, (x as f64 / 14.0).sin())).collect();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..99.0))
+///     .set_codomain(Domain(-2.0..2.0))
+///     .set_title("A - B")
+///     .set_size(Size::new(60, 25))
+///     .add_plot(Box::new(plot::Diff::new(a, b)));
+///
+/// println!("{plot}");
+/// ```
+pub struct Diff {
+    x: Vec<f64>,
+    difference: Vec<f64>,
+    colors: (Color, Color),
+}
+
+impl Diff {
+    /// Create the difference of the `(x, y)` points of `a` and `b`. The difference is computed
+    /// at the x values of `a` within the x values of `b`, where `b` is linearly interpolated.
+    /// The points do not need to be sorted.
+    pub fn new(mut a: Vec<(f64, f64)>, mut b: Vec<(f64, f64)>) -> Self {
+        a.sort_by(|a, b| a.0.total_cmp(&b.0));
+        b.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let (b_x, b_y): (Vec<_>, Vec<_>) = b.into_iter().unzip();
+        let (first, last) = match (b_x.first(), b_x.last()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => (f64::INFINITY, f64::NEG_INFINITY),
+        };
+        let (x, difference) = a
+            .into_iter()
+            .filter(|&(x, _)| x >= first && x <= last)
+            .map(|(x, y)| (x, y - interpolate(&b_x, &b_y, x)))
+            .unzip();
+        Self {
+            x,
+            difference,
+            colors: (Color::Green, Color::Red),
+        }
+    }
+
+    /// Fill the area where `a` is above `b` with `positive` and the area where it is below with
+    /// `negative`. By default, they are filled in green and red.
+    pub fn with_colors(mut self, positive: Color, negative: Color) -> Self {
+        self.colors = (positive, negative);
+        self
+    }
+}
+
+impl DrawView for Diff {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let (Some(&first), Some(&last)) = (self.x.first(), self.x.last()) else {
+            return;
+        };
+        let (positive, negative) = self.colors;
+        let pixel_width = view.domain.range() / view.size.w as f64;
+        for column in 0..view.size.w {
+            let x = view.domain.min() + (column as f64 + 0.5) * pixel_width;
+            if x < first || x > last {
+                continue;
+            }
+            let difference = interpolate(&self.x, &self.difference, x);
+            let (color, level) = match difference >= 0.0 {
+                true => (positive, 1.0),
+                false => (negative, 0.5),
+            };
+            canvas.set_color(Some(color));
+            canvas.shade_rect(x, 0.0, x, difference, level);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolated_difference() {
+        let diff = Diff::new(
+            vec![(2.0, 1.0), (0.0, 3.0), (1.0, 2.0), (5.0, 0.0)],
+            vec![(0.0, 1.0), (2.0, 3.0)],
+        );
+        assert_eq!(diff.x, vec![0.0, 1.0, 2.0]);
+        assert_eq!(diff.difference, vec![2.0, 0.0, -2.0]);
+    }
+}
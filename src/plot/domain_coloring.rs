@@ -0,0 +1,101 @@
+use crate::{Color, DrawView, View, ViewCanvas};
+use num_complex::Complex;
+use std::f64::consts::{FRAC_2_PI, TAU};
+
+/// A [domain coloring](https://en.wikipedia.org/wiki/Domain_coloring) of a complex function: each
+/// point `x + iy` of the view is drawn with the color of the argument of `f(x + iy)` and an
+/// intensity growing with its magnitude.
+///
+/// Each character of the view (2 by 4 pixels) is drawn with the value of the function at its
+/// center. The argument goes around the color wheel, from red for positive real numbers to cyan
+/// for negative ones. Zeros are empty and poles are completely filled, so that the function can
+/// still be read in a terminal without colors. Values that are not finite are not drawn.
+///
+/// Requires the `complex` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-2.0..2.0))
+///     .set_codomain(Domain(-2.0..2.0))
+///     .set_aspect(Aspect::Equal)
+///     .set_title("(z² - 1) / (z² + 1)")
+///     .set_size(Size::new(80, 40))
+///     .add_plot(Box::new(plot::DomainColoring::new(|z: Complex<f64>| {
+///         (z * z - 1.0) / (z * z + 1.0)
+///     })));
+///
+/// println!("{plot}");
+/// ```
+pub struct DomainColoring<F> {
+    function: F,
+}
+
+impl<F> DomainColoring<F>
+where
+    F: Fn(Complex<f64>) -> Complex<f64>,
+{
+    /// Create a new domain coloring of `function`.
+    pub fn new(function: F) -> Self {
+        Self { function }
+    }
+}
+
+impl<F> DrawView for DomainColoring<F>
+where
+    F: Fn(Complex<f64>) -> Complex<f64>,
+{
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let cell_width = 2.0 * view.domain.range() / view.size.w as f64;
+        let cell_height = 4.0 * view.codomain.range() / view.size.h as f64;
+        let columns = view.size.w.div_ceil(2);
+        let rows = view.size.h.div_ceil(4);
+        for row in 0..rows {
+            let y0 = view.codomain.min() + row as f64 * cell_height;
+            for column in 0..columns {
+                let x0 = view.domain.min() + column as f64 * cell_width;
+                let z = Complex::new(x0 + cell_width / 2.0, y0 + cell_height / 2.0);
+                let value = (self.function)(z);
+                if !value.is_finite() {
+                    continue;
+                }
+                let intensity = FRAC_2_PI * value.norm().atan();
+                canvas.set_color(Some(hue(value.arg() / TAU)));
+                canvas.shade_rect(x0, y0, x0 + cell_width, y0 + cell_height, intensity);
+            }
+        }
+        canvas.set_color(None);
+    }
+}
+
+/// The color of the color wheel at `turn` (a fraction of a turn, from red to red).
+fn hue(turn: f64) -> Color {
+    let h = turn.rem_euclid(1.0) * 6.0;
+    let x = 1.0 - (h % 2.0 - 1.0).abs();
+    let (r, g, b) = match h as u8 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+    let channel = |value: f64| (value * 255.0).round() as u8;
+    Color::Rgb(channel(r), channel(g), channel(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_wheel() {
+        assert_eq!(hue(0.0), Color::Rgb(255, 0, 0));
+        assert_eq!(hue(1.0 / 3.0), Color::Rgb(0, 255, 0));
+        assert_eq!(hue(-0.5), Color::Rgb(0, 255, 255));
+        assert_eq!(hue(0.75), Color::Rgb(128, 0, 255));
+    }
+}
@@ -0,0 +1,91 @@
+use super::{dotted_line, marker};
+use crate::{DrawView, View, ViewCanvas};
+
+/// A [Cleveland dot plot](https://en.wikipedia.org/wiki/Dot_plot_(statistics)): a marker for the
+/// value of each category, linked to the name of its category by a faint dotted line.
+///
+/// Like [`Bars::labeled`](super::Bars::labeled), the first category is centered on `x = 0.5`, the
+/// next one on `x = 1.5`, etc... and the names are written below the x axis instead of the values
+/// of the x axis. With less ink than bars, it stays readable with many categories.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let dots = plot::DotPlot::new(vec![
+///     ("mon".to_string(), 42.0),
+///     ("tue".to_string(), 57.0),
+///     ("wed".to_string(), 31.0),
+///     ("thu".to_string(), 64.0),
+///     ("fri".to_string(), 48.0),
+/// ]);
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..5.0))
+///     .set_codomain(Domain(0.0..70.0))
+///     .set_title("Commits per day")
+///     .set_size(Size::new(80, 30))
+///     .add_plot(Box::new(dots));
+///
+/// println!("{plot}");
+/// ```
+pub struct DotPlot {
+    dots: Vec<(String, f64)>,
+}
+
+impl DotPlot {
+    /// Create a new dot plot from the name of each category and its value.
+    pub fn new(dots: Vec<(String, f64)>) -> Self {
+        Self { dots }
+    }
+
+    fn positions(&self) -> impl Iterator<Item = (f64, &(String, f64))> {
+        self.dots
+            .iter()
+            .enumerate()
+            .map(|(index, dot)| (index as f64 + 0.5, dot))
+    }
+}
+
+impl DrawView for DotPlot {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        for (x, (_, value)) in self.positions() {
+            dotted_line(view, canvas, (x, view.codomain.min()), (x, *value));
+            marker(view, canvas, x, *value);
+        }
+    }
+
+    fn x_labels(&self) -> Vec<(f64, String)> {
+        self.positions()
+            .map(|(x, (name, _))| (x, name.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AxisMode, Decorations, Domain, Plot, Size};
+
+    #[test]
+    fn dots_and_names() {
+        let dots = DotPlot::new(vec![("a".to_string(), 1.0), ("b".to_string(), 3.0)]);
+        assert_eq!(
+            dots.x_labels(),
+            [(0.5, "a".to_string()), (1.5, "b".to_string())]
+        );
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..2.0))
+            .set_codomain(Domain(0.0..4.0))
+            .set_size(Size::new(20, 16))
+            .set_axis_mode(AxisMode::None)
+            .set_decorations(Decorations::NONE)
+            .add_plot(Box::new(dots));
+        // A marker on each value, linked to the bottom of the view by a dotted line.
+        assert_eq!(
+            plot.try_render().unwrap(),
+            "      ⢠⠤⢤ \n      ⠸⢬⠼ \n ⢠⠤⢤   ⠠  \n ⠸⢬⠼   ⢐  \n"
+        );
+    }
+}
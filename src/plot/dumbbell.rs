@@ -0,0 +1,95 @@
+use super::marker;
+use crate::{DrawView, View, ViewCanvas};
+
+/// A dumbbell chart: two values for each category, linked by a line, to compare a value before
+/// and after a change, or the minimum and the maximum of each category.
+///
+/// The first category is drawn at `x = 0.5`, the next one at `x = 1.5`, etc... The first value of
+/// each category is drawn as a hollow marker and the second one as a filled marker.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let dumbbell = plot::Dumbbell::new(vec![(820.0, 640.0), (410.0, 450.0), (1250.0, 700.0)])
+///     .with_labels(vec!["parse".to_string(), "check".to_string(), "codegen".to_string()]);
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..3.0))
+///     .set_codomain(Domain(0.0..1400.0))
+///     .set_title("Build time (ms), before and after")
+///     .set_size(Size::new(60, 30))
+///     .add_plot(Box::new(dumbbell));
+///
+/// println!("{plot}");
+/// ```
+pub struct Dumbbell {
+    pairs: Vec<(f64, f64)>,
+    labels: Vec<String>,
+}
+
+impl Dumbbell {
+    /// Create a new dumbbell chart from the two values of each category.
+    pub fn new(pairs: Vec<(f64, f64)>) -> Self {
+        Self {
+            pairs,
+            labels: Vec::new(),
+        }
+    }
+
+    /// Write the name of each category below the x axis instead of the values of the x axis.
+    pub fn with_labels(mut self, labels: Vec<String>) -> Self {
+        self.labels = labels;
+        self
+    }
+}
+
+impl DrawView for Dumbbell {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let dx = view.domain.range() / view.size.w as f64;
+        let dy = view.codomain.range() / view.size.h as f64;
+        for (index, &(first, second)) in self.pairs.iter().enumerate() {
+            let x = index as f64 + 0.5;
+            canvas.line(x, first, x, second);
+            marker(view, canvas, x, first);
+            marker(view, canvas, x, second);
+            // Fill the inside of the second marker, one column of pixels at a time.
+            for column in -1..=1 {
+                let x = x + column as f64 * dx;
+                canvas.line(x, second - dy, x, second + dy);
+            }
+        }
+    }
+
+    fn x_labels(&self) -> Vec<(f64, String)> {
+        self.labels
+            .iter()
+            .enumerate()
+            .map(|(index, label)| (index as f64 + 0.5, label.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AxisMode, Decorations, Domain, Plot, Size};
+
+    #[test]
+    fn hollow_and_filled_markers() {
+        let dumbbell = Dumbbell::new(vec![(1.0, 3.0), (3.0, 1.0)]);
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..2.0))
+            .set_codomain(Domain(0.0..4.0))
+            .set_size(Size::new(20, 16))
+            .set_axis_mode(AxisMode::None)
+            .set_decorations(Decorations::NONE)
+            .add_plot(Box::new(dumbbell));
+        // The first value is hollow, the second one is filled, linked by a line.
+        assert_eq!(
+            plot.try_render().unwrap(),
+            " ⢠⣤⣤  ⢠⠤⢤ \n ⠸⢿⠿  ⠸⢼⠼ \n ⢠⢼⢤  ⢠⣼⣤ \n ⠸⠬⠼  ⠸⠿⠿ \n"
+        );
+    }
+}
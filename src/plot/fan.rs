@@ -0,0 +1,119 @@
+use super::shade_between;
+use crate::{Color, DrawView, View, ViewCanvas};
+
+/// A fan chart: percentiles of a quantity over x (a forecast, latencies over time, etc...) drawn
+/// as nested shaded ribbons around the median line.
+///
+/// The percentiles are given from the lowest to the highest, like p10, p25, p50, p75 and p90.
+/// The first and the last percentiles bound the outer ribbon, the second and the second to last
+/// the next one, etc... Inner ribbons are shaded more densely. With an odd number of
+/// percentiles, the middle one is the median and is drawn as a line.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let x = (0..30).map(|day| day as f64).collect::<Vec<_>>();
+/// let percentile = |z: f64| x.iter().map(|x| 100.0 + x + z * x.sqrt() * 4.0).collect();
+/// let percentiles = vec![
+///     percentile(-1.28),
+///     percentile(-0.67),
+///     percentile(0.0),
+///     percentile(0.67),
+///     percentile(1.28),
+/// ];
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..29.0))
+///     .set_codomain(Domain(80.0..160.0))
+///     .set_title("Forecast")
+///     .set_size(Size::new(60, 25))
+///     .add_plot(Box::new(plot::FanChart::new(x, percentiles)));
+///
+/// println!("{plot}");
+/// ```
+pub struct FanChart {
+    x: Vec<f64>,
+    percentiles: Vec<Vec<f64>>,
+    color: Option<Color>,
+}
+
+impl FanChart {
+    /// Create a new fan chart from the values of each percentile, from the lowest percentile to
+    /// the highest. The values of each percentile are matched with the values of `x` in order,
+    /// which must be sorted. Extra values of either are ignored.
+    pub fn new(x: Vec<f64>, percentiles: Vec<Vec<f64>>) -> Self {
+        let len = percentiles.iter().map(Vec::len).fold(x.len(), usize::min);
+        Self {
+            x: x[..len].to_vec(),
+            percentiles: percentiles
+                .into_iter()
+                .map(|values| values[..len].to_vec())
+                .collect(),
+            color: None,
+        }
+    }
+
+    /// Draw the ribbons and the median with `color`. By default, the first color of the palette
+    /// of the theme is used.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// The pairs of percentiles bounding each ribbon, from the outer ribbon to the inner one.
+    fn ribbons(&self) -> impl Iterator<Item = (&[f64], &[f64])> {
+        let count = self.percentiles.len();
+        (0..count / 2).map(move |index| {
+            let lower = &self.percentiles[index];
+            let upper = &self.percentiles[count - 1 - index];
+            (lower.as_slice(), upper.as_slice())
+        })
+    }
+}
+
+impl DrawView for FanChart {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        canvas.set_color(self.color.or_else(|| view.series_color(0)));
+        let count = self.percentiles.len() / 2;
+        for (index, (lower, upper)) in self.ribbons().enumerate() {
+            // Inner ribbons are drawn over the outer ones with a higher level, so they look
+            // denser.
+            let level = (index + 1) as f64 / (count + 1) as f64;
+            shade_between(view, canvas, &self.x, lower, upper, level);
+        }
+        if self.percentiles.len() % 2 == 1 {
+            let median = &self.percentiles[count];
+            self.x.windows(2).zip(median.windows(2)).for_each(|(x, y)| {
+                canvas.line(x[0], y[0], x[1], y[1]);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_ribbons() {
+        let fan = FanChart::new(
+            vec![0.0, 1.0],
+            vec![
+                vec![1.0, 1.0],
+                vec![2.0, 2.0],
+                vec![3.0, 3.0],
+                vec![4.0, 4.0, 9.0],
+            ],
+        );
+        let ribbons = fan.ribbons().collect::<Vec<_>>();
+        assert_eq!(
+            ribbons,
+            vec![
+                (&[1.0, 1.0][..], &[4.0, 4.0][..]),
+                (&[2.0, 2.0][..], &[3.0, 3.0][..])
+            ]
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{DrawView, View, ViewCanvas};
+
+/// A task of a [`Gantt`] chart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Task {
+    /// When the task starts, on the x axis.
+    pub start: f64,
+    /// When the task ends, on the x axis.
+    pub end: f64,
+    /// The row of the chart on which the task is drawn, starting from the top.
+    pub row: usize,
+    /// The name of the task, written left of the y axis on its row.
+    pub label: String,
+}
+
+impl Task {
+    /// Create a new task named `label`, from `start` to `end` on the x axis, drawn on `row` (the
+    /// first row is at the top).
+    pub fn new(start: f64, end: f64, row: usize, label: &str) -> Self {
+        Self {
+            start,
+            end,
+            row,
+            label: String::from(label),
+        }
+    }
+}
+
+/// A [Gantt chart](https://en.wikipedia.org/wiki/Gantt_chart). Each task is drawn as an
+/// horizontal bar on its row.
+///
+/// The row `n` is drawn between `y = rows - n - 1` and `y = rows - n`, so a codomain from `0` to
+/// the number of rows shows every row with the first one at the top. The y axis is categorical:
+/// the labels of the tasks are written left of it, on their rows, instead of its values. When
+/// several tasks share a row, the label of the first one is written.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+/// use termplot::plot::Task;
+///
+/// let tasks = vec![
+///     Task::new(0.0, 4.0, 0, "fetch"),
+///     Task::new(4.0, 12.0, 1, "compile"),
+///     Task::new(12.0, 17.0, 2, "test"),
+///     Task::new(12.0, 14.0, 3, "docs"),
+///     Task::new(17.0, 19.0, 4, "deploy"),
+/// ];
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..20.0))
+///     .set_codomain(Domain(0.0..5.0))
+///     .set_title("Build pipeline")
+///     .set_x_label("Time (s)")
+///     .set_size(Size::new(80, 25))
+///     .add_plot(Box::new(plot::Gantt::new(tasks)));
+///
+/// println!("{plot}");
+/// ```
+pub struct Gantt {
+    tasks: Vec<Task>,
+}
+
+impl Gantt {
+    /// The proportion of the height of a row taken by a bar.
+    const BAR_HEIGHT: f64 = 0.5;
+
+    /// Create a new Gantt chart from its tasks.
+    pub fn new(tasks: Vec<Task>) -> Self {
+        Self { tasks }
+    }
+
+    /// The number of rows of the chart.
+    fn rows(&self) -> usize {
+        self.tasks
+            .iter()
+            .map(|task| task.row + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The y coordinate of the center of the row of `task`.
+    fn center(&self, task: &Task) -> f64 {
+        (self.rows() - task.row) as f64 - 0.5
+    }
+}
+
+impl DrawView for Gantt {
+    fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+        for task in self.tasks.iter() {
+            let center = self.center(task);
+            let (y0, y1) = (
+                center - Self::BAR_HEIGHT / 2.0,
+                center + Self::BAR_HEIGHT / 2.0,
+            );
+            canvas.fill_rect(task.start, y0, task.end, y1);
+        }
+    }
+
+    /// The label of each task, on its row.
+    fn y_labels(&self) -> Vec<(f64, String)> {
+        self.tasks
+            .iter()
+            .map(|task| (self.center(task), task.label.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decorations, Domain, Plot, Size};
+
+    #[test]
+    fn labels_on_the_y_axis() {
+        let tasks = vec![
+            Task::new(0.0, 2.0, 0, "fetch"),
+            Task::new(2.0, 4.0, 1, "build"),
+            Task::new(3.0, 4.0, 1, "lint"),
+        ];
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..4.0))
+            .set_codomain(Domain(0.0..2.0))
+            .set_size(Size::new(8, 8))
+            .set_decorations(Decorations {
+                y_ticks: true,
+                ..Decorations::NONE
+            })
+            .add_plot(Box::new(Gantt::new(tasks)));
+        let rendered = plot.try_render().unwrap();
+        let labels = rendered
+            .lines()
+            .map(|line| line.split(|c| ('⠀'..='⣿').contains(&c)).next().unwrap())
+            .collect::<Vec<_>>();
+        // The label of the first task of a row is written.
+        assert_eq!(labels, ["fetch", "build"]);
+    }
+}
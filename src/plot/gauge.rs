@@ -0,0 +1,161 @@
+use crate::{Align, Color, Domain, DrawView, View, ViewCanvas};
+use std::f64::consts::PI;
+
+/// The number of segments of a half circle, used to draw the edges of the bands.
+const SEGMENTS: usize = 64;
+
+/// A gauge: a semicircular dial from the start of a range on the left to its end on the right,
+/// with a needle pointing at a value.
+///
+/// The dial is centered horizontally at the bottom of the view and is as large as the view allows.
+/// Its radius is the same on both axis, in the units of the axis: use
+/// [`Plot::set_aspect`](crate::Plot::set_aspect) with [`Aspect::Equal`](crate::Aspect::Equal) to
+/// keep it round, and [`AxisMode::None`](crate::AxisMode::None) to hide the axis of the plot.
+/// Values outside of the range are drawn at the closest end of the dial.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let gauge = plot::Gauge::new(72.0, Domain(0.0..100.0)).with_bands(vec![
+///     (60.0, Some(Color::Green)),
+///     (85.0, Some(Color::Yellow)),
+///     (100.0, Some(Color::Red)),
+/// ]);
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-1.0..1.0))
+///     .set_codomain(Domain(0.0..1.0))
+///     .set_aspect(Aspect::Equal)
+///     .set_axis_mode(AxisMode::None)
+///     .set_title("CPU (%)")
+///     .set_size(Size::new(60, 32))
+///     .add_plot(Box::new(gauge));
+///
+/// println!("{plot}");
+/// ```
+pub struct Gauge {
+    value: f64,
+    range: Domain,
+    bands: Vec<(f64, Option<Color>)>,
+}
+
+impl Gauge {
+    /// Create a new gauge showing `value` on a dial going over `range`.
+    pub fn new(value: f64, range: Domain) -> Self {
+        Self {
+            value,
+            range,
+            bands: Vec::new(),
+        }
+    }
+
+    /// Set the threshold bands drawn along the dial, each with the value where it ends and its
+    /// color, in increasing order. The first band starts at the start of the range and each
+    /// following band starts at the end of the previous one. By default, there are no bands.
+    pub fn with_bands(mut self, bands: Vec<(f64, Option<Color>)>) -> Self {
+        self.bands = bands;
+        self
+    }
+
+    /// The position of `value` on the dial, from `0.0` (the start of the range) to `1.0`.
+    fn fraction(&self, value: f64) -> f64 {
+        ((value - self.range.min()) / self.range.range()).clamp(0.0, 1.0)
+    }
+}
+
+impl DrawView for Gauge {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let center = (
+            (view.domain.min() + view.domain.max()) / 2.0,
+            view.codomain.min() + view.codomain.range() * 0.1,
+        );
+        // Room is left for the labels below the dial.
+        let radius = (view.domain.range() * 0.45).min(view.codomain.range() * 0.8);
+        let point = |fraction: f64, length: f64| {
+            let angle = PI * (1.0 - fraction);
+            (
+                center.0 + angle.cos() * radius * length,
+                center.1 + angle.sin() * radius * length,
+            )
+        };
+
+        let mut start = 0.0;
+        for &(end, color) in self.bands.iter() {
+            let end = self.fraction(end);
+            canvas.set_color(color);
+            // The band goes along the outer edge of the dial and back along its inner edge.
+            let steps = ((end - start) * SEGMENTS as f64).ceil() as usize;
+            let fractions =
+                (0..=steps).map(|step| start + (end - start) * step as f64 / steps.max(1) as f64);
+            let band = fractions
+                .clone()
+                .map(|fraction| point(fraction, 1.0))
+                .chain(fractions.rev().map(|fraction| point(fraction, 0.85)))
+                .collect::<Vec<_>>();
+            canvas.fill_polygon(&band);
+            start = end;
+        }
+        canvas.set_color(None);
+
+        canvas.arc(center.0, center.1, radius, radius, 0.0, PI);
+        let (x, y) = point(self.fraction(self.value), 0.8);
+        canvas.line(center.0, center.1, x, y);
+
+        let below = center.1 - view.codomain.range() * 0.08;
+        canvas.text(
+            point(0.0, 1.0).0,
+            below,
+            &label(self.range.min()),
+            Align::Center,
+        );
+        canvas.text(
+            point(1.0, 1.0).0,
+            below,
+            &label(self.range.max()),
+            Align::Center,
+        );
+        canvas.text(center.0, below, &label(self.value), Align::Center);
+    }
+}
+
+/// The label of a value, with at most two decimals.
+fn label(value: f64) -> String {
+    ((value * 100.0).round() / 100.0).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Aspect, AxisMode, Plot, Size};
+
+    #[test]
+    fn values_are_clamped_to_the_range() {
+        let gauge = Gauge::new(0.0, Domain(10.0..20.0));
+        assert_eq!(gauge.fraction(15.0), 0.5);
+        assert_eq!(gauge.fraction(5.0), 0.0);
+        assert_eq!(gauge.fraction(25.0), 1.0);
+    }
+
+    #[test]
+    fn round_dial() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(-1.0..1.0))
+            .set_codomain(Domain(0.0..1.0))
+            .set_aspect(Aspect::Equal)
+            .set_axis_mode(AxisMode::None)
+            .set_size(Size::new(120, 32))
+            .add_plot(Box::new(Gauge::new(50.0, Domain(0.0..100.0))));
+        let pixels = plot.view.pixels().unwrap();
+        let rows = pixels
+            .iter()
+            .filter(|row| row.contains(&true))
+            .collect::<Vec<_>>();
+        let columns = (0..120)
+            .filter(|&x| pixels.iter().any(|row| row[x]))
+            .count();
+        // The half circle is twice as wide as it is high.
+        assert!((columns as f64 / (2.0 * rows.len() as f64) - 1.0).abs() < 0.1);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::color::Colormap;
+use crate::{Domain, DrawView, View, ViewCanvas};
+
+/// A [heatmap](https://en.wikipedia.org/wiki/Heat_map) of a grid of values.
+///
+/// Each value is drawn as a rectangular cell shaded according to its intensity: the smallest
+/// value is empty and the largest value is completely filled.
+///
+/// By default, each cell is 1 unit wide and 1 unit tall, and the first row is drawn at the
+/// bottom, so the value `values[row][column]` covers `column..column + 1` on the x axis and
+/// `row..row + 1` on the y axis. Use [`Heatmap::with_extent`] to stretch the grid over other
+/// ranges, and [`Heatmap::with_colormap`] to color the cells instead of shading them.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let values = (0..10)
+///     .map(|y| (0..10).map(|x| ((x * y) as f64).sqrt()).collect())
+///     .collect();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..10.0))
+///     .set_codomain(Domain(0.0..10.0))
+///     .set_title("Graph title")
+///     .set_size(Size::new(60, 40))
+///     .add_plot(Box::new(plot::Heatmap::new(values)));
+///
+/// println!("{plot}");
+/// ```
+pub struct Heatmap {
+    values: Vec<Vec<f64>>,
+    extent: Option<(Domain, Domain)>,
+    colormap: Option<Colormap>,
+}
+
+impl Heatmap {
+    /// Create a new heatmap. `values[row][column]` is the value of a cell.
+    pub fn new(values: Vec<Vec<f64>>) -> Self {
+        Self {
+            values,
+            extent: None,
+            colormap: None,
+        }
+    }
+
+    /// Stretch the grid so that its columns cover `x` and its rows cover `y`.
+    pub fn with_extent(mut self, x: Domain, y: Domain) -> Self {
+        self.extent = Some((x, y));
+        self
+    }
+
+    /// Fill every cell and color it with `colormap`, from the start of the map for the smallest
+    /// value to its end for the largest value. A colorbar on the right of the view shows the value
+    /// of each color. See [`Colormap`].
+    pub fn with_colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = Some(colormap);
+        self
+    }
+
+    /// The smallest and largest finite values of the grid.
+    fn bounds(&self) -> Option<(f64, f64)> {
+        let (min, max) = self
+            .values
+            .iter()
+            .flatten()
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            });
+        (min <= max).then_some((min, max))
+    }
+}
+
+impl DrawView for Heatmap {
+    fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+        let Some((min, max)) = self.bounds() else {
+            return;
+        };
+        let rows = self.values.len();
+        let columns = self.values.iter().map(|row| row.len()).max().unwrap_or(0);
+        let (x, y) = match &self.extent {
+            Some((x, y)) => (x.clone(), y.clone()),
+            None => (Domain(0.0..columns as f64), Domain(0.0..rows as f64)),
+        };
+        let cell_width = (x.max() - x.min()) / columns as f64;
+        let cell_height = (y.max() - y.min()) / rows as f64;
+        for (row, values) in self.values.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                if !value.is_finite() {
+                    continue;
+                }
+                let level = match max - min {
+                    range if range <= 0.0 => 1.0,
+                    range => (value - min) / range,
+                };
+                let x0 = x.min() + column as f64 * cell_width;
+                let y0 = y.min() + row as f64 * cell_height;
+                let (x1, y1) = (x0 + cell_width, y0 + cell_height);
+                match self.colormap {
+                    Some(colormap) => {
+                        canvas.set_color(Some(colormap.color(level)));
+                        canvas.fill_rect(x0, y0, x1, y1);
+                    }
+                    None => canvas.shade_rect(x0, y0, x1, y1, level),
+                }
+            }
+        }
+    }
+
+    fn colorbar(&self) -> Option<(Colormap, Domain)> {
+        let (min, max) = self.bounds()?;
+        Some((self.colormap?, Domain(min..max)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Size;
+    use std::collections::HashMap;
+
+    /// The color of each pixel set by `heatmap` on a view of 4 by 4 pixels from 0 to 2.
+    fn colors(heatmap: &Heatmap) -> HashMap<(u32, u32), Option<crate::Color>> {
+        let view = View {
+            domain: Domain(0.0..2.0),
+            codomain: Domain(0.0..2.0),
+            size: Size::new(4, 4),
+            ..View::default()
+        };
+        let mut canvas = ViewCanvas::new(&view);
+        canvas.recorded = Some(HashMap::new());
+        heatmap.draw(&view, &mut canvas);
+        canvas.recorded.unwrap()
+    }
+
+    #[test]
+    fn colormap_colors() {
+        let viridis = Colormap::Viridis;
+        // The first row is at the bottom, and the cell of NaN is not drawn.
+        let heatmap =
+            Heatmap::new(vec![vec![0.0, 1.0], vec![2.0, f64::NAN]]).with_colormap(viridis);
+        let pixels = colors(&heatmap);
+        assert_eq!(pixels[&(0, 2)], Some(viridis.color(0.0)));
+        assert_eq!(pixels[&(2, 2)], Some(viridis.color(0.5)));
+        assert_eq!(pixels[&(0, 0)], Some(viridis.color(1.0)));
+        assert!(!pixels.contains_key(&(2, 0)));
+        assert_eq!(heatmap.colorbar(), Some((viridis, Domain(0.0..2.0))));
+
+        // When all the values are equal, they all have the color of the end of the map.
+        let heatmap =
+            Heatmap::new(vec![vec![3.0, 3.0], vec![f64::NAN, 3.0]]).with_colormap(viridis);
+        let pixels = colors(&heatmap);
+        assert_eq!(pixels[&(0, 2)], Some(viridis.color(1.0)));
+        assert_eq!(pixels[&(2, 0)], Some(viridis.color(1.0)));
+        assert!(!pixels.contains_key(&(0, 0)));
+        assert_eq!(heatmap.colorbar(), Some((viridis, Domain(3.0..3.0))));
+
+        // Without any finite value, nothing is drawn.
+        let heatmap = Heatmap::new(vec![vec![f64::NAN]]).with_colormap(viridis);
+        assert!(colors(&heatmap).is_empty());
+        assert_eq!(heatmap.colorbar(), None);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::color::Colormap;
+use crate::{Domain, DrawView, View, ViewCanvas};
+use std::collections::HashMap;
+
+/// A hexagonal binning of a cloud of points: the points are counted in hexagonal bins and each
+/// bin with points is drawn as a hexagon.
+///
+/// The outline of each bin is drawn, with a filled hexagon inside of it whose area grows with
+/// the count of the bin, up to the whole bin for the largest count. Use
+/// [`Hexbin::with_colormap`] to fill the bins with colors instead. Unlike [`Density2D`], the exact
+/// count of each bin is available, see [`Hexbin::bins`].
+///
+/// The hexagons have a vertex at the top and one at the bottom, and their size is measured in the
+/// units of the axis: with different scales on the x and y axis, they look stretched.
+///
+/// [`Density2D`]: crate::plot::Density2D
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let points = (0..500)
+///     .map(|i| {
+///         let t = i as f64 / 50.0;
+///         (t + (i % 7) as f64 / 7.0, t.sin() * 3.0 + (i % 5) as f64 / 5.0)
+///     })
+///     .collect();
+/// let hexbin = plot::Hexbin::new(points, 0.5);
+/// assert_eq!(hexbin.bins().iter().map(|(_, count)| count).sum::<usize>(), 500);
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..11.0))
+///     .set_codomain(Domain(-4.0..4.0))
+///     .set_size(Size::new(80, 40))
+///     .add_plot(Box::new(hexbin));
+///
+/// println!("{plot}");
+/// ```
+pub struct Hexbin {
+    /// The center and the count of each bin with points.
+    bins: Vec<((f64, f64), usize)>,
+    size: f64,
+    colormap: Option<Colormap>,
+}
+
+impl Hexbin {
+    /// Count `points` in hexagonal bins of `size`: the distance from the center of a bin to its
+    /// vertices. Points that are not finite are ignored.
+    pub fn new(points: Vec<(f64, f64)>, size: f64) -> Self {
+        let mut counts = HashMap::<(i64, i64), usize>::new();
+        for (x, y) in points {
+            if x.is_finite() && y.is_finite() {
+                *counts.entry(hexagon(x, y, size)).or_default() += 1;
+            }
+        }
+        let mut bins = counts.into_iter().collect::<Vec<_>>();
+        bins.sort_by_key(|&((q, r), _)| (r, q));
+        let bins = bins
+            .into_iter()
+            .map(|((q, r), count)| (center(q, r, size), count))
+            .collect();
+        Self {
+            bins,
+            size,
+            colormap: None,
+        }
+    }
+
+    /// Fill every bin and color it with `colormap` by its count, from the start of the map for no
+    /// points to its end for the largest count. A colorbar on the right of the view shows the
+    /// count of each color.
+    pub fn with_colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = Some(colormap);
+        self
+    }
+
+    /// The center and the count of every bin with points, from the bottom row of bins to the top
+    /// one.
+    pub fn bins(&self) -> &[((f64, f64), usize)] {
+        &self.bins
+    }
+
+    fn max_count(&self) -> usize {
+        self.bins.iter().map(|&(_, count)| count).max().unwrap_or(0)
+    }
+}
+
+impl DrawView for Hexbin {
+    fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+        let max = self.max_count() as f64;
+        for &((x, y), count) in &self.bins {
+            let level = count as f64 / max;
+            match self.colormap {
+                Some(colormap) => {
+                    canvas.set_color(Some(colormap.color(level)));
+                    canvas.fill_polygon(&vertices(x, y, self.size));
+                }
+                None => {
+                    canvas.polygon(&vertices(x, y, self.size));
+                    canvas.fill_polygon(&vertices(x, y, self.size * level.sqrt()));
+                }
+            }
+        }
+    }
+
+    fn colorbar(&self) -> Option<(Colormap, Domain)> {
+        Some((self.colormap?, Domain(0.0..self.max_count() as f64)))
+    }
+}
+
+/// The axial coordinates of the hexagon of `size` holding (`x`, `y`). See
+/// [hexagonal grids](https://www.redblobgames.com/grids/hexagons/).
+fn hexagon(x: f64, y: f64, size: f64) -> (i64, i64) {
+    let q = (3f64.sqrt() / 3.0 * x - y / 3.0) / size;
+    let r = (2.0 / 3.0 * y) / size;
+    // Round the cube coordinates `(q, r, -q - r)`, fixing the one that was rounded the most.
+    let s = -q - r;
+    let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+    let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+    if dq > dr && dq > ds {
+        rq = -rr - rs;
+    } else if dr > ds {
+        rr = -rq - rs;
+    }
+    (rq as i64, rr as i64)
+}
+
+/// The center of the hexagon of `size` at the axial coordinates (`q`, `r`).
+fn center(q: i64, r: i64, size: f64) -> (f64, f64) {
+    let (q, r) = (q as f64, r as f64);
+    (size * 3f64.sqrt() * (q + r / 2.0), size * 1.5 * r)
+}
+
+/// The vertices of the hexagon of `size` centered on (`x`, `y`).
+fn vertices(x: f64, y: f64, size: f64) -> Vec<(f64, f64)> {
+    (0..6)
+        .map(|index| {
+            let angle = std::f64::consts::PI / 3.0 * index as f64 + std::f64::consts::PI / 6.0;
+            (x + size * angle.cos(), y + size * angle.sin())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hexagonal_bins() {
+        assert_eq!(hexagon(0.1, -0.1, 1.0), (0, 0));
+        let (x, y) = center(2, -1, 1.0);
+        assert_eq!(hexagon(x + 0.4, y - 0.4, 1.0), (2, -1));
+        let hexbin = Hexbin::new(
+            vec![(0.0, 0.0), (0.2, 0.1), (3.0, 0.0), (f64::NAN, 0.0)],
+            1.0,
+        );
+        let counts = hexbin
+            .bins()
+            .iter()
+            .map(|&(_, count)| count)
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![2, 1]);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{Color, DrawView, View, ViewCanvas};
+use std::ops;
+
+/// A highlighted range of the x axis: the characters of the view in the range are drawn in
+/// reverse video, or on a colored background with [`Highlight::with_color`], to call attention
+/// to a window of time (an incident, a deployment, etc...).
+///
+/// The plots drawn in the range stay visible, whichever plot is drawn first. The range covers
+/// whole characters, and is only drawn with the braille backend.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let latency = (0..200)
+///     .map(|t| (t as f64, if (120..140).contains(&t) { 80.0 } else { 20.0 }))
+///     .collect();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..200.0))
+///     .set_codomain(Domain(0.0..100.0))
+///     .set_title("Latency (ms)")
+///     .set_size(Size::new(60, 20))
+///     .add_plot(Box::new(plot::Highlight::x_range(115.0..145.0)))
+///     .add_plot(Box::new(plot::Lines::new(latency)));
+///
+/// println!("{plot}");
+/// ```
+pub struct Highlight {
+    range: ops::Range<f64>,
+    color: Option<Color>,
+}
+
+impl Highlight {
+    /// Highlight the view from `range.start` to `range.end` on the x axis, over the whole height
+    /// of the view.
+    pub fn x_range(range: ops::Range<f64>) -> Self {
+        Self { range, color: None }
+    }
+
+    /// Draw the range on a background of `color` instead of in reverse video.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl DrawView for Highlight {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        canvas.set_color(self.color);
+        canvas.highlight_rect(
+            self.range.start,
+            view.codomain.min(),
+            self.range.end,
+            view.codomain.max(),
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{DrawView, View, ViewCanvas};
+
+/// The curve of an implicit function: the points where `f(x, y) = 0`.
+///
+/// The function is evaluated at the corners of every pixel of the view, and the curve is traced
+/// with the [marching squares](https://en.wikipedia.org/wiki/Marching_squares) algorithm between
+/// the corners where the sign of the function changes. Write `x² + y² = 25` as
+/// `x² + y² - 25 = 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-6.0..6.0))
+///     .set_codomain(Domain(-6.0..6.0))
+///     .set_aspect(Aspect::Equal)
+///     .set_title("x² + y² = 25 and y² = x³ - 2x + 1")
+///     .set_size(Size::new(120, 60))
+///     .add_plot(Box::new(plot::Implicit::new(|x, y| x * x + y * y - 25.0)))
+///     .add_plot(Box::new(plot::Implicit::new(|x, y| {
+///         y * y - x.powi(3) + 2.0 * x - 1.0
+///     })));
+///
+/// println!("{plot}");
+/// ```
+pub struct Implicit<F> {
+    function: F,
+}
+
+impl<F> Implicit<F>
+where
+    F: Fn(f64, f64) -> f64,
+{
+    /// Create a new implicit curve where `function(x, y) = 0`.
+    pub fn new(function: F) -> Self {
+        Self { function }
+    }
+}
+
+impl<F> DrawView for Implicit<F>
+where
+    F: Fn(f64, f64) -> f64,
+{
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let (columns, rows) = (view.size.w, view.size.h);
+        let x = |column: usize| {
+            view.domain.min() + column as f64 / columns as f64 * view.domain.range()
+        };
+        let y = |row: usize| view.codomain.min() + row as f64 / rows as f64 * view.codomain.range();
+        let xs = (0..=columns).map(x).collect::<Vec<_>>();
+        let ys = (0..=rows).map(y).collect::<Vec<_>>();
+        let values = ys
+            .iter()
+            .map(|&y| xs.iter().map(|&x| (self.function)(x, y)).collect())
+            .collect::<Vec<Vec<_>>>();
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let corners = [
+                    values[row][column],
+                    values[row][column + 1],
+                    values[row + 1][column + 1],
+                    values[row + 1][column],
+                ];
+                let center = (self.function)(
+                    (xs[column] + xs[column + 1]) / 2.0,
+                    (ys[row] + ys[row + 1]) / 2.0,
+                );
+                let (width, height) = (xs[column + 1] - xs[column], ys[row + 1] - ys[row]);
+                for ((x0, y0), (x1, y1)) in segments(corners, center) {
+                    canvas.line(
+                        xs[column] + x0 * width,
+                        ys[row] + y0 * height,
+                        xs[column] + x1 * width,
+                        ys[row] + y1 * height,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// A segment between two points, in the coordinates of a square of side 1.
+type Segment = ((f64, f64), (f64, f64));
+
+/// The segments of the curve crossing a square, from the values of the function at its corners
+/// (bottom left, bottom right, top right and top left) and at its center.
+fn segments(corners: [f64; 4], center: f64) -> Vec<Segment> {
+    if corners.iter().any(|value| !value.is_finite()) {
+        return Vec::new();
+    }
+    const POSITIONS: [(f64, f64); 4] = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+    // The point where the curve crosses each edge, from the corner `index` to the next one.
+    let crossing = |index: usize| {
+        let next = (index + 1) % 4;
+        let (a, b) = (corners[index], corners[next]);
+        if (a < 0.0) == (b < 0.0) {
+            return None;
+        }
+        let t = a / (a - b);
+        let ((x0, y0), (x1, y1)) = (POSITIONS[index], POSITIONS[next]);
+        Some((x0 + (x1 - x0) * t, y0 + (y1 - y0) * t))
+    };
+    let crossings = (0..4).filter_map(|edge| crossing(edge).map(|point| (edge, point)));
+    let crossings = crossings.collect::<Vec<_>>();
+    match crossings.as_slice() {
+        [(_, a), (_, b)] => vec![(*a, *b)],
+        // A saddle: the curve crosses every edge. The center tells which opposite corners are
+        // linked.
+        [(0, a), (1, b), (2, c), (3, d)] => match (center < 0.0) == (corners[0] < 0.0) {
+            true => vec![(*a, *b), (*c, *d)],
+            false => vec![(*d, *a), (*b, *c)],
+        },
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marching_squares() {
+        assert!(segments([1.0, 1.0, 1.0, 1.0], 1.0).is_empty());
+        // Only the bottom left corner is inside.
+        assert_eq!(
+            segments([-1.0, 1.0, 1.0, 1.0], 1.0),
+            vec![((0.5, 0.0), (0.0, 0.5))]
+        );
+        // A saddle where the center is outside, like the bottom left and top right corners.
+        assert_eq!(
+            segments([1.0, -1.0, 1.0, -1.0], 1.0),
+            vec![((0.5, 0.0), (1.0, 0.5)), ((0.5, 1.0), (0.0, 0.5))]
+        );
+        assert_eq!(
+            segments([1.0, -1.0, 1.0, -1.0], -1.0),
+            vec![((0.0, 0.5), (0.5, 0.0)), ((1.0, 0.5), (0.5, 1.0))]
+        );
+    }
+}
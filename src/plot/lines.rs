@@ -0,0 +1,107 @@
+use super::{segments, Gradient};
+use crate::{Color, DrawView, View, ViewCanvas};
+
+/// A line going through a series of points.
+///
+/// The points are joined in the order they are given.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let points = (0..20).map(|x| (x as f64, (x as f64).sqrt())).collect();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..20.0))
+///     .set_codomain(Domain(0.0..5.0))
+///     .set_title("Graph title")
+///     .set_size(Size::new(50, 25))
+///     .add_plot(Box::new(plot::Lines::new(points)));
+///
+/// println!("{plot}");
+/// ```
+pub struct Lines {
+    points: Vec<(f64, f64)>,
+    gradient: Option<Gradient>,
+    width: f64,
+}
+
+impl Lines {
+    /// Create a new line going through the `(x, y)` points.
+    pub fn new(points: Vec<(f64, f64)>) -> Self {
+        Self {
+            points,
+            gradient: None,
+            width: 1.0,
+        }
+    }
+
+    /// Color each segment with the color given by `gradient` at the middle `(x, y)` of the
+    /// segment. See [`Graph::with_gradient`](super::Graph::with_gradient).
+    pub fn with_gradient(mut self, gradient: impl Fn(f64, f64) -> Color + 'static) -> Self {
+        self.gradient = Some(Box::new(gradient));
+        self
+    }
+
+    /// Draw the line `width` pixels thick, to emphasize it (for example a fit over noisy data).
+    /// See [`ViewCanvas::line_with_width`].
+    pub fn with_line_width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+impl DrawView for Lines {
+    fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+        segments(canvas, &self.points, self.gradient.as_ref(), self.width);
+    }
+
+    fn points(&self, _: &View) -> Vec<(f64, f64)> {
+        self.points.clone()
+    }
+}
+
+/// A [scatter plot](https://en.wikipedia.org/wiki/Scatter_plot). Each point is drawn as a single
+/// pixel.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+/// use rand::Rng;
+///
+/// let mut rng = rand::thread_rng();
+/// let points = (0..100)
+///     .map(|_| (rng.gen_range(0.0f64..10.0f64), rng.gen_range(0.0f64..10.0f64)))
+///     .collect();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..10.0))
+///     .set_codomain(Domain(0.0..10.0))
+///     .set_title("Graph title")
+///     .set_size(Size::new(50, 25))
+///     .add_plot(Box::new(plot::Scatter::new(points)));
+///
+/// println!("{plot}");
+/// ```
+pub struct Scatter {
+    points: Vec<(f64, f64)>,
+}
+
+impl Scatter {
+    /// Create a new scatter plot from `(x, y)` points.
+    pub fn new(points: Vec<(f64, f64)>) -> Self {
+        Self { points }
+    }
+}
+
+impl DrawView for Scatter {
+    fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+        self.points.iter().for_each(|&(x, y)| canvas.point(x, y));
+    }
+
+    fn points(&self, _: &View) -> Vec<(f64, f64)> {
+        self.points.clone()
+    }
+}
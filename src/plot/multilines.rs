@@ -0,0 +1,171 @@
+use crate::{Color, DrawView, LegendEntry, View, ViewCanvas};
+
+/// Several lines sharing the same x values, each with its own color and name.
+///
+/// Each series is drawn with a color of the palette of the theme, in order, unless colors are given
+/// with [`MultiLines::with_colors`]. The names of the series are shown in the legend of the plot.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let x = (0..50).map(|x| x as f64 / 5.0).collect::<Vec<_>>();
+/// let ys = vec![
+///     x.iter().map(|x| x.sin()).collect(),
+///     x.iter().map(|x| x.cos()).collect(),
+/// ];
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..10.0))
+///     .set_codomain(Domain(-1.2..1.2))
+///     .set_title("Graph title")
+///     .set_size(Size::new(50, 25))
+///     .add_plot(Box::new(
+///         plot::MultiLines::new(x, ys).with_names(vec!["sin(x)", "cos(x)"]),
+///     ));
+///
+/// println!("{plot}");
+/// ```
+pub struct MultiLines {
+    x: Vec<f64>,
+    ys: Vec<Vec<f64>>,
+    names: Vec<String>,
+    colors: Vec<Color>,
+}
+
+impl MultiLines {
+    /// Create a new line for each series of `ys`. The y values of each series are matched with
+    /// the values of `x` in order; extra values of either are ignored.
+    pub fn new(x: Vec<f64>, ys: Vec<Vec<f64>>) -> Self {
+        let names = (1..=ys.len())
+            .map(|index| format!("series {index}"))
+            .collect();
+        Self {
+            x,
+            ys,
+            names,
+            colors: Vec::new(),
+        }
+    }
+
+    /// Name each series for the legend, in the same order as `ys`. Series without a name keep
+    /// their default name (`series 1`, `series 2`, etc...).
+    pub fn with_names(mut self, names: Vec<&str>) -> Self {
+        for (name, new_name) in self.names.iter_mut().zip(names) {
+            *name = String::from(new_name);
+        }
+        self
+    }
+
+    /// Use `colors` for the series instead of the palette of the theme. The colors are reused in
+    /// order when there are more series than colors.
+    pub fn with_colors(mut self, colors: Vec<Color>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    fn color(&self, view: &View, index: usize) -> Option<Color> {
+        match self.colors.len() {
+            0 => view.series_color(index),
+            len => Some(self.colors[index % len]),
+        }
+    }
+}
+
+impl DrawView for MultiLines {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        for (index, y) in self.ys.iter().enumerate() {
+            canvas.set_color(self.color(view, index));
+            let points = self
+                .x
+                .iter()
+                .copied()
+                .zip(y.iter().copied())
+                .collect::<Vec<_>>();
+            points.windows(2).for_each(|line| {
+                canvas.line(line[0].0, line[0].1, line[1].0, line[1].1);
+            });
+        }
+    }
+
+    /// The points of every series.
+    fn points(&self, _: &View) -> Vec<(f64, f64)> {
+        self.ys
+            .iter()
+            .flat_map(|y| self.x.iter().copied().zip(y.iter().copied()))
+            .collect()
+    }
+
+    /// The points of each series, in the order of the series.
+    fn series_points(&self, _: &View) -> Vec<Vec<(f64, f64)>> {
+        self.ys
+            .iter()
+            .map(|y| self.x.iter().copied().zip(y.iter().copied()).collect())
+            .collect()
+    }
+
+    fn legend(&self, view: &View) -> Vec<LegendEntry> {
+        self.names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| LegendEntry::new(name, self.color(view, index)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Domain, Size};
+    use std::collections::HashMap;
+
+    #[test]
+    fn series_with_their_colors() {
+        let view = View {
+            domain: Domain(0.0..4.0),
+            codomain: Domain(0.0..4.0),
+            size: Size::new(8, 8),
+            ..View::default()
+        };
+        // The extra y value of the first series and the extra x values are ignored.
+        let lines = MultiLines::new(
+            vec![0.5, 1.5, 2.5, 3.5],
+            vec![vec![0.5, 0.5, 0.5, 0.5, 3.5], vec![3.5, 3.5]],
+        )
+        .with_names(vec!["low"]);
+        assert_eq!(
+            lines.series_points(&view),
+            [
+                vec![(0.5, 0.5), (1.5, 0.5), (2.5, 0.5), (3.5, 0.5)],
+                vec![(0.5, 3.5), (1.5, 3.5)],
+            ]
+        );
+        assert_eq!(
+            lines.legend(&view),
+            [
+                LegendEntry::new("low", view.series_color(0)),
+                LegendEntry::new("series 2", view.series_color(1)),
+            ]
+        );
+
+        let mut canvas = ViewCanvas::new(&view);
+        canvas.recorded = Some(HashMap::new());
+        lines.draw(&view, &mut canvas);
+        let pixels = canvas.recorded.take().unwrap();
+        assert_eq!(pixels[&(1, 7)], view.series_color(0));
+        assert_eq!(pixels[&(6, 7)], view.series_color(0));
+        assert_eq!(pixels[&(1, 1)], view.series_color(1));
+        assert!(!pixels.contains_key(&(6, 1)));
+        assert_eq!(canvas.rows(false), ["⠐⠒  ", "⢀⣀⣀⣀"]);
+
+        // The colors given are reused when there are more series than colors.
+        let lines = MultiLines::new(vec![0.0, 1.0], vec![vec![0.0; 2]; 3])
+            .with_colors(vec![Color::Red, Color::Green]);
+        let colors = lines.legend(&view).into_iter().map(|entry| entry.color);
+        assert_eq!(
+            colors.collect::<Vec<_>>(),
+            [Some(Color::Red), Some(Color::Green), Some(Color::Red)]
+        );
+    }
+}
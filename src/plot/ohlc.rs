@@ -0,0 +1,136 @@
+use crate::{Color, DrawView, View, ViewCanvas};
+
+/// The prices of a period (a day, an hour, etc...) at `x`: the price at its opening, the highest
+/// and the lowest prices, and the price at its closing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quote {
+    /// Where the period is drawn on the x axis, like its start or its middle.
+    pub x: f64,
+    /// The price at the opening of the period.
+    pub open: f64,
+    /// The highest price during the period.
+    pub high: f64,
+    /// The lowest price during the period.
+    pub low: f64,
+    /// The price at the closing of the period.
+    pub close: f64,
+}
+
+impl Quote {
+    /// Create the quote of the period at `x` from its opening, highest, lowest and closing
+    /// prices.
+    pub fn new(x: f64, open: f64, high: f64, low: f64, close: f64) -> Self {
+        Self {
+            x,
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+
+    /// Whether the price closed at or above its opening price.
+    pub fn is_rising(&self) -> bool {
+        self.close >= self.open
+    }
+}
+
+/// An [OHLC chart](https://en.wikipedia.org/wiki/Open-high-low-close_chart): a vertical bar from
+/// the lowest to the highest price of each period, with a tick on the left at the opening price
+/// and a tick on the right at the closing price.
+///
+/// Periods closing at or above their opening price are drawn in green and the other ones in red,
+/// unless colors are given with [`Ohlc::with_colors`].
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let quotes = vec![
+///     plot::Quote::new(1.0, 100.0, 104.0, 98.0, 103.0),
+///     plot::Quote::new(2.0, 103.0, 107.0, 101.0, 106.0),
+///     plot::Quote::new(3.0, 106.0, 108.0, 99.0, 100.0),
+///     plot::Quote::new(4.0, 100.0, 102.0, 95.0, 97.0),
+///     plot::Quote::new(5.0, 97.0, 105.0, 96.0, 104.0),
+/// ];
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..6.0))
+///     .set_codomain(Domain(90.0..110.0))
+///     .set_title("ACME")
+///     .set_size(Size::new(60, 25))
+///     .add_plot(Box::new(plot::Ohlc::new(quotes)));
+///
+/// println!("{plot}");
+/// ```
+pub struct Ohlc {
+    quotes: Vec<Quote>,
+    colors: Option<(Color, Color)>,
+}
+
+impl Ohlc {
+    /// Create a new OHLC chart from the quote of each period.
+    pub fn new(quotes: Vec<Quote>) -> Self {
+        Self {
+            quotes,
+            colors: None,
+        }
+    }
+
+    /// Draw the rising periods with `rising` and the falling periods with `falling`. By default,
+    /// they are drawn in green and red.
+    pub fn with_colors(mut self, rising: Color, falling: Color) -> Self {
+        self.colors = Some((rising, falling));
+        self
+    }
+
+    /// The color of a period, by whether it is rising.
+    fn color(&self, quote: &Quote) -> Color {
+        let (rising, falling) = self.colors.unwrap_or((Color::Green, Color::Red));
+        match quote.is_rising() {
+            true => rising,
+            false => falling,
+        }
+    }
+}
+
+/// The length of the ticks: a third of the smallest distance between two periods, or of the
+/// domain when there is a single period.
+fn tick_length(view: &View, quotes: &[Quote]) -> f64 {
+    let mut positions = quotes.iter().map(|quote| quote.x).collect::<Vec<_>>();
+    positions.sort_by(f64::total_cmp);
+    let distance = positions
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|&distance| distance > 0.0)
+        .fold(view.domain.range(), f64::min);
+    distance / 3.0
+}
+
+impl DrawView for Ohlc {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let tick = tick_length(view, &self.quotes);
+        for quote in &self.quotes {
+            canvas.set_color(Some(self.color(quote)));
+            canvas.line(quote.x, quote.low, quote.x, quote.high);
+            canvas.line(quote.x - tick, quote.open, quote.x, quote.open);
+            canvas.line(quote.x, quote.close, quote.x + tick, quote.close);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rising_and_falling() {
+        let ohlc = Ohlc::new(Vec::new()).with_colors(Color::Blue, Color::Yellow);
+        let rising = Quote::new(0.0, 1.0, 3.0, 0.0, 2.0);
+        let falling = Quote::new(0.0, 2.0, 3.0, 0.0, 1.0);
+        assert_eq!(ohlc.color(&rising), Color::Blue);
+        assert_eq!(ohlc.color(&falling), Color::Yellow);
+        assert_eq!(Ohlc::new(Vec::new()).color(&falling), Color::Red);
+    }
+}
@@ -0,0 +1,112 @@
+use super::{marker, rk4};
+use crate::{DrawView, View, ViewCanvas};
+
+/// A [phase portrait](https://en.wikipedia.org/wiki/Phase_portrait) of a system of two
+/// differential equations: the trajectories of the state `(x, y)` from initial conditions.
+///
+/// Each trajectory is integrated with the Runge-Kutta method, starts with a small marker and is
+/// drawn with a color of the palette of the theme. By default, trajectories are integrated for 10
+/// units of time with steps of 0.01; see [`PhasePortrait::with_duration`] and
+/// [`PhasePortrait::with_step`]. A trajectory stops when the state is no longer finite.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// // A damped pendulum: the angle and the angular velocity.
+/// let pendulum = |(angle, velocity): (f64, f64)| (velocity, -angle.sin() - 0.2 * velocity);
+/// let initial_conditions = vec![(-3.0, 2.5), (-1.0, 3.0), (2.0, -3.0), (3.0, 0.0)];
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-4.0..4.0))
+///     .set_codomain(Domain(-3.5..3.5))
+///     .set_title("Damped pendulum")
+///     .set_x_label("Angle")
+///     .set_size(Size::new(100, 50))
+///     .add_plot(Box::new(
+///         plot::PhasePortrait::new(pendulum, initial_conditions).with_duration(30.0),
+///     ));
+///
+/// println!("{plot}");
+/// ```
+pub struct PhasePortrait<F> {
+    derivative: F,
+    initial_conditions: Vec<(f64, f64)>,
+    duration: f64,
+    step: f64,
+}
+
+impl<F> PhasePortrait<F>
+where
+    F: Fn((f64, f64)) -> (f64, f64),
+{
+    /// Create a new phase portrait of the system where `derivative` gives the derivative of the
+    /// state `(x, y)`, with a trajectory for each of the `initial_conditions`.
+    pub fn new(derivative: F, initial_conditions: Vec<(f64, f64)>) -> Self {
+        Self {
+            derivative,
+            initial_conditions,
+            duration: 10.0,
+            step: 0.01,
+        }
+    }
+
+    /// Set how long each trajectory is integrated for.
+    pub fn with_duration(mut self, duration: f64) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Set the time step of the integration. Smaller steps are more precise but slower.
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+}
+
+impl<F> DrawView for PhasePortrait<F>
+where
+    F: Fn((f64, f64)) -> (f64, f64),
+{
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let steps = match self.step > 0.0 {
+            true => (self.duration / self.step).ceil() as usize,
+            false => 0,
+        };
+        for (index, &initial) in self.initial_conditions.iter().enumerate() {
+            canvas.set_color(view.series_color(index));
+            marker(view, canvas, initial.0, initial.1);
+            let mut state = initial;
+            for _ in 0..steps {
+                let next = rk4(&self.derivative, state, self.step);
+                if !next.0.is_finite() || !next.1.is_finite() {
+                    break;
+                }
+                canvas.line(state.0, state.1, next.0, next.1);
+                state = next;
+            }
+        }
+        canvas.set_color(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AxisMode, Decorations, Domain, Plot, Size};
+
+    #[test]
+    fn trajectory_for_the_duration() {
+        // A marker on the initial condition, followed for 1.5 units of time.
+        let portrait = PhasePortrait::new(|_| (1.0, 0.0), vec![(-1.0, 0.0)]).with_duration(1.5);
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(-2.0..2.0))
+            .set_codomain(Domain(-2.0..2.0))
+            .set_size(Size::new(16, 8))
+            .set_axis_mode(AxisMode::None)
+            .set_decorations(Decorations::NONE)
+            .add_plot(Box::new(portrait));
+        assert_eq!(plot.try_render().unwrap(), " ⡤⠤⡄    \n ⠧⠭⠏⠉⠁  \n");
+    }
+}
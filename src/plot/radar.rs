@@ -0,0 +1,177 @@
+use super::dotted_line;
+use crate::{Align, DrawView, LegendEntry, View, ViewCanvas};
+use std::f64::consts::TAU;
+
+/// A radar (or spider) chart: an axis for each variable radiating from the center of the view,
+/// and a polygon connecting the values of each series.
+///
+/// The first axis points up and the others follow clockwise. Values are scaled so that the
+/// largest value (or the maximum given with [`Radar::with_max`]) reaches the end of the axis,
+/// which is drawn close to the edges of the view. Dotted rings mark half and the whole of the
+/// scale.
+///
+/// The chart is as large as the view allows, with the same radius on both axis in the units of the
+/// axis: use [`Plot::set_aspect`](crate::Plot::set_aspect) with
+/// [`Aspect::Equal`](crate::Aspect::Equal) to keep it round, and
+/// [`AxisMode::None`](crate::AxisMode::None) to hide the axis of the plot.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let radar = plot::Radar::new(vec![
+///     ("speed".to_string(), 7.0),
+///     ("power".to_string(), 5.0),
+///     ("range".to_string(), 9.0),
+///     ("comfort".to_string(), 4.0),
+///     ("price".to_string(), 6.0),
+/// ])
+/// .with_series("model B", vec![5.0, 8.0, 4.0, 7.0, 5.0])
+/// .with_max(10.0);
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-1.0..1.0))
+///     .set_codomain(Domain(-1.0..1.0))
+///     .set_aspect(Aspect::Equal)
+///     .set_axis_mode(AxisMode::None)
+///     .set_size(Size::new(60, 60))
+///     .add_plot(Box::new(radar));
+///
+/// println!("{plot}");
+/// ```
+pub struct Radar {
+    axes: Vec<String>,
+    series: Vec<(String, Vec<f64>)>,
+    max: Option<f64>,
+}
+
+impl Radar {
+    /// Create a new radar chart from the name of each axis and the value of the first series on
+    /// that axis.
+    pub fn new(values: Vec<(String, f64)>) -> Self {
+        let (axes, values) = values.into_iter().unzip();
+        Self {
+            axes,
+            series: vec![(String::from("series 1"), values)],
+            max: None,
+        }
+    }
+
+    /// Overlay another series, with a value for each axis in order. Each series is drawn with a
+    /// color of the palette of the theme and, when there is more than one series, is shown in the
+    /// legend with its name.
+    pub fn with_series(mut self, name: &str, values: Vec<f64>) -> Self {
+        self.series.push((String::from(name), values));
+        self
+    }
+
+    /// Set the value reaching the end of the axis. By default, it is the largest value.
+    pub fn with_max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    fn max(&self) -> f64 {
+        self.max.unwrap_or_else(|| {
+            self.series
+                .iter()
+                .flat_map(|(_, values)| values.iter().copied())
+                .fold(f64::MIN_POSITIVE, f64::max)
+        })
+    }
+}
+
+impl DrawView for Radar {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let count = self.axes.len();
+        if count == 0 {
+            return;
+        }
+        let center = (
+            (view.domain.min() + view.domain.max()) / 2.0,
+            (view.codomain.min() + view.codomain.max()) / 2.0,
+        );
+        // The end of the axes leave room for their names.
+        let radius = (view.domain.range() * 0.4).min(view.codomain.range() * 0.4);
+        let point = |index: usize, fraction: f64| {
+            let (x, y) = direction(index, count);
+            (
+                center.0 + x * radius * fraction,
+                center.1 + y * radius * fraction,
+            )
+        };
+
+        for ring in [0.5, 1.0] {
+            for index in 0..count {
+                dotted_line(view, canvas, point(index, ring), point(index + 1, ring));
+            }
+        }
+        for (index, name) in self.axes.iter().enumerate() {
+            let (x, y) = point(index, 1.0);
+            canvas.line(center.0, center.1, x, y);
+            let (x, y) = point(index, 1.15);
+            canvas.text(x, y, name, Align::Center);
+        }
+
+        let max = self.max();
+        for (series, (_, values)) in self.series.iter().enumerate() {
+            canvas.set_color(view.series_color(series));
+            let vertices = (0..count)
+                .map(|index| point(index, values.get(index).copied().unwrap_or(0.0) / max))
+                .collect::<Vec<_>>();
+            canvas.polygon(&vertices);
+        }
+    }
+
+    fn legend(&self, view: &View) -> Vec<LegendEntry> {
+        if self.series.len() < 2 {
+            return Vec::new();
+        }
+        self.series
+            .iter()
+            .enumerate()
+            .map(|(index, (name, _))| LegendEntry::new(name, view.series_color(index)))
+            .collect()
+    }
+}
+
+/// The direction of the axis at `index` out of `count` axes, starting up and going clockwise.
+fn direction(index: usize, count: usize) -> (f64, f64) {
+    let angle = TAU * index as f64 / count as f64;
+    (angle.sin(), angle.cos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Aspect, AxisMode, Domain, Plot, Size};
+
+    #[test]
+    fn axes_go_clockwise_from_the_top() {
+        let close = |(x0, y0): (f64, f64), (x1, y1): (f64, f64)| {
+            (x0 - x1).abs() < 1e-9 && (y0 - y1).abs() < 1e-9
+        };
+        assert!(close(direction(0, 4), (0.0, 1.0)));
+        assert!(close(direction(1, 4), (1.0, 0.0)));
+        assert!(close(direction(2, 4), (0.0, -1.0)));
+        assert!(close(direction(4, 4), (0.0, 1.0)));
+    }
+
+    #[test]
+    fn round_chart() {
+        let axes = (0..4).map(|_| (String::new(), 1.0)).collect();
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(-1.0..1.0))
+            .set_codomain(Domain(-1.0..1.0))
+            .set_aspect(Aspect::Equal)
+            .set_axis_mode(AxisMode::None)
+            .set_size(Size::new(80, 40))
+            .add_plot(Box::new(Radar::new(axes)));
+        let pixels = plot.view.pixels().unwrap();
+        let rows = pixels.iter().filter(|row| row.contains(&true)).count();
+        let columns = (0..80).filter(|&x| pixels.iter().any(|row| row[x])).count();
+        // The axes are as long horizontally as vertically.
+        assert!(rows.abs_diff(columns) <= 1, "{columns} by {rows}");
+    }
+}
@@ -0,0 +1,92 @@
+use super::shade_between;
+use crate::{Color, DrawView, View, ViewCanvas};
+
+/// A shaded band between a lower and an upper curve, like a confidence interval.
+///
+/// Only the band is drawn: add a [`Lines`] of the mean (or the estimate) after the ribbon to
+/// draw it over the band.
+///
+/// [`Lines`]: crate::plot::Lines
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let points = (0..50).map(|x| x as f64 / 5.0).collect::<Vec<_>>();
+/// let band = points
+///     .iter()
+///     .map(|&x| (x, x.sin() - 0.1 - x / 40.0, x.sin() + 0.1 + x / 40.0))
+///     .collect();
+/// let mean = points.iter().map(|&x| (x, x.sin())).collect();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..10.0))
+///     .set_codomain(Domain(-1.5..1.5))
+///     .set_size(Size::new(60, 25))
+///     .add_plot(Box::new(plot::Ribbon::new(band)))
+///     .add_plot(Box::new(plot::Lines::new(mean)));
+///
+/// println!("{plot}");
+/// ```
+pub struct Ribbon {
+    x: Vec<f64>,
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    level: f64,
+    color: Option<Color>,
+}
+
+impl Ribbon {
+    /// Create a new ribbon from the `(x, lower, upper)` bounds of the band at each x. The points
+    /// do not need to be sorted.
+    pub fn new(mut points: Vec<(f64, f64, f64)>) -> Self {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut ribbon = Self {
+            x: Vec::new(),
+            lower: Vec::new(),
+            upper: Vec::new(),
+            level: 0.5,
+            color: None,
+        };
+        for (x, lower, upper) in points {
+            ribbon.x.push(x);
+            ribbon.lower.push(lower);
+            ribbon.upper.push(upper);
+        }
+        ribbon
+    }
+
+    /// Set the proportion of the pixels of the band that are set, from `0.0` to `1.0`. By
+    /// default, half of the pixels are set so that a line drawn over the band stays visible.
+    pub fn with_level(mut self, level: f64) -> Self {
+        self.level = level.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Shade the band with `color`. By default, the default color of the terminal is used.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl DrawView for Ribbon {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        canvas.set_color(self.color);
+        shade_between(view, canvas, &self.x, &self.lower, &self.upper, self.level);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_bounds() {
+        let ribbon = Ribbon::new(vec![(2.0, 1.0, 3.0), (0.0, -1.0, 1.0), (1.0, 0.0, 2.0)]);
+        assert_eq!(ribbon.x, vec![0.0, 1.0, 2.0]);
+        assert_eq!(ribbon.lower, vec![-1.0, 0.0, 1.0]);
+        assert_eq!(ribbon.upper, vec![1.0, 2.0, 3.0]);
+    }
+}
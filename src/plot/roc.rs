@@ -0,0 +1,215 @@
+use super::dotted_line;
+use crate::{DrawView, View, ViewCanvas};
+
+/// A [ROC curve](https://en.wikipedia.org/wiki/Receiver_operating_characteristic) of a binary
+/// classifier.
+///
+/// The curve is computed from the scores given by the classifier and the true labels. The
+/// diagonal of a random classifier is drawn as a dotted reference line.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let scores = vec![0.9, 0.8, 0.7, 0.6, 0.55, 0.54, 0.53, 0.52, 0.51, 0.505];
+/// let labels = vec![true, true, false, true, true, true, false, false, true, false];
+///
+/// let roc = plot::Roc::new(scores, labels);
+/// println!("AUC: {:.3}", roc.auc());
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..1.0))
+///     .set_codomain(Domain(0.0..1.0))
+///     .set_title("ROC curve")
+///     .set_x_label("False positive rate")
+///     .set_y_label("True positive rate")
+///     .set_size(Size::new(50, 50))
+///     .add_plot(Box::new(roc));
+///
+/// println!("{plot}");
+/// ```
+pub struct Roc {
+    points: Vec<(f64, f64)>,
+    auc: f64,
+}
+
+impl Roc {
+    /// Create a new ROC curve from the scores of a classifier and the true labels.
+    ///
+    /// A higher score means the classifier is more confident that the sample is positive. Each
+    /// score must have a corresponding label.
+    pub fn new(scores: Vec<f64>, labels: Vec<bool>) -> Self {
+        let positives = labels.iter().filter(|&&label| label).count().max(1) as f64;
+        let negatives = labels.iter().filter(|&&label| !label).count().max(1) as f64;
+        let points = confusion_counts(&scores, &labels)
+            .into_iter()
+            .map(|(tp, fp)| (fp / negatives, tp / positives))
+            .collect::<Vec<_>>();
+        let auc = trapezoid(&points);
+        Self { points, auc }
+    }
+
+    /// The area under the curve.
+    pub fn auc(&self) -> f64 {
+        self.auc
+    }
+
+    /// The points of the curve as `(false positive rate, true positive rate)`.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+}
+
+impl DrawView for Roc {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        dotted_line(view, canvas, (0.0, 0.0), (1.0, 1.0));
+        draw_curve(&self.points, canvas);
+    }
+}
+
+/// A [precision-recall curve](https://en.wikipedia.org/wiki/Precision_and_recall) of a binary
+/// classifier.
+///
+/// The curve is computed from the scores given by the classifier and the true labels. The
+/// baseline of a random classifier (the proportion of positive samples) is drawn as a dotted
+/// reference line.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let scores = vec![0.9, 0.8, 0.7, 0.6, 0.55, 0.54, 0.53, 0.52, 0.51, 0.505];
+/// let labels = vec![true, true, false, true, true, true, false, false, true, false];
+///
+/// let pr = plot::PrecisionRecall::new(scores, labels);
+/// println!("AUC: {:.3}", pr.auc());
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..1.0))
+///     .set_codomain(Domain(0.0..1.0))
+///     .set_title("Precision-recall curve")
+///     .set_x_label("Recall")
+///     .set_y_label("Precision")
+///     .set_size(Size::new(50, 50))
+///     .add_plot(Box::new(pr));
+///
+/// println!("{plot}");
+/// ```
+pub struct PrecisionRecall {
+    points: Vec<(f64, f64)>,
+    auc: f64,
+    baseline: f64,
+}
+
+impl PrecisionRecall {
+    /// Create a new precision-recall curve from the scores of a classifier and the true labels.
+    ///
+    /// A higher score means the classifier is more confident that the sample is positive. Each
+    /// score must have a corresponding label.
+    pub fn new(scores: Vec<f64>, labels: Vec<bool>) -> Self {
+        let positives = labels.iter().filter(|&&label| label).count();
+        let baseline = positives as f64 / labels.len().max(1) as f64;
+        let counts = confusion_counts(&scores, &labels);
+        let mut points = vec![(0.0, 1.0)];
+        points.extend(
+            counts
+                .into_iter()
+                .skip(1)
+                .map(|(tp, fp)| (tp / positives.max(1) as f64, tp / (tp + fp))),
+        );
+        let auc = trapezoid(&points);
+        Self {
+            points,
+            auc,
+            baseline,
+        }
+    }
+
+    /// The area under the curve.
+    pub fn auc(&self) -> f64 {
+        self.auc
+    }
+
+    /// The points of the curve as `(recall, precision)`.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+}
+
+impl DrawView for PrecisionRecall {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        dotted_line(view, canvas, (0.0, self.baseline), (1.0, self.baseline));
+        draw_curve(&self.points, canvas);
+    }
+}
+
+/// Count the true positives and false positives for each distinct threshold, from the highest
+/// score to the lowest.
+///
+/// The first entry is always `(0, 0)` (nothing is classified as positive). Samples with the same
+/// score are grouped together since they cannot be separated by a threshold.
+fn confusion_counts(scores: &[f64], labels: &[bool]) -> Vec<(f64, f64)> {
+    let mut samples = scores
+        .iter()
+        .copied()
+        .zip(labels.iter().copied())
+        .filter(|(score, _)| !score.is_nan())
+        .collect::<Vec<_>>();
+    samples.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut counts = vec![(0.0, 0.0)];
+    let (mut tp, mut fp) = (0.0, 0.0);
+    for (index, (score, label)) in samples.iter().enumerate() {
+        match label {
+            true => tp += 1.0,
+            false => fp += 1.0,
+        }
+        let is_last_of_group = samples.get(index + 1).is_none_or(|(next, _)| next != score);
+        if is_last_of_group {
+            counts.push((tp, fp));
+        }
+    }
+    counts
+}
+
+/// Area under a curve using the trapezoidal rule.
+fn trapezoid(points: &[(f64, f64)]) -> f64 {
+    points
+        .windows(2)
+        .map(|line| (line[1].0 - line[0].0) * (line[0].1 + line[1].1) / 2.0)
+        .sum()
+}
+
+fn draw_curve(points: &[(f64, f64)], canvas: &mut ViewCanvas) {
+    points.windows(2).for_each(|line| {
+        canvas.line(line[0].0, line[0].1, line[1].0, line[1].1);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perfect_classifier() {
+        let roc = Roc::new(vec![0.9, 0.8, 0.2, 0.1], vec![true, true, false, false]);
+        assert_eq!(roc.auc(), 1.0);
+        let pr = PrecisionRecall::new(vec![0.9, 0.8, 0.2, 0.1], vec![true, true, false, false]);
+        assert_eq!(pr.auc(), 1.0);
+    }
+
+    #[test]
+    fn ties_are_grouped() {
+        let roc = Roc::new(vec![0.5, 0.5, 0.5, 0.5], vec![true, false, true, false]);
+        assert_eq!(roc.points(), &[(0.0, 0.0), (1.0, 1.0)]);
+        assert_eq!(roc.auc(), 0.5);
+    }
+
+    #[test]
+    fn partially_ordered() {
+        let roc = Roc::new(vec![0.9, 0.8, 0.7, 0.6], vec![true, false, true, false]);
+        assert_eq!(roc.auc(), 0.75);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{Domain, DrawView, View, ViewCanvas};
+use std::collections::VecDeque;
+
+/// A line going through the last samples pushed, for live monitors.
+///
+/// Only the last `capacity` samples are kept. The x coordinate of a sample is its index since the
+/// first sample was pushed, and the domain of the plot is shifted to show the last `capacity`
+/// samples (see [`DrawView::domain`]), so the newest sample is always on the right of the view.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut series = plot::RollingSeries::new(60);
+/// let mut renderer = Renderer::new(std::io::stdout());
+/// for tick in 0..100 {
+///     series.push((tick as f64 / 5.0).sin() * 50.0 + 50.0);
+///
+///     let mut plot = Plot::default();
+///     plot.set_codomain(Domain(0.0..100.0))
+///         .set_title("CPU usage")
+///         .set_size(Size::new(60, 20))
+///         .add_plot(Box::new(series.clone()));
+///     renderer.draw(&plot.to_string()).unwrap();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RollingSeries {
+    values: VecDeque<f64>,
+    capacity: usize,
+    /// The number of samples pushed since the creation of the series.
+    pushed: usize,
+}
+
+impl RollingSeries {
+    /// Create a new series keeping the last `capacity` samples (at least one).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            values: VecDeque::with_capacity(capacity),
+            capacity,
+            pushed: 0,
+        }
+    }
+
+    /// Add a sample, dropping the oldest sample when the series is full.
+    pub fn push(&mut self, value: f64) {
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value);
+        self.pushed += 1;
+    }
+
+    /// The samples kept, from the oldest to the newest.
+    pub fn values(&self) -> &VecDeque<f64> {
+        &self.values
+    }
+
+    /// The index of the oldest sample kept.
+    fn first_index(&self) -> usize {
+        self.pushed - self.values.len()
+    }
+}
+
+impl DrawView for RollingSeries {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        match self.points(view).as_slice() {
+            [(x, y)] => canvas.point(*x, *y),
+            points => points.windows(2).for_each(|line| {
+                canvas.line(line[0].0, line[0].1, line[1].0, line[1].1);
+            }),
+        }
+    }
+
+    /// The samples kept, at the index where they were pushed.
+    fn points(&self, _: &View) -> Vec<(f64, f64)> {
+        let first = self.first_index() as f64;
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(index, &value)| (first + index as f64, value))
+            .collect()
+    }
+
+    fn domain(&self) -> Option<Domain> {
+        let first = self.first_index();
+        let last = first + self.capacity.max(2) - 1;
+        Some(Domain(first as f64..last as f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn domain_follows_the_last_samples() {
+        let mut series = RollingSeries::new(3);
+        series.push(1.0);
+        assert_eq!(series.domain(), Some(Domain(0.0..2.0)));
+        for value in [2.0, 3.0, 4.0, 5.0] {
+            series.push(value);
+        }
+        assert_eq!(series.values(), &[3.0, 4.0, 5.0]);
+        assert_eq!(series.domain(), Some(Domain(2.0..4.0)));
+    }
+}
@@ -0,0 +1,112 @@
+use super::{dotted_line, marker};
+use crate::{DrawView, View, ViewCanvas};
+
+/// A [scree plot](https://en.wikipedia.org/wiki/Scree_plot), also known as an elbow plot.
+///
+/// The values (explained variance of each principal component, inertia for each number of
+/// clusters, etc...) are sorted from the largest to the smallest and plotted against their rank,
+/// starting at 1. The knee of the curve (the point after which adding components stops
+/// explaining much) is detected and marked with a dotted vertical line.
+///
+/// The knee is the point that is the farthest from the straight line between the first and the
+/// last point, once both axis are normalized (see
+/// [Kneedle](https://raghavan.usc.edu/papers/kneedle-simplex11.pdf)).
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let scree = plot::Scree::new(vec![4.2, 2.1, 0.9, 0.5, 0.4, 0.3, 0.25, 0.2]);
+/// assert_eq!(scree.knee(), Some(2));
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..9.0))
+///     .set_codomain(Domain(0.0..5.0))
+///     .set_title("Explained variance")
+///     .set_x_label("Component")
+///     .set_size(Size::new(50, 25))
+///     .add_plot(Box::new(scree));
+///
+/// println!("{plot}");
+/// ```
+pub struct Scree {
+    values: Vec<f64>,
+    knee: Option<usize>,
+}
+
+impl Scree {
+    /// Create a new scree plot. The values do not need to be sorted.
+    pub fn new(mut values: Vec<f64>) -> Self {
+        values.retain(|value| value.is_finite());
+        values.sort_by(|a, b| b.total_cmp(a));
+        let knee = find_knee(&values);
+        Self { values, knee }
+    }
+
+    /// The index of the knee in the sorted values, if there is one. The knee is drawn at the
+    /// rank `index + 1`.
+    pub fn knee(&self) -> Option<usize> {
+        self.knee
+    }
+
+    /// The values, sorted from the largest to the smallest.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+}
+
+impl DrawView for Scree {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let points = self
+            .values
+            .iter()
+            .enumerate()
+            .map(|(index, &value)| (index as f64 + 1.0, value))
+            .collect::<Vec<_>>();
+        points.windows(2).for_each(|line| {
+            canvas.line(line[0].0, line[0].1, line[1].0, line[1].1);
+        });
+        points.iter().for_each(|&(x, y)| marker(view, canvas, x, y));
+        if let Some(&(x, y)) = self.knee.and_then(|index| points.get(index)) {
+            let from = (x, view.codomain.min());
+            dotted_line(view, canvas, from, (x, view.codomain.max()));
+            marker(view, canvas, x, y);
+        }
+    }
+}
+
+/// Find the point of a decreasing curve that is the farthest below the line going from the first
+/// point to the last point, once both axis are normalized to `0..1`.
+fn find_knee(values: &[f64]) -> Option<usize> {
+    let (first, last) = (*values.first()?, *values.last()?);
+    if values.len() < 3 || first <= last {
+        return None;
+    }
+    let steps = (values.len() - 1) as f64;
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let x = index as f64 / steps;
+            let y = (value - last) / (first - last);
+            // The line goes from (0, 1) to (1, 0), so `1 - x` is its height at x.
+            (index, (1.0 - x) - y)
+        })
+        .filter(|&(_, distance)| distance > 1e-9)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knee() {
+        assert_eq!(find_knee(&[10.0, 3.0, 2.0, 1.5, 1.0]), Some(1));
+        assert_eq!(find_knee(&[4.0, 3.0, 2.0, 1.0]), None);
+        assert_eq!(find_knee(&[1.0, 1.0]), None);
+        assert_eq!(Scree::new(vec![1.0, 10.0, 1.5, 3.0, 2.0]).knee(), Some(1));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{DrawView, View, ViewCanvas};
+
+/// A [slope field](https://en.wikipedia.org/wiki/Slope_field) of a first-order differential
+/// equation `dy/dx = f(x, y)`: a short segment with the slope `f(x, y)` at each point of a grid
+/// across the view.
+///
+/// By default, the points of the grid are 8 pixels apart. The segments all have the same length
+/// on the screen. Points where the slope is not a number are skipped, and infinite slopes are
+/// drawn vertically. Add a [`Graph`](super::Graph) to draw a solution along the field.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-3.0..3.0))
+///     .set_codomain(Domain(-3.0..3.0))
+///     .set_title("dy/dx = x - y")
+///     .set_size(Size::new(100, 48))
+///     .add_plot(Box::new(plot::SlopeField::new(|x, y| x - y)))
+///     .add_plot(Box::new(plot::Graph::new(|x| x - 1.0 + 2.0 * (-x).exp())));
+///
+/// println!("{plot}");
+/// ```
+pub struct SlopeField<F> {
+    slope: F,
+    spacing: usize,
+}
+
+impl<F> SlopeField<F>
+where
+    F: Fn(f64, f64) -> f64,
+{
+    /// Create a new slope field where `slope(x, y)` is the slope `dy/dx` at `(x, y)`.
+    pub fn new(slope: F) -> Self {
+        Self { slope, spacing: 8 }
+    }
+
+    /// Set the distance between two points of the grid, in pixels.
+    pub fn with_spacing(mut self, spacing: usize) -> Self {
+        self.spacing = spacing.max(1);
+        self
+    }
+}
+
+impl<F> DrawView for SlopeField<F>
+where
+    F: Fn(f64, f64) -> f64,
+{
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        // The size of a pixel, in the units of each axis.
+        let dx = view.domain.range() / view.size.w as f64;
+        let dy = view.codomain.range() / view.size.h as f64;
+        // Segments take most of the space between two points of the grid.
+        let half_length = self.spacing as f64 * 0.35;
+        let offset = self.spacing as f64 / 2.0;
+        for row in 0..view.size.h / self.spacing {
+            let y = view.codomain.min() + (row as f64 * self.spacing as f64 + offset) * dy;
+            for column in 0..view.size.w / self.spacing {
+                let x = view.domain.min() + (column as f64 * self.spacing as f64 + offset) * dx;
+                let slope = (self.slope)(x, y);
+                // The direction of the segment, in pixels.
+                let (u, v): (f64, f64) = match slope {
+                    slope if slope.is_nan() => continue,
+                    slope if slope.is_infinite() => (0.0, 1.0),
+                    slope => (1.0, slope * dx / dy),
+                };
+                let scale = half_length / u.hypot(v);
+                let (u, v) = (u * scale * dx, v * scale * dy);
+                canvas.line(x - u, y - v, x + u, y + v);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AxisMode, Decorations, Domain, Plot, Size};
+
+    #[test]
+    fn segments_on_a_grid() {
+        fn render(field: impl DrawView + 'static) -> String {
+            let mut plot = Plot::default();
+            plot.set_domain(Domain(-2.0..2.0))
+                .set_codomain(Domain(-1.0..1.0))
+                .set_size(Size::new(16, 8))
+                .set_axis_mode(AxisMode::None)
+                .set_decorations(Decorations::NONE)
+                .add_plot(Box::new(field));
+            plot.try_render().unwrap()
+        }
+        // A segment in the middle of each square of 8 by 8 pixels, vertical where the slope is
+        // infinite and skipped where it is not a number.
+        let field = SlopeField::new(|x, _| match x < 0.0 {
+            true => 0.0,
+            false => f64::INFINITY,
+        });
+        assert_eq!(render(field), "      ⡆ \n⠈⠉⠉⠉  ⡇ \n");
+        let field = SlopeField::new(|x, _| if x < 0.0 { 1.0 } else { f64::NAN }).with_spacing(4);
+        assert_eq!(render(field), "⢀⠔⢀⠔    \n⢀⠔⢀⠔    \n");
+    }
+}
@@ -0,0 +1,152 @@
+use super::Heatmap;
+use crate::color::Colormap;
+use crate::{Domain, DrawView, View, ViewCanvas};
+
+/// A [spectrogram](https://en.wikipedia.org/wiki/Spectrogram): the intensity of each frequency
+/// over time, drawn like a [`Heatmap`] with the time on the x axis and the frequency on the y
+/// axis.
+///
+/// By default, each spectrum is 1 unit wide and each frequency bin 1 unit tall, so
+/// `spectra[time][bin]` covers `time..time + 1` on the x axis and `bin..bin + 1` on the y axis.
+/// Use [`Spectrogram::with_extent`] to use seconds and hertz instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// // A chirp: a single frequency rising over time.
+/// let spectra = (0..60)
+///     .map(|time| {
+///         (0..32)
+///             .map(|bin| 1.0 / (1.0 + (bin as f64 - time as f64 / 2.0).powi(2)))
+///             .collect()
+///     })
+///     .collect();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..3.0))
+///     .set_codomain(Domain(0.0..8000.0))
+///     .set_title("Chirp")
+///     .set_x_label("Time (s)")
+///     .set_y_label("Frequency (Hz)")
+///     .set_size(Size::new(120, 48))
+///     .add_plot(Box::new(
+///         plot::Spectrogram::new(spectra)
+///             .with_extent(Domain(0.0..3.0), Domain(0.0..8000.0))
+///             .with_decibels()
+///             .with_colormap(color::Colormap::Magma),
+///     ));
+///
+/// println!("{plot}");
+/// ```
+pub struct Spectrogram {
+    spectra: Vec<Vec<f64>>,
+    extent: Option<(Domain, Domain)>,
+    decibels: bool,
+    colormap: Option<Colormap>,
+}
+
+impl Spectrogram {
+    /// Create a new spectrogram from spectra ordered by time. `spectra[time][bin]` is the
+    /// intensity (for example the power) of a frequency bin at a time.
+    pub fn new(spectra: Vec<Vec<f64>>) -> Self {
+        Self {
+            spectra,
+            extent: None,
+            decibels: false,
+            colormap: None,
+        }
+    }
+
+    /// Stretch the spectra so that they cover `time` on the x axis and `frequency` on the y axis.
+    pub fn with_extent(mut self, time: Domain, frequency: Domain) -> Self {
+        self.extent = Some((time, frequency));
+        self
+    }
+
+    /// Shade the intensities on a logarithmic scale, in decibels (`10 log10(intensity)`), so that
+    /// quiet frequencies stay visible next to loud ones. Intensities that are not positive are
+    /// not drawn.
+    pub fn with_decibels(mut self) -> Self {
+        self.decibels = true;
+        self
+    }
+
+    /// Color the intensities with `colormap` instead of shading them. See
+    /// [`Heatmap::with_colormap`].
+    pub fn with_colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = Some(colormap);
+        self
+    }
+
+    /// The heatmap drawing the spectra, with a row for each frequency bin and a column for each
+    /// spectrum.
+    fn heatmap(&self) -> Heatmap {
+        let bins = self.spectra.iter().map(Vec::len).max().unwrap_or(0);
+        let values = (0..bins)
+            .map(|bin| {
+                self.spectra
+                    .iter()
+                    .map(|spectrum| {
+                        let intensity = spectrum.get(bin).copied().unwrap_or(f64::NAN);
+                        match self.decibels {
+                            true if intensity > 0.0 => 10.0 * intensity.log10(),
+                            true => f64::NAN,
+                            false => intensity,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let heatmap = Heatmap::new(values);
+        let heatmap = match &self.extent {
+            Some((time, frequency)) => heatmap.with_extent(time.clone(), frequency.clone()),
+            None => heatmap,
+        };
+        match self.colormap {
+            Some(colormap) => heatmap.with_colormap(colormap),
+            None => heatmap,
+        }
+    }
+}
+
+impl DrawView for Spectrogram {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        self.heatmap().draw(view, canvas);
+    }
+
+    fn colorbar(&self) -> Option<(Colormap, Domain)> {
+        self.heatmap().colorbar()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AxisMode, Decorations, Plot, Size};
+
+    #[test]
+    fn decibels() {
+        // A column for each spectrum, with the low frequencies at the bottom. The intensity of 0
+        // is not drawn.
+        let spectrogram = Spectrogram::new(vec![vec![1.0, 100.0], vec![10.0, 0.0]])
+            .with_decibels()
+            .with_colormap(Colormap::Viridis);
+        assert_eq!(
+            spectrogram.colorbar(),
+            Some((Colormap::Viridis, Domain(0.0..20.0)))
+        );
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..3.0))
+            .set_codomain(Domain(-1.0..2.0))
+            .set_size(Size::new(6, 12))
+            .set_axis_mode(AxisMode::None)
+            .set_decorations(Decorations::NONE)
+            .add_plot(Box::new(spectrogram));
+        assert_eq!(
+            plot.try_render().unwrap(),
+            "⣿   ██ 20.0\n⣿⣿  ██     \n    ██ 0.0 \n"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{DrawView, View, ViewCanvas};
+use std::f64::consts::TAU;
+
+/// The amplitude spectrum of a signal: the amplitude of each frequency, from 0 Hz to half of the
+/// sample rate, computed with a fast Fourier transform.
+///
+/// The signal is padded with zeros to a power of two samples. The amplitudes are those of the
+/// sinusoids making up the signal: a sine wave of amplitude `a` gives a peak of height `a` at its
+/// frequency. Use [`Spectrum::with_decibels`] to show quiet frequencies next to loud ones, and
+/// [`Plot::set_x_scale`](crate::Plot::set_x_scale) for a logarithmic frequency axis.
+///
+/// Requires the `dsp` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+/// use std::f64::consts::TAU;
+///
+/// let sample_rate = 1000.0;
+/// let samples = (0..1000)
+///     .map(|index| {
+///         let time = index as f64 / sample_rate;
+///         (TAU * 50.0 * time).sin() + 0.5 * (TAU * 120.0 * time).sin()
+///     })
+///     .collect::<Vec<_>>();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..500.0))
+///     .set_codomain(Domain(0.0..1.2))
+///     .set_title("Spectrum")
+///     .set_x_label("Frequency (Hz)")
+///     .set_size(Size::new(100, 40))
+///     .add_plot(Box::new(plot::Spectrum::from_samples(&samples, sample_rate)));
+///
+/// println!("{plot}");
+/// ```
+pub struct Spectrum {
+    /// The `(frequency, amplitude)` of each bin.
+    points: Vec<(f64, f64)>,
+}
+
+impl Spectrum {
+    /// Compute the spectrum of `samples`, taken `sample_rate` times per second.
+    pub fn from_samples(samples: &[f64], sample_rate: f64) -> Self {
+        let size = samples.len().max(1).next_power_of_two();
+        let mut values = samples
+            .iter()
+            .map(|&sample| (sample, 0.0))
+            .collect::<Vec<_>>();
+        values.resize(size, (0.0, 0.0));
+        fft(&mut values);
+        let count = samples.len().max(1) as f64;
+        let points = values[..=size / 2]
+            .iter()
+            .enumerate()
+            .map(|(bin, &(re, im))| {
+                // The other half of the spectrum mirrors this one, except for 0 Hz and for half
+                // of the sample rate.
+                let factor = if bin == 0 || bin == size / 2 {
+                    1.0
+                } else {
+                    2.0
+                };
+                let amplitude = factor * re.hypot(im) / count;
+                (bin as f64 * sample_rate / size as f64, amplitude)
+            })
+            .collect();
+        Self { points }
+    }
+
+    /// Show the amplitudes in decibels (`20 log10(amplitude)`), where an amplitude of `1.0` is
+    /// 0 dB.
+    pub fn with_decibels(mut self) -> Self {
+        for (_, amplitude) in self.points.iter_mut() {
+            *amplitude = 20.0 * amplitude.log10();
+        }
+        self
+    }
+
+    /// The `(frequency, amplitude)` of each frequency bin, from 0 Hz to half of the sample rate.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+}
+
+impl DrawView for Spectrum {
+    fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+        for pair in self.points.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            if y0.is_finite() && y1.is_finite() {
+                canvas.line(x0, y0, x1, y1);
+            }
+        }
+    }
+}
+
+/// Replace `values`, made of `(real, imaginary)` parts, with their discrete Fourier transform.
+/// The number of values must be a power of two.
+fn fft(values: &mut [(f64, f64)]) {
+    let size = values.len();
+    // Reorder the values by the bit reversal of their index.
+    let mut j = 0;
+    for i in 1..size {
+        let mut bit = size >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+    let mut length = 2;
+    while length <= size {
+        let angle = -TAU / length as f64;
+        for start in (0..size).step_by(length) {
+            for k in 0..length / 2 {
+                let (sin, cos) = (angle * k as f64).sin_cos();
+                let (re, im) = values[start + k + length / 2];
+                let odd = (re * cos - im * sin, re * sin + im * cos);
+                let even = values[start + k];
+                values[start + k] = (even.0 + odd.0, even.1 + odd.1);
+                values[start + k + length / 2] = (even.0 - odd.0, even.1 - odd.1);
+            }
+        }
+        length <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peaks_at_the_frequency_of_a_sine() {
+        let samples = (0..64)
+            .map(|index| 0.5 + 2.0 * (TAU * 8.0 * index as f64 / 64.0).sin())
+            .collect::<Vec<_>>();
+        let spectrum = Spectrum::from_samples(&samples, 64.0);
+        let points = spectrum.points();
+        assert_eq!(points.len(), 33);
+        assert!((points[0].1 - 0.5).abs() < 1e-9);
+        assert_eq!(points[8].0, 8.0);
+        assert!((points[8].1 - 2.0).abs() < 1e-9);
+        assert!(points
+            .iter()
+            .enumerate()
+            .all(|(bin, &(_, amplitude))| bin == 0 || bin == 8 || amplitude < 1e-9));
+    }
+}
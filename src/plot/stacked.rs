@@ -0,0 +1,155 @@
+use crate::{Color, DrawView, LegendEntry, View, ViewCanvas};
+
+/// A [stacked area chart](https://en.wikipedia.org/wiki/Area_chart). The series are added on top
+/// of each other and the band between two successive cumulative curves is filled.
+///
+/// Each band is drawn with a color of the palette of the theme, in order, and its name is shown in
+/// the legend of the plot. The values are expected to be positive, the first series is drawn at the
+/// bottom.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let x = (0..24).map(|hour| hour as f64).collect::<Vec<_>>();
+/// let ys = vec![
+///     x.iter().map(|x| 20.0 + 10.0 * (x / 4.0).sin()).collect(),
+///     x.iter().map(|x| 15.0 + x).collect(),
+///     vec![10.0; 24],
+/// ];
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..23.0))
+///     .set_codomain(Domain(0.0..100.0))
+///     .set_title("CPU usage")
+///     .set_size(Size::new(50, 25))
+///     .add_plot(Box::new(
+///         plot::StackedArea::new(x, ys).with_names(vec!["firefox", "cargo", "other"]),
+///     ));
+///
+/// println!("{plot}");
+/// ```
+pub struct StackedArea {
+    x: Vec<f64>,
+    /// The cumulative sums of the series: `cumulative[i]` is the top of the band `i`.
+    cumulative: Vec<Vec<f64>>,
+    names: Vec<String>,
+    colors: Vec<Color>,
+}
+
+impl StackedArea {
+    /// Create a new stacked area chart of the series of `ys`. The y values of each series are
+    /// matched with the values of `x` in order, which must be sorted. Extra values of either are
+    /// ignored.
+    pub fn new(x: Vec<f64>, ys: Vec<Vec<f64>>) -> Self {
+        let len = ys.iter().map(Vec::len).fold(x.len(), usize::min);
+        let mut total = vec![0.0; len];
+        let cumulative = ys
+            .iter()
+            .map(|y| {
+                total.iter_mut().zip(y).for_each(|(total, y)| *total += y);
+                total.clone()
+            })
+            .collect();
+        let names = (1..=ys.len())
+            .map(|index| format!("series {index}"))
+            .collect();
+        Self {
+            x: x[..len].to_vec(),
+            cumulative,
+            names,
+            colors: Vec::new(),
+        }
+    }
+
+    /// Name each series for the legend, in the same order as `ys`. Series without a name keep
+    /// their default name (`series 1`, `series 2`, etc...).
+    pub fn with_names(mut self, names: Vec<&str>) -> Self {
+        for (name, new_name) in self.names.iter_mut().zip(names) {
+            *name = String::from(new_name);
+        }
+        self
+    }
+
+    /// Use `colors` for the bands instead of the palette of the theme. The colors are reused in
+    /// order when there are more series than colors.
+    pub fn with_colors(mut self, colors: Vec<Color>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    fn color(&self, view: &View, index: usize) -> Option<Color> {
+        match self.colors.len() {
+            0 => view.series_color(index),
+            len => Some(self.colors[index % len]),
+        }
+    }
+}
+
+impl DrawView for StackedArea {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let (Some(&first), Some(&last)) = (self.x.first(), self.x.last()) else {
+            return;
+        };
+        let pixel_width = view.domain.range() / view.size.w as f64;
+        for (index, top) in self.cumulative.iter().enumerate() {
+            canvas.set_color(self.color(view, index));
+            // Every other band is filled more sparsely so that the bands can be told apart
+            // without colors.
+            let level = if index % 2 == 0 { 0.5 } else { 0.25 };
+            for column in 0..view.size.w {
+                let x = view.domain.min() + (column as f64 + 0.5) * pixel_width;
+                if x < first || x > last {
+                    continue;
+                }
+                let bottom = match index {
+                    0 => 0.0,
+                    _ => interpolate(&self.x, &self.cumulative[index - 1], x),
+                };
+                canvas.shade_rect(x, bottom, x, interpolate(&self.x, top, x), level);
+            }
+            self.x.windows(2).zip(top.windows(2)).for_each(|(x, y)| {
+                canvas.line(x[0], y[0], x[1], y[1]);
+            });
+        }
+    }
+
+    fn legend(&self, view: &View) -> Vec<LegendEntry> {
+        self.names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| LegendEntry::new(name, self.color(view, index)))
+            .collect()
+    }
+}
+
+/// The linear interpolation of the `(xs, ys)` curve at `x`. `xs` must be sorted and `x` must be
+/// between its first and last value.
+pub(crate) fn interpolate(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let index = xs.partition_point(|&value| value < x);
+    match index {
+        0 => ys[0],
+        index if index >= xs.len() => ys[xs.len() - 1],
+        index => {
+            let (x0, x1) = (xs[index - 1], xs[index]);
+            let (y0, y1) = (ys[index - 1], ys[index]);
+            match x1 - x0 {
+                width if width <= 0.0 => y1,
+                width => y0 + (y1 - y0) * (x - x0) / width,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cumulative() {
+        let area = StackedArea::new(vec![0.0, 1.0], vec![vec![1.0, 2.0], vec![3.0, 4.0, 5.0]]);
+        assert_eq!(area.cumulative, vec![vec![1.0, 2.0], vec![4.0, 6.0]]);
+        assert_eq!(interpolate(&area.x, &area.cumulative[1], 0.5), 5.0);
+    }
+}
@@ -0,0 +1,133 @@
+use super::{dotted_line, strip::quantile};
+use crate::{Align, DrawView, View, ViewCanvas};
+
+/// The summary statistics of samples drawn over the x axis: a line at the mean, a dotted line at
+/// the median and a shaded band one standard deviation around the mean, with small labels.
+///
+/// The overlay spans the whole height of the view, so it can be added over an [`Histogram`] of
+/// the same samples (or any plot with the samples on the x axis).
+///
+/// [`Histogram`]: crate::plot::Histogram
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let values = vec![2.1, 3.4, 3.9, 4.2, 4.8, 5.0, 5.3, 6.1, 6.6, 8.9];
+/// let stats = plot::StatsOverlay::new(&values);
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..10.0))
+///     .set_codomain(Domain(0.0..5.0))
+///     .set_size(Size::new(60, 25))
+///     .add_plot(Box::new(plot::Histogram::new_with_buckets_count(
+///         values.clone(),
+///         5,
+///     )))
+///     .add_plot(Box::new(stats));
+///
+/// println!("{plot}");
+/// ```
+pub struct StatsOverlay {
+    mean: f64,
+    median: f64,
+    std_dev: f64,
+}
+
+impl StatsOverlay {
+    /// Compute the statistics of `values`. Values that are not finite are ignored; nothing is
+    /// drawn when no value is left.
+    pub fn new(values: &[f64]) -> Self {
+        let mut sorted = values
+            .iter()
+            .copied()
+            .filter(|value| value.is_finite())
+            .collect::<Vec<_>>();
+        sorted.sort_by(f64::total_cmp);
+        if sorted.is_empty() {
+            return Self {
+                mean: f64::NAN,
+                median: f64::NAN,
+                std_dev: f64::NAN,
+            };
+        }
+        let count = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / count;
+        let variance = sorted
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        Self {
+            mean,
+            median: quantile(&sorted, 0.5),
+            std_dev: variance.sqrt(),
+        }
+    }
+
+    /// The mean of the values.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// The median of the values, interpolated between the two middle values when there is an
+    /// even number of values.
+    pub fn median(&self) -> f64 {
+        self.median
+    }
+
+    /// The (population) standard deviation of the values.
+    pub fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+}
+
+impl DrawView for StatsOverlay {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        if self.mean.is_nan() {
+            return;
+        }
+        let (bottom, top) = (view.codomain.min(), view.codomain.max());
+        let row_height = 4.0 * view.codomain.range() / view.size.h as f64;
+        let column_width = 2.0 * view.domain.range() / view.size.w as f64;
+        canvas.shade_rect(
+            self.mean - self.std_dev,
+            bottom,
+            self.mean + self.std_dev,
+            top,
+            0.25,
+        );
+        canvas.line(self.mean, bottom, self.mean, top);
+        dotted_line(view, canvas, (self.median, bottom), (self.median, top));
+        // The labels are on different rows so that they do not overlap when the mean and the
+        // median are close.
+        canvas.text(self.mean + column_width, top, "mean", Align::Left);
+        canvas.text(
+            self.median + column_width,
+            top - row_height,
+            "median",
+            Align::Left,
+        );
+        canvas.text(
+            self.mean + self.std_dev + column_width,
+            bottom + row_height,
+            "+1σ",
+            Align::Left,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let stats = StatsOverlay::new(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0, f64::NAN]);
+        assert_eq!(stats.mean(), 5.0);
+        assert_eq!(stats.median(), 4.5);
+        assert_eq!(stats.std_dev(), 2.0);
+        assert!(StatsOverlay::new(&[]).mean().is_nan());
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{DataSource, DrawView, View, ViewCanvas};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+/// A line going through the points of a [`DataSource`].
+///
+/// New points are pulled from the source each time the plot is drawn. All the points received so
+/// far are kept, unless a capacity is set with [`StreamLines::with_capacity`].
+///
+/// See [`ChannelSource`](crate::ChannelSource) for an example.
+pub struct StreamLines<S: DataSource> {
+    stream: RefCell<Stream<S>>,
+}
+
+impl<S: DataSource> StreamLines<S> {
+    /// Create a new line going through the points of `source`.
+    pub fn new(source: S) -> Self {
+        Self {
+            stream: RefCell::new(Stream::new(source)),
+        }
+    }
+
+    /// Only keep the last `capacity` points received.
+    pub fn with_capacity(self, capacity: usize) -> Self {
+        self.stream.borrow_mut().capacity = Some(capacity);
+        self
+    }
+}
+
+impl<S: DataSource> DrawView for StreamLines<S> {
+    fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+        let mut stream = self.stream.borrow_mut();
+        stream.poll();
+        let points = stream.points.make_contiguous();
+        points.windows(2).for_each(|line| {
+            canvas.line(line[0].0, line[0].1, line[1].0, line[1].1);
+        });
+    }
+}
+
+/// A scatter plot of the points of a [`DataSource`].
+///
+/// New points are pulled from the source each time the plot is drawn. All the points received so
+/// far are kept, unless a capacity is set with [`StreamScatter::with_capacity`].
+pub struct StreamScatter<S: DataSource> {
+    stream: RefCell<Stream<S>>,
+}
+
+impl<S: DataSource> StreamScatter<S> {
+    /// Create a new scatter plot of the points of `source`.
+    pub fn new(source: S) -> Self {
+        Self {
+            stream: RefCell::new(Stream::new(source)),
+        }
+    }
+
+    /// Only keep the last `capacity` points received.
+    pub fn with_capacity(self, capacity: usize) -> Self {
+        self.stream.borrow_mut().capacity = Some(capacity);
+        self
+    }
+}
+
+impl<S: DataSource> DrawView for StreamScatter<S> {
+    fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+        let mut stream = self.stream.borrow_mut();
+        stream.poll();
+        stream.points.iter().for_each(|&(x, y)| canvas.point(x, y));
+    }
+}
+
+/// The points received from a source.
+struct Stream<S> {
+    source: S,
+    points: VecDeque<(f64, f64)>,
+    capacity: Option<usize>,
+}
+
+impl<S: DataSource> Stream<S> {
+    fn new(source: S) -> Self {
+        Self {
+            source,
+            points: VecDeque::new(),
+            capacity: None,
+        }
+    }
+
+    /// Pull every chunk available from the source.
+    fn poll(&mut self) {
+        while let Some(chunk) = self.source.next_chunk() {
+            self.points.extend(chunk.iter().copied());
+        }
+        if let Some(capacity) = self.capacity {
+            let overflow = self.points.len().saturating_sub(capacity);
+            self.points.drain(..overflow);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Chunks(Vec<Vec<(f64, f64)>>, Vec<(f64, f64)>);
+
+    impl DataSource for Chunks {
+        fn next_chunk(&mut self) -> Option<&[(f64, f64)]> {
+            self.1 = self.0.pop()?;
+            Some(&self.1)
+        }
+    }
+
+    #[test]
+    fn capacity() {
+        let source = Chunks(vec![vec![(2.0, 2.0)], vec![(0.0, 0.0), (1.0, 1.0)]], vec![]);
+        let mut stream = Stream::new(source);
+        stream.capacity = Some(2);
+        stream.poll();
+        assert_eq!(stream.points, [(1.0, 1.0), (2.0, 2.0)]);
+        stream.poll();
+        assert_eq!(stream.points.len(), 2);
+    }
+}
@@ -0,0 +1,117 @@
+use super::rk4;
+use crate::{DrawView, View, ViewCanvas};
+
+/// The streamlines of a vector field: the curves following the direction of the field, going
+/// through seed points.
+///
+/// Each streamline is traced forward and backward from its seed, with the Runge-Kutta method,
+/// until it leaves the view, reaches a point where the field vanishes, or is as long as a few
+/// times the size of the view. Only the direction of the field is followed, so slow and fast
+/// regions are traced with the same precision. Not to be confused with
+/// [`StreamLines`](super::StreamLines), which draws streamed data.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// // A flow spiraling into the origin.
+/// let field = |x: f64, y: f64| (y, -x - 0.3 * y);
+/// let seeds = (-2..=2).map(|y| (-5.0, 2.0 * y as f64)).collect();
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-5.0..5.0))
+///     .set_codomain(Domain(-5.0..5.0))
+///     .set_aspect(Aspect::Equal)
+///     .set_size(Size::new(100, 50))
+///     .add_plot(Box::new(plot::Streamlines::new(field, seeds)));
+///
+/// println!("{plot}");
+/// ```
+pub struct Streamlines<F> {
+    field: F,
+    seeds: Vec<(f64, f64)>,
+}
+
+impl<F> Streamlines<F>
+where
+    F: Fn(f64, f64) -> (f64, f64),
+{
+    /// Create the streamlines of `field`, which gives the vector `(dx, dy)` of the field at
+    /// `(x, y)`, going through each of the `seeds`.
+    pub fn new(field: F, seeds: Vec<(f64, f64)>) -> Self {
+        Self { field, seeds }
+    }
+}
+
+impl<F> DrawView for Streamlines<F>
+where
+    F: Fn(f64, f64) -> (f64, f64),
+{
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let (width, height) = (view.domain.range(), view.codomain.range());
+        // The field is followed in the coordinates of the view, from 0 to 1 on both axis, so
+        // that a step is about a pixel long on both axis.
+        let step = 1.0 / view.size.w.max(view.size.h).max(1) as f64;
+        let max_steps = 4 * (view.size.w + view.size.h);
+        let direction = |sign: f64| {
+            move |(u, v): (f64, f64)| {
+                let x = view.domain.min() + u * width;
+                let y = view.codomain.min() + v * height;
+                let (dx, dy) = (self.field)(x, y);
+                let (du, dv) = (dx / width, dy / height);
+                let length = du.hypot(dv);
+                match length > f64::EPSILON && length.is_finite() {
+                    true => (sign * du / length, sign * dv / length),
+                    false => (0.0, 0.0),
+                }
+            }
+        };
+        let inside = |(u, v): (f64, f64)| (0.0..=1.0).contains(&u) && (0.0..=1.0).contains(&v);
+
+        for &(x, y) in self.seeds.iter() {
+            let seed = (
+                (x - view.domain.min()) / width,
+                (y - view.codomain.min()) / height,
+            );
+            for sign in [1.0, -1.0] {
+                let direction = direction(sign);
+                let mut point = seed;
+                for _ in 0..max_steps {
+                    if !inside(point) || direction(point) == (0.0, 0.0) {
+                        break;
+                    }
+                    let next = rk4(direction, point, step);
+                    canvas.line(
+                        view.domain.min() + point.0 * width,
+                        view.codomain.min() + point.1 * height,
+                        view.domain.min() + next.0 * width,
+                        view.codomain.min() + next.1 * height,
+                    );
+                    point = next;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AxisMode, Decorations, Domain, Plot, Size};
+
+    #[test]
+    fn traced_both_ways() {
+        // The field points away from `x = 0`: backward, the streamlines stop there, and forward
+        // they go to the edge of the view.
+        let streamlines = Streamlines::new(|x, _| (x, 0.0), vec![(1.0, 1.0), (-1.0, -1.0)]);
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(-2.0..2.0))
+            .set_codomain(Domain(-2.0..2.0))
+            .set_size(Size::new(16, 8))
+            .set_axis_mode(AxisMode::None)
+            .set_decorations(Decorations::NONE)
+            .add_plot(Box::new(streamlines));
+        assert_eq!(plot.try_render().unwrap(), "    ⠤⠤⠤⠤\n⠤⠤⠤⠤⠄   \n");
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{DrawView, View, ViewCanvas};
+
+/// A strip plot: every sample of each category drawn as a point.
+///
+/// The samples of the first category are drawn at `x = 0.5`, the samples of the next one at
+/// `x = 1.5`, etc... Samples with the same value are drawn on top of each other; use
+/// [`Strip::with_jitter`] to spread them horizontally, and [`Strip::with_box`] to summarize them
+/// with a box plot.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let strip = plot::Strip::new(vec![
+///     vec![12.0, 14.5, 13.0, 15.0, 12.5, 14.0, 13.5],
+///     vec![18.0, 21.0, 19.5, 20.0, 22.5, 19.0],
+///     vec![9.0, 11.0, 10.5, 10.0, 9.5, 12.0, 11.5, 10.0],
+/// ])
+/// .with_jitter(0.4)
+/// .with_labels(vec!["v1".to_string(), "v2".to_string(), "v3".to_string()]);
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..3.0))
+///     .set_codomain(Domain(0.0..25.0))
+///     .set_title("Response time (ms)")
+///     .set_size(Size::new(60, 30))
+///     .add_plot(Box::new(strip));
+///
+/// println!("{plot}");
+/// ```
+pub struct Strip {
+    samples: Vec<Vec<f64>>,
+    labels: Vec<String>,
+    jitter: f64,
+    with_box: bool,
+}
+
+impl Strip {
+    /// Create a new strip plot from the samples of each category.
+    pub fn new(samples: Vec<Vec<f64>>) -> Self {
+        Self {
+            samples,
+            labels: Vec::new(),
+            jitter: 0.0,
+            with_box: false,
+        }
+    }
+
+    /// Spread the samples of each category over `width` units of the x axis, centered on the
+    /// category, to reduce overplotting. The spread is deterministic: the same samples are always
+    /// drawn at the same place. By default, there is no jitter.
+    pub fn with_jitter(mut self, width: f64) -> Self {
+        self.jitter = width;
+        self
+    }
+
+    /// Draw a box plot behind the samples of each category: a box from the first to the third
+    /// quartile split by the median, and whiskers reaching the farthest samples within 1.5 times
+    /// the height of the box. By default, there is no box.
+    pub fn with_box(mut self, with_box: bool) -> Self {
+        self.with_box = with_box;
+        self
+    }
+
+    /// Write the name of each category below the x axis instead of the values of the x axis.
+    pub fn with_labels(mut self, labels: Vec<String>) -> Self {
+        self.labels = labels;
+        self
+    }
+}
+
+impl DrawView for Strip {
+    fn draw(&self, _: &View, canvas: &mut ViewCanvas) {
+        for (index, samples) in self.samples.iter().enumerate() {
+            let center = index as f64 + 0.5;
+            if self.with_box {
+                draw_box(canvas, center, samples);
+            }
+            for (sample, &y) in samples.iter().enumerate() {
+                canvas.point(center + jitter(sample) * self.jitter, y);
+            }
+        }
+    }
+
+    fn x_labels(&self) -> Vec<(f64, String)> {
+        self.labels
+            .iter()
+            .enumerate()
+            .map(|(index, label)| (index as f64 + 0.5, label.clone()))
+            .collect()
+    }
+}
+
+/// Draw the box plot of `samples` centered on `center`. See [`Strip::with_box`].
+fn draw_box(canvas: &mut ViewCanvas, center: f64, samples: &[f64]) {
+    let mut sorted = samples
+        .iter()
+        .copied()
+        .filter(|sample| sample.is_finite())
+        .collect::<Vec<_>>();
+    if sorted.is_empty() {
+        return;
+    }
+    sorted.sort_by(f64::total_cmp);
+    let [q1, median, q3] = [0.25, 0.5, 0.75].map(|fraction| quantile(&sorted, fraction));
+    let reach = 1.5 * (q3 - q1);
+    let low = sorted
+        .iter()
+        .copied()
+        .find(|&y| y >= q1 - reach)
+        .unwrap_or(q1);
+    let high = sorted
+        .iter()
+        .copied()
+        .rfind(|&y| y <= q3 + reach)
+        .unwrap_or(q3);
+    let (left, right) = (center - 0.3, center + 0.3);
+    canvas.rect(left, q1, right, q3);
+    canvas.line(left, median, right, median);
+    canvas.line(center, q3, center, high);
+    canvas.line(center, q1, center, low);
+    canvas.line(center - 0.1, high, center + 0.1, high);
+    canvas.line(center - 0.1, low, center + 0.1, low);
+}
+
+/// The value below which `fraction` of the `sorted` samples are, interpolated between the two
+/// closest samples.
+pub(crate) fn quantile(sorted: &[f64], fraction: f64) -> f64 {
+    let position = fraction * (sorted.len() - 1) as f64;
+    let (below, above) = (position.floor() as usize, position.ceil() as usize);
+    sorted[below] + (sorted[above] - sorted[below]) * (position - below as f64)
+}
+
+/// The offset, from `-0.5` to `0.5`, of the `index`th sample of a category. The offsets follow
+/// the fractional parts of the multiples of the golden ratio, which spread evenly.
+fn jitter(index: usize) -> f64 {
+    const GOLDEN_RATIO: f64 = 0.618_033_988_749_895;
+    (index as f64 * GOLDEN_RATIO).fract() - 0.5
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quartiles() {
+        let sorted = [1.0, 2.0, 3.0, 4.0, 10.0];
+        assert_eq!(quantile(&sorted, 0.25), 2.0);
+        assert_eq!(quantile(&sorted, 0.5), 3.0);
+        assert_eq!(quantile(&sorted, 0.875), 7.0);
+    }
+
+    #[test]
+    fn jitter_spreads_evenly() {
+        let mut offsets = (0..10).map(jitter).collect::<Vec<_>>();
+        assert_eq!(offsets[0], -0.5);
+        offsets.sort_by(f64::total_cmp);
+        for pair in offsets.windows(2) {
+            assert!(pair[1] - pair[0] > 0.05);
+        }
+        assert!(offsets.iter().all(|offset| (-0.5..0.5).contains(offset)));
+    }
+}
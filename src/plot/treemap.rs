@@ -0,0 +1,185 @@
+use crate::{Align, DrawView, View, ViewCanvas};
+
+/// A treemap: a rectangle for each item, with an area proportional to its value, tiling the whole
+/// view.
+///
+/// The rectangles are laid out with the
+/// [squarified](https://www.win.tue.nl/~vanwijk/stm.pdf) algorithm, which keeps them close to
+/// squares. The name of each item is written in the top left corner of its rectangle, and
+/// shortened when the rectangle is too small. Items without a positive value are not drawn.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let treemap = plot::Treemap::new(vec![
+///     ("target".to_string(), 4200.0),
+///     ("src".to_string(), 1800.0),
+///     (".git".to_string(), 900.0),
+///     ("assets".to_string(), 600.0),
+///     ("docs".to_string(), 250.0),
+///     ("tests".to_string(), 120.0),
+/// ]);
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..1.0))
+///     .set_codomain(Domain(0.0..1.0))
+///     .set_axis_mode(AxisMode::None)
+///     .set_title("Disk usage")
+///     .set_size(Size::new(80, 40))
+///     .add_plot(Box::new(treemap));
+///
+/// println!("{plot}");
+/// ```
+pub struct Treemap {
+    items: Vec<(String, f64)>,
+}
+
+impl Treemap {
+    /// Create a new treemap from the name and the value of each item.
+    pub fn new(items: Vec<(String, f64)>) -> Self {
+        Self { items }
+    }
+}
+
+impl DrawView for Treemap {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let (width, height) = (view.size.w as f64, view.size.h as f64);
+        let values = self
+            .items
+            .iter()
+            .map(|(_, value)| *value)
+            .collect::<Vec<_>>();
+        let rects = squarify(&values, Rect::new(0.0, 0.0, width, height));
+        // From pixels (from the top left corner of the view) to the coordinates of the plot.
+        let x = |x: f64| view.domain.min() + x / width * (view.domain.max() - view.domain.min());
+        let y =
+            |y: f64| view.codomain.max() - y / height * (view.codomain.max() - view.codomain.min());
+        for ((name, _), rect) in self.items.iter().zip(rects) {
+            let Some(rect) = rect else {
+                continue;
+            };
+            let (left, right) = (x(rect.x), x(rect.x + rect.w));
+            let (top, bottom) = (y(rect.y), y(rect.y + rect.h));
+            canvas.rect(left, bottom, right, top);
+
+            // A character takes 2 by 4 pixels, and the label stays inside of the borders.
+            let fit = ((rect.w - 3.0) / 2.0).max(0.0) as usize;
+            if rect.h >= 8.0 && fit > 0 {
+                let label = name.chars().take(fit).collect::<String>();
+                canvas.text(x(rect.x + 2.0), y(rect.y + 4.0), &label, Align::Left);
+            }
+        }
+    }
+}
+
+/// A rectangle in pixels, from its top left corner.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Rect {
+    x: f64,
+    y: f64,
+    w: f64,
+    h: f64,
+}
+
+impl Rect {
+    fn new(x: f64, y: f64, w: f64, h: f64) -> Self {
+        Self { x, y, w, h }
+    }
+}
+
+/// Lay out a rectangle for each value in `bounds`, with an area proportional to the value. Values
+/// that are not positive have no rectangle.
+fn squarify(values: &[f64], bounds: Rect) -> Vec<Option<Rect>> {
+    let total = values.iter().filter(|&&value| value > 0.0).sum::<f64>();
+    let mut rects = vec![None; values.len()];
+    let mut order = (0..values.len())
+        .filter(|&index| values[index] > 0.0)
+        .collect::<Vec<_>>();
+    order.sort_by(|&a, &b| values[b].total_cmp(&values[a]));
+    // The area of each rectangle, from the largest to the smallest.
+    let scale = bounds.w * bounds.h / total;
+    let areas = order
+        .iter()
+        .map(|&index| values[index] * scale)
+        .collect::<Vec<_>>();
+
+    let mut free = bounds;
+    let mut row_start = 0;
+    for end in 1..=areas.len() {
+        let side = free.w.min(free.h);
+        let row = &areas[row_start..end];
+        let is_last = end == areas.len();
+        // Keep adding rectangles to the row while it makes them closer to squares.
+        if !is_last && worst(&areas[row_start..end + 1], side) <= worst(row, side) {
+            continue;
+        }
+        let row_rects = lay_out_row(row, &mut free);
+        for (offset, rect) in row_rects.into_iter().enumerate() {
+            rects[order[row_start + offset]] = Some(rect);
+        }
+        row_start = end;
+    }
+    rects
+}
+
+/// The worst aspect ratio of the rectangles of `row` laid out along a side of length `side`.
+fn worst(row: &[f64], side: f64) -> f64 {
+    let sum = row.iter().sum::<f64>();
+    let max = row.iter().copied().fold(f64::MIN, f64::max);
+    let min = row.iter().copied().fold(f64::MAX, f64::min);
+    let side = side * side;
+    (side * max / (sum * sum)).max(sum * sum / (side * min))
+}
+
+/// Lay out `row` along the shortest side of `free`, and remove the space taken from `free`.
+fn lay_out_row(row: &[f64], free: &mut Rect) -> Vec<Rect> {
+    let sum = row.iter().sum::<f64>();
+    let mut rects = Vec::with_capacity(row.len());
+    if free.w >= free.h {
+        // A column on the left.
+        let width = sum / free.h;
+        let mut y = free.y;
+        for area in row {
+            let height = area / width;
+            rects.push(Rect::new(free.x, y, width, height));
+            y += height;
+        }
+        *free = Rect::new(free.x + width, free.y, free.w - width, free.h);
+    } else {
+        // A row on the top.
+        let height = sum / free.w;
+        let mut x = free.x;
+        for area in row {
+            let width = area / height;
+            rects.push(Rect::new(x, free.y, width, height));
+            x += width;
+        }
+        *free = Rect::new(free.x, free.y + height, free.w, free.h - height);
+    }
+    rects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rectangles_tile_the_bounds() {
+        let values = [6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0, 0.0];
+        let rects = squarify(&values, Rect::new(0.0, 0.0, 6.0, 4.0));
+        assert_eq!(rects[7], None);
+        let mut area = 0.0;
+        for (rect, value) in rects.iter().flatten().zip(values) {
+            assert!((rect.w * rect.h - value).abs() < 1e-9);
+            assert!(rect.x >= 0.0 && rect.x + rect.w <= 6.0 + 1e-9);
+            assert!(rect.y >= 0.0 && rect.y + rect.h <= 4.0 + 1e-9);
+            area += rect.w * rect.h;
+        }
+        assert!((area - 24.0).abs() < 1e-9);
+        // The first row of the example of the paper: two 3 by 2 rectangles.
+        assert_eq!(rects[0], Some(Rect::new(0.0, 0.0, 3.0, 2.0)));
+        assert_eq!(rects[1], Some(Rect::new(0.0, 2.0, 3.0, 2.0)));
+    }
+}
@@ -0,0 +1,94 @@
+use super::dotted_line;
+use crate::{DrawView, View, ViewCanvas};
+
+/// A [waterfall chart](https://en.wikipedia.org/wiki/Waterfall_chart) showing how sequential
+/// deltas add up.
+///
+/// Each delta is drawn as a bar floating between the running total before and after it, and
+/// dotted connectors join the top of the running total between successive bars. Like
+/// [`Bars`](super::Bars), the bars are 1 unit apart starting at `x = 0`. Positive deltas are
+/// filled, negative deltas are hollow.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..6.0))
+///     .set_codomain(Domain(0.0..100.0))
+///     .set_title("Request latency (ms)")
+///     .set_size(Size::new(50, 25))
+///     .add_plot(Box::new(plot::Waterfall::new(vec![
+///         12.0, 35.0, 20.0, -8.0, 25.0, -4.0,
+///     ])));
+///
+/// println!("{plot}");
+/// ```
+pub struct Waterfall {
+    deltas: Vec<f64>,
+}
+
+impl Waterfall {
+    /// The proportion of the unit taken by a bar, the rest is left for the connectors.
+    const BAR_WIDTH: f64 = 0.6;
+
+    /// Create a new waterfall chart. Each value of `deltas` is added to the running total of
+    /// the previous values.
+    pub fn new(deltas: Vec<f64>) -> Self {
+        Self { deltas }
+    }
+
+    /// The running total after each delta.
+    pub fn totals(&self) -> Vec<f64> {
+        self.deltas
+            .iter()
+            .scan(0.0, |total, delta| {
+                *total += delta;
+                Some(*total)
+            })
+            .collect()
+    }
+}
+
+impl DrawView for Waterfall {
+    fn draw(&self, view: &View, canvas: &mut ViewCanvas) {
+        let margin = (1.0 - Self::BAR_WIDTH) / 2.0;
+        let mut before = 0.0;
+        for (index, (delta, after)) in self.deltas.iter().zip(self.totals()).enumerate() {
+            let (x0, x1) = (index as f64 + margin, index as f64 + 1.0 - margin);
+            canvas.rect(x0, before, x1, after);
+            if *delta > 0.0 {
+                canvas.shade_rect(x0, before, x1, after, 0.5);
+            }
+            if index + 1 < self.deltas.len() {
+                dotted_line(view, canvas, (x1, after), (x1 + 2.0 * margin, after));
+            }
+            before = after;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AxisMode, Decorations, Domain, Plot, Size};
+
+    #[test]
+    fn floating_bars() {
+        let waterfall = Waterfall::new(vec![2.0, -1.0]);
+        assert_eq!(waterfall.totals(), vec![2.0, 1.0]);
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..2.0))
+            .set_codomain(Domain(0.0..2.0))
+            .set_size(Size::new(20, 16))
+            .set_axis_mode(AxisMode::None)
+            .set_decorations(Decorations::NONE)
+            .add_plot(Box::new(waterfall));
+        // The rise is filled from 0 to 2, the fall is hollow from 2 to 1.
+        assert_eq!(
+            plot.try_render().unwrap(),
+            " ⣟⢝⢝⡇ ⡏⠉⠉⡇\n ⣗⢕⢕⡇ ⡇  ⡇\n ⣗⢕⢕⡇ ⠉⠉⠉⠁\n ⣗⣕⣕⡇     \n"
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Images of the view for the backends drawing pixels of the screen (see `Plot::set_backend`).
+
+use crate::color::Rgb;
+
+/// The number of pixels drawn on each axis for each pixel of the braille canvas: about one pixel
+/// of the screen for each pixel drawn, as a character is usually 8 by 16 pixels.
+pub(crate) const RESOLUTION: usize = 4;
+
+/// Encode `pixels` (rows of colors, `None` for the pixels that are not set) as a PNG image. The
+/// pixels that are not set are transparent.
+pub(crate) fn png(pixels: &[Vec<Option<Rgb>>]) -> Vec<u8> {
+    let (height, width) = (pixels.len(), pixels.first().map_or(0, Vec::len));
+    let data = pixels
+        .iter()
+        .flatten()
+        .flat_map(|pixel| match pixel {
+            Some((r, g, b)) => [*r, *g, *b, 255],
+            None => [0; 4],
+        })
+        .collect::<Vec<_>>();
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&data))
+        .expect("writing an image in memory does not fail");
+    png
+}
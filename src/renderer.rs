@@ -0,0 +1,267 @@
+use std::io::{self, Write};
+
+use crate::text;
+
+/// Begin a synchronized update: the terminal waits for the end of the update before showing it.
+/// Terminals that do not support synchronized updates ignore the sequence.
+const BEGIN_SYNCHRONIZED_UPDATE: &str = "\x1b[?2026h";
+const END_SYNCHRONIZED_UPDATE: &str = "\x1b[?2026l";
+/// Clear from the cursor to the end of the line.
+const CLEAR_LINE: &str = "\x1b[K";
+/// Clear from the cursor to the end of the screen.
+const CLEAR_BELOW: &str = "\x1b[J";
+/// Reset the colors and the style of the text.
+const RESET: &str = "\x1b[0m";
+
+/// Draws frames in the terminal, each frame replacing the previous one, without flickering.
+///
+/// Each frame is first written in a back buffer, then written to the terminal in a single write,
+/// inside a synchronized update for the terminals supporting it. When a frame has as many lines
+/// as the previous one, only the cells that changed are written (with the cursor moves to reach
+/// them), which keeps live updates of large plots fast on slow connections. Otherwise, the
+/// previous frame is overwritten line by line instead of being cleared first.
+///
+/// The renderer assumes nothing else is written to the terminal between two frames. Use
+/// [`Renderer::reset`] when something else was written.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut renderer = Renderer::new(std::io::stdout());
+/// for frame in 0..10 {
+///     let phase = frame as f64 / 10.0;
+///     let mut plot = Plot::default();
+///     plot.set_domain(Domain(-10.0..10.0))
+///         .set_codomain(Domain(-1.2..1.2))
+///         .set_size(Size::new(50, 25))
+///         .add_plot(Box::new(plot::Graph::new(move |x| (x + phase).sin())));
+///     renderer.draw(&plot.to_string()).unwrap();
+/// }
+/// ```
+pub struct Renderer<W> {
+    out: W,
+    buffer: String,
+    previous: Vec<Vec<Cell>>,
+}
+
+impl<W: Write> Renderer<W> {
+    /// Create a new renderer writing to `out`. The first frame is drawn at the position of the
+    /// cursor.
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            buffer: String::new(),
+            previous: Vec::new(),
+        }
+    }
+
+    /// Draw `frame`, replacing the previous frame.
+    pub fn draw(&mut self, frame: &str) -> io::Result<()> {
+        let grid = frame.lines().map(cells).collect::<Vec<_>>();
+        self.buffer.clear();
+        self.buffer.push_str(BEGIN_SYNCHRONIZED_UPDATE);
+        match self.previous.len() {
+            0 => self.write_lines(frame),
+            rows if rows == grid.len() => self.write_changes(&grid),
+            rows => {
+                self.buffer.push_str(&format!("\x1b[{rows}A\r"));
+                self.write_lines(frame);
+            }
+        }
+        self.buffer.push_str(END_SYNCHRONIZED_UPDATE);
+        self.out.write_all(self.buffer.as_bytes())?;
+        self.out.flush()?;
+        self.previous = grid;
+        Ok(())
+    }
+
+    /// Forget the previous frame: the next frame is drawn at the position of the cursor.
+    pub fn reset(&mut self) {
+        self.previous.clear();
+    }
+
+    /// Write every line of `frame`, from the position of the cursor.
+    fn write_lines(&mut self, frame: &str) {
+        for line in frame.lines() {
+            // `\r` is needed when the terminal is in raw mode.
+            self.buffer.push_str(line);
+            self.buffer.push_str(CLEAR_LINE);
+            self.buffer.push_str("\r\n");
+        }
+        self.buffer.push_str(CLEAR_BELOW);
+    }
+
+    /// Write the cells of `grid` that differ from the previous frame, which has the same number
+    /// of rows. The cursor is below the previous frame before and after.
+    fn write_changes(&mut self, grid: &[Vec<Cell>]) {
+        let rows = grid.len();
+        let mut cursor_row = rows;
+        for (row, (previous, current)) in self.previous.iter().zip(grid.iter()).enumerate() {
+            let mut changes = String::new();
+            let columns = columns(current);
+            let mut column = 0;
+            while column < current.len() {
+                if previous.get(column) == Some(&current[column]) {
+                    column += 1;
+                    continue;
+                }
+                // Write the whole run of changed cells after a single cursor move.
+                changes.push_str(&format!("\x1b[{}G", columns[column] + 1));
+                let mut style = None;
+                while column < current.len() && previous.get(column) != Some(&current[column]) {
+                    let cell = &current[column];
+                    if style != Some(&cell.style) {
+                        changes.push_str(RESET);
+                        changes.push_str(&cell.style);
+                        style = Some(&cell.style);
+                    }
+                    changes.push(cell.char);
+                    column += 1;
+                }
+                changes.push_str(RESET);
+            }
+            if current.len() < previous.len() {
+                changes.push_str(&format!("\x1b[{}G{CLEAR_LINE}", columns[current.len()] + 1));
+            }
+            if !changes.is_empty() {
+                self.buffer.push_str(&move_vertically(cursor_row, row));
+                self.buffer.push_str(&changes);
+                cursor_row = row;
+            }
+        }
+        self.buffer.push_str(&move_vertically(cursor_row, rows));
+        self.buffer.push('\r');
+    }
+}
+
+/// A character printed in the terminal, with its colors and style.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Cell {
+    /// The escape sequences setting the colors and the style of the character.
+    pub style: String,
+    pub char: char,
+}
+
+/// Split a line into its cells. Each cell keeps the escape sequences (colors and style) active
+/// when its character is printed.
+pub(crate) fn cells(line: &str) -> Vec<Cell> {
+    let mut cells = Vec::new();
+    let mut style = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            let mut sequence = String::from(c);
+            for c in chars.by_ref() {
+                sequence.push(c);
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            match sequence.as_str() {
+                RESET | "\x1b[m" => style.clear(),
+                _ if sequence.ends_with('m') => style.push_str(&sequence),
+                _ => {}
+            }
+        } else {
+            cells.push(Cell {
+                style: style.clone(),
+                char: c,
+            });
+        }
+    }
+    cells
+}
+
+/// The terminal column where each cell of a line starts, counted from `0`, followed by the width
+/// of the whole line. Wide characters take two columns, see [`text::width`].
+fn columns(cells: &[Cell]) -> Vec<usize> {
+    let mut column = 0;
+    let mut columns = vec![0];
+    for cell in cells {
+        column += text::width(cell.char.encode_utf8(&mut [0; 4]));
+        columns.push(column);
+    }
+    columns
+}
+
+/// The escape sequence moving the cursor from row `from` to row `to`.
+fn move_vertically(from: usize, to: usize) -> String {
+    match from.cmp(&to) {
+        std::cmp::Ordering::Equal => String::new(),
+        std::cmp::Ordering::Greater => format!("\x1b[{}A", from - to),
+        std::cmp::Ordering::Less => format!("\x1b[{}B", to - from),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output(renderer: &mut Renderer<Vec<u8>>, frame: &str) -> String {
+        renderer.out.clear();
+        renderer.draw(frame).unwrap();
+        String::from_utf8(renderer.out.clone()).unwrap()
+    }
+
+    #[test]
+    fn overwrites_previous_frame() {
+        let mut renderer = Renderer::new(Vec::new());
+        output(&mut renderer, "ab\ncd\n");
+        assert_eq!(
+            output(&mut renderer, "e\n"),
+            "\x1b[?2026h\x1b[2A\re\x1b[K\r\n\x1b[J\x1b[?2026l"
+        );
+    }
+
+    #[test]
+    fn writes_changed_cells_only() {
+        let mut renderer = Renderer::new(Vec::new());
+        output(&mut renderer, "abcd\nefgh\nijkl\n");
+        assert_eq!(
+            output(&mut renderer, "abcd\neXYh\nij\n"),
+            "\x1b[?2026h\x1b[2A\x1b[2G\x1b[0mXY\x1b[0m\x1b[1B\x1b[3G\x1b[K\x1b[1B\r\x1b[?2026l"
+        );
+        assert_eq!(
+            output(&mut renderer, "abcd\neXYh\nij\n"),
+            "\x1b[?2026h\r\x1b[?2026l"
+        );
+    }
+
+    #[test]
+    fn wide_characters_take_two_columns() {
+        let mut renderer = Renderer::new(Vec::new());
+        output(&mut renderer, "日本ab\n");
+        assert_eq!(
+            output(&mut renderer, "日本aX\n"),
+            "\x1b[?2026h\x1b[1A\x1b[6G\x1b[0mX\x1b[0m\x1b[1B\r\x1b[?2026l"
+        );
+        assert_eq!(
+            output(&mut renderer, "日本\n"),
+            "\x1b[?2026h\x1b[1A\x1b[5G\x1b[K\x1b[1B\r\x1b[?2026l"
+        );
+    }
+
+    #[test]
+    fn cells_keep_their_colors() {
+        let red = "\x1b[31m".to_string();
+        assert_eq!(
+            cells("a\x1b[31mb\x1b[0mc"),
+            vec![
+                Cell {
+                    style: String::new(),
+                    char: 'a'
+                },
+                Cell {
+                    style: red,
+                    char: 'b'
+                },
+                Cell {
+                    style: String::new(),
+                    char: 'c'
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::Domain;
+
+/// How values are placed along an axis. See [`Plot::set_x_scale`](crate::Plot::set_x_scale) and
+/// [`Plot::set_y_scale`](crate::Plot::set_y_scale).
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-10.0..10.0))
+///     .set_codomain(Domain(-1000.0..1000.0))
+///     .set_y_scale(Scale::SymLog { linthresh: 1.0 })
+///     .set_y_ticks(5)
+///     .set_size(Size::new(50, 25))
+///     .add_plot(Box::new(plot::Graph::new(|x| x.powi(3))));
+///
+/// println!("{plot}");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Scale {
+    /// Values are evenly spaced.
+    #[default]
+    Linear,
+    /// Symmetric logarithmic scale: linear between `-linthresh` and `linthresh`, and
+    /// logarithmic (base 10) away from zero. Unlike a logarithmic scale, zero and negative
+    /// values can be placed on the axis.
+    ///
+    /// `linthresh` must be positive and finite: rendering fails with
+    /// [`RenderError::InvalidScale`](crate::RenderError::InvalidScale) otherwise.
+    SymLog { linthresh: f64 },
+}
+
+impl Scale {
+    /// Whether values can be placed with the scale.
+    pub(crate) fn is_valid(&self) -> bool {
+        match *self {
+            Self::Linear => true,
+            Self::SymLog { linthresh } => linthresh > 0.0 && linthresh.is_finite(),
+        }
+    }
+
+    /// The position of `value` on a linear axis.
+    fn apply(&self, value: f64) -> f64 {
+        match *self {
+            Self::Linear => value,
+            Self::SymLog { linthresh } => {
+                let scaled = value.abs() / linthresh;
+                match scaled <= 1.0 {
+                    true => value / linthresh,
+                    false => value.signum() * (1.0 + scaled.log10()),
+                }
+            }
+        }
+    }
+
+    /// The value at `position` on a linear axis. The inverse of [`Scale::apply`].
+    fn invert(&self, position: f64) -> f64 {
+        match *self {
+            Self::Linear => position,
+            Self::SymLog { linthresh } => match position.abs() <= 1.0 {
+                true => position * linthresh,
+                false => position.signum() * linthresh * 10f64.powf(position.abs() - 1.0),
+            },
+        }
+    }
+
+    /// Where `value` is along `domain`, from `0.0` at its start to `1.0` at its end, even when
+    /// the domain is reversed (its start is larger than its end).
+    pub(crate) fn fraction(&self, domain: &Domain, value: f64) -> f64 {
+        let (start, end) = (self.apply(domain.min()), self.apply(domain.max()));
+        (self.apply(value) - start) / (end - start)
+    }
+
+    /// The value at `fraction` along `domain`. The inverse of [`Scale::fraction`].
+    pub(crate) fn value_at(&self, domain: &Domain, fraction: f64) -> f64 {
+        let (start, end) = (self.apply(domain.min()), self.apply(domain.max()));
+        self.invert(start + (end - start) * fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symlog() {
+        let scale = Scale::SymLog { linthresh: 1.0 };
+        let domain = Domain(-100.0..100.0);
+        assert_eq!(scale.fraction(&domain, 0.0), 0.5);
+        assert_eq!(scale.fraction(&domain, 1.0), 4.0 / 6.0);
+        assert_eq!(scale.fraction(&domain, 100.0), 1.0);
+        for value in [-100.0, -3.0, -0.5, 0.0, 0.25, 10.0] {
+            let fraction = scale.fraction(&domain, value);
+            assert!((scale.value_at(&domain, fraction) - value).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn reversed_domain() {
+        let domain = Domain(8.0..-8.0);
+        assert_eq!(Scale::Linear.fraction(&domain, 8.0), 0.0);
+        assert_eq!(Scale::Linear.fraction(&domain, -4.0), 0.75);
+        assert_eq!(Scale::Linear.value_at(&domain, 1.0), -8.0);
+        assert!(!Scale::SymLog { linthresh: 0.0 }.is_valid());
+    }
+}
@@ -0,0 +1,105 @@
+//! Drawing images with sixel escape sequences, for the terminals supporting them (xterm, mlterm,
+//! foot, etc...).
+
+use crate::color::Rgb;
+
+/// The number of pixels drawn on each axis for each pixel of the braille canvas.
+pub(crate) const RESOLUTION: usize = 2;
+/// The size in pixels of the screen of each pixel drawn, so that the image is about as large as
+/// the braille characters in most terminals.
+const ZOOM: usize = 2;
+/// The number of color registers of most terminals.
+const REGISTERS: usize = 256;
+
+/// Encode `pixels` (rows of colors, `None` for the pixels that are not set) as a sixel image.
+/// The pixels that are not set keep the background of the terminal.
+pub(crate) fn encode(pixels: &[Vec<Option<Rgb>>]) -> String {
+    let mut colors = Vec::new();
+    for &color in pixels.iter().flatten().flatten() {
+        if !colors.contains(&color) {
+            colors.push(color);
+        }
+    }
+    // With too many colors (like the gradient of a heatmap), the components are rounded to the
+    // 6 levels of the 216 colors cube.
+    let quantize = colors.len() > REGISTERS;
+    let quantized = |(r, g, b): Rgb| match quantize {
+        true => (level(r), level(g), level(b)),
+        false => (r, g, b),
+    };
+    let mut registers = Vec::new();
+    for &color in &colors {
+        if !registers.contains(&quantized(color)) {
+            registers.push(quantized(color));
+        }
+    }
+    let (height, width) = (
+        pixels.len() * ZOOM,
+        pixels.first().map_or(0, Vec::len) * ZOOM,
+    );
+    // `0;1;0`: the pixels that are not set are transparent.
+    let mut out = format!("\x1bP0;1;0q\"1;1;{width};{height}");
+    for (index, &(r, g, b)) in registers.iter().enumerate() {
+        let (r, g, b) = (percent(r), percent(g), percent(b));
+        out.push_str(&format!("#{index};2;{r};{g};{b}"));
+    }
+    // The image is written in bands of 6 rows, one pass for each color of the band.
+    for band in 0..height.div_ceil(6) {
+        for (index, &register) in registers.iter().enumerate() {
+            let sixels = (0..width)
+                .map(|x| {
+                    (0..6).fold(0, |bits, bit| {
+                        let y = band * 6 + bit;
+                        let pixel = pixels.get(y / ZOOM).and_then(|row| row[x / ZOOM]);
+                        match y < height && pixel.map(quantized) == Some(register) {
+                            true => bits | 1 << bit,
+                            false => bits,
+                        }
+                    })
+                })
+                .collect::<Vec<u8>>();
+            if sixels.iter().all(|&bits| bits == 0) {
+                continue;
+            }
+            out.push_str(&format!("#{index}"));
+            for run in sixels.chunk_by(|a, b| a == b) {
+                let char = (b'?' + run[0]) as char;
+                match run.len() {
+                    // Runs of up to 3 sixels are shorter when written as they are.
+                    1..=3 => out.extend(std::iter::repeat_n(char, run.len())),
+                    count => out.push_str(&format!("!{count}{char}")),
+                }
+            }
+            // Go back to the start of the band for the next color.
+            out.push('$');
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    out
+}
+
+/// The component rounded to the closest of 6 levels.
+fn level(component: u8) -> u8 {
+    (component as f64 / 51.0).round() as u8 * 51
+}
+
+/// The component in percent, as expected by the color registers.
+fn percent(component: u8) -> u8 {
+    (component as f64 * 100.0 / 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_bands() {
+        let red = Some((255, 0, 0));
+        let pixels = vec![vec![red, None, None, red, red], vec![None; 5]];
+        assert_eq!(
+            encode(&pixels),
+            "\x1bP0;1;0q\"1;1;10;4#0;2;100;0;0#0BB!4?!4B$-\x1b\\"
+        );
+    }
+}
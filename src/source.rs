@@ -0,0 +1,88 @@
+use std::sync::mpsc;
+
+/// A source of points that arrive over time (a socket, a channel, a sensor, etc...).
+///
+/// Plots consuming a [`DataSource`] (like [`plot::StreamLines`](crate::plot::StreamLines)) pull
+/// the new points each time they are drawn, so the data does not need to be collected up front.
+///
+/// # Examples
+///
+/// A source reading one point per line of any reader:
+///
+/// ```rust
+/// use std::io::BufRead;
+/// use termplot::DataSource;
+///
+/// struct Lines<R> {
+///     reader: R,
+///     chunk: Vec<(f64, f64)>,
+/// }
+///
+/// impl<R: BufRead> DataSource for Lines<R> {
+///     fn next_chunk(&mut self) -> Option<&[(f64, f64)]> {
+///         let mut line = String::new();
+///         self.reader.read_line(&mut line).ok()?;
+///         let (x, y) = line.trim().split_once(',')?;
+///         self.chunk = vec![(x.parse().ok()?, y.parse().ok()?)];
+///         Some(&self.chunk)
+///     }
+/// }
+/// ```
+pub trait DataSource {
+    /// The next chunk of points.
+    ///
+    /// Return `None` when no new points are available for now. The source will be polled again
+    /// the next time the plot is drawn.
+    fn next_chunk(&mut self) -> Option<&[(f64, f64)]>;
+}
+
+/// A [`DataSource`] receiving points from a channel.
+///
+/// Every point that was sent since the last poll is returned in a single chunk.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::mpsc;
+/// use termplot::*;
+///
+/// let (sender, receiver) = mpsc::channel();
+/// std::thread::spawn(move || {
+///     for x in 0..100 {
+///         let x = x as f64 / 10.0;
+///         sender.send((x, x.sin())).unwrap();
+///     }
+/// });
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..10.0))
+///     .set_codomain(Domain(-1.0..1.0))
+///     .add_plot(Box::new(plot::StreamLines::new(ChannelSource::new(receiver))));
+///
+/// println!("{plot}");
+/// ```
+pub struct ChannelSource {
+    receiver: mpsc::Receiver<(f64, f64)>,
+    chunk: Vec<(f64, f64)>,
+}
+
+impl ChannelSource {
+    /// Create a new source receiving points from `receiver`.
+    pub fn new(receiver: mpsc::Receiver<(f64, f64)>) -> Self {
+        Self {
+            receiver,
+            chunk: Vec::new(),
+        }
+    }
+}
+
+impl DataSource for ChannelSource {
+    fn next_chunk(&mut self) -> Option<&[(f64, f64)]> {
+        self.chunk.clear();
+        self.chunk.extend(self.receiver.try_iter());
+        match self.chunk.is_empty() {
+            true => None,
+            false => Some(&self.chunk),
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{plot, Domain, Plot, Size};
+#[cfg(any(feature = "json", feature = "toml"))]
+use std::fmt;
+
+/// A declarative description of a [`Plot`].
+///
+/// A [`PlotSpec`] describes the title, the labels, the domain, the codomain, the size and the
+/// series of a plot. Only data-driven series can be described (see [`SeriesSpec`]), functions
+/// can still be added with [`Plot::add_plot`] after building the plot.
+///
+/// When the `serde` feature is enabled, a spec can be serialized and deserialized to store it in
+/// a configuration file. Missing fields use their default value when deserializing. The `json`
+/// and `toml` features add `PlotSpec::from_json` and `PlotSpec::from_toml`.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(-10.0..10.0))
+///     .set_codomain(Domain(-0.3..1.2))
+///     .set_title("Graph title")
+///     .set_size(Size::new(50, 25));
+///
+/// let spec = plot.spec();
+/// assert_eq!(spec.title, "Graph title");
+///
+/// let mut plot = spec.build();
+/// plot.add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
+///
+/// println!("{plot}");
+/// ```
+///
+/// Loading a spec from TOML (with the `toml` feature):
+///
+/// ```rust
+/// # #[cfg(feature = "toml")]
+/// # {
+/// use termplot::PlotSpec;
+///
+/// let spec = PlotSpec::from_toml(r#"
+///     title = "Requests per second"
+///     domain = { start = 0.0, end = 4.0 }
+///     codomain = { start = 0.0, end = 10.0 }
+///
+///     [[series]]
+///     type = "lines"
+///     points = [[0.0, 1.0], [1.0, 4.0], [2.0, 3.0], [3.0, 8.0]]
+///
+///     [[series]]
+///     type = "bars"
+///     heights = [2.0, 5.0, 1.0, 8.0]
+/// "#).unwrap();
+///
+/// println!("{}", spec.build());
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct PlotSpec {
+    /// The title of the plot.
+    pub title: String,
+    /// The label of the x axis.
+    pub x_label: String,
+    /// The label of the y axis.
+    pub y_label: String,
+    /// Domain (range of the x axis) of the plot.
+    pub domain: Domain,
+    /// Codomain (range of the y axis) of the plot.
+    pub codomain: Domain,
+    /// The size of the view. See [`Plot::set_size`] for more informations.
+    pub size: Size,
+    /// The series drawn on the plot, in order.
+    pub series: Vec<SeriesSpec>,
+}
+
+impl PlotSpec {
+    /// Create a new [`Plot`] from the spec.
+    pub fn build(&self) -> Plot {
+        let mut plot = Plot::default();
+        plot.set_title(&self.title)
+            .set_x_label(&self.x_label)
+            .set_y_label(&self.y_label)
+            .set_domain(self.domain.clone())
+            .set_codomain(self.codomain.clone())
+            .set_size(self.size.clone());
+        for series in self.series.iter() {
+            plot.add_plot(series.build());
+        }
+        plot
+    }
+
+    /// Load a spec from a JSON document.
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> Result<Self, SpecError> {
+        serde_json::from_str(json).map_err(SpecError::Json)
+    }
+
+    /// Load a spec from a TOML document.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self, SpecError> {
+        toml::from_str(toml).map_err(SpecError::Toml)
+    }
+}
+
+/// A data-driven series of a [`PlotSpec`].
+///
+/// When deserialized, the kind of series is given by the `type` field (`"lines"`, `"scatter"`,
+/// `"bars"` or `"histogram"`).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "lowercase")
+)]
+pub enum SeriesSpec {
+    /// A line going through `(x, y)` points. See [`plot::Lines`].
+    Lines { points: Vec<(f64, f64)> },
+    /// `(x, y)` points. See [`plot::Scatter`].
+    Scatter { points: Vec<(f64, f64)> },
+    /// Bars with the given heights. See [`plot::Bars`].
+    Bars { heights: Vec<f64> },
+    /// An histogram of the values sorted in a number of buckets of the same width. See
+    /// [`plot::Histogram`].
+    Histogram { values: Vec<f64>, buckets: u32 },
+}
+
+impl SeriesSpec {
+    fn build(&self) -> Box<dyn crate::DrawView> {
+        match self {
+            Self::Lines { points } => Box::new(plot::Lines::new(points.clone())),
+            Self::Scatter { points } => Box::new(plot::Scatter::new(points.clone())),
+            Self::Bars { heights } => Box::new(plot::Bars::new(heights.clone())),
+            Self::Histogram { values, buckets } => Box::new(
+                plot::Histogram::new_with_buckets_count(values.clone(), *buckets),
+            ),
+        }
+    }
+}
+
+/// An error returned when a [`PlotSpec`] could not be loaded.
+#[cfg(any(feature = "json", feature = "toml"))]
+#[derive(Debug)]
+pub enum SpecError {
+    /// The JSON document is invalid or does not describe a spec.
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+    /// The TOML document is invalid or does not describe a spec.
+    #[cfg(feature = "toml")]
+    Toml(toml::de::Error),
+}
+
+#[cfg(any(feature = "json", feature = "toml"))]
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            #[cfg(feature = "json")]
+            Self::Json(ref error) => write!(f, "invalid JSON plot spec: {error}"),
+            #[cfg(feature = "toml")]
+            Self::Toml(ref error) => write!(f, "invalid TOML plot spec: {error}"),
+        }
+    }
+}
+
+#[cfg(any(feature = "json", feature = "toml"))]
+impl std::error::Error for SpecError {}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(0.0..11.0))
+            .set_codomain(Domain(0.0..45.0))
+            .set_title("Graph title")
+            .set_x_label("X axis")
+            .set_size(Size::new(100, 25));
+        let spec = plot.spec();
+        let json = serde_json::to_string(&spec).unwrap();
+        let parsed: PlotSpec = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, spec);
+        assert_eq!(parsed.build().spec(), spec);
+    }
+
+    #[test]
+    fn missing_fields_use_defaults() {
+        let spec: PlotSpec = serde_json::from_str(r#"{ "title": "Graph title" }"#).unwrap();
+        assert_eq!(spec.title, "Graph title");
+        assert_eq!(spec.domain, Domain::default());
+        assert_eq!(spec.size, Size::default());
+        assert!(spec.series.is_empty());
+    }
+
+    #[test]
+    fn series() {
+        let spec: PlotSpec = serde_json::from_str(
+            r#"{
+                "series": [
+                    { "type": "lines", "points": [[0.0, 1.0], [1.0, 2.0]] },
+                    { "type": "histogram", "values": [1.0, 2.0, 2.5], "buckets": 2 }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            spec.series,
+            vec![
+                SeriesSpec::Lines {
+                    points: vec![(0.0, 1.0), (1.0, 2.0)]
+                },
+                SeriesSpec::Histogram {
+                    values: vec![1.0, 2.0, 2.5],
+                    buckets: 2
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn from_toml() {
+        let spec = PlotSpec::from_toml(
+            r#"
+            title = "Graph title"
+            size = { w = 50, h = 25 }
+
+            [[series]]
+            type = "scatter"
+            points = [[0.0, 1.0]]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(spec.size, Size::new(50, 25));
+        assert_eq!(
+            spec.series,
+            vec![SeriesSpec::Scatter {
+                points: vec![(0.0, 1.0)]
+            }]
+        );
+        assert!(PlotSpec::from_toml("title = 1").is_err());
+    }
+}
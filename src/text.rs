@@ -0,0 +1,220 @@
+//! Measuring text printed in the terminal.
+
+use crate::Align;
+use unicode_width::UnicodeWidthChar;
+
+/// The number of columns taken by `text` once printed in the terminal.
+///
+/// Wide characters (CJK, emoji, etc...) take two columns, and ANSI escape sequences (used for
+/// colors) and combining characters do not take any column.
+pub(crate) fn width(text: &str) -> usize {
+    let mut width = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip the sequence up to its final byte (a letter).
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            width += c.width().unwrap_or(0);
+        }
+    }
+    width
+}
+
+/// `text` without its ANSI escape sequences (colors and styles).
+pub(crate) fn strip_escapes(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+/// Shorten `text` to at most `width` columns, ending it with `…` when it is cut.
+pub(crate) fn truncate(text: &str, width: usize) -> String {
+    if self::width(text) <= width {
+        return text.to_string();
+    }
+    let mut truncated = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if used + char_width + 1 > width {
+            break;
+        }
+        used += char_width;
+        truncated.push(c);
+    }
+    if width > 0 {
+        truncated.push('…');
+    }
+    truncated
+}
+
+/// Split `text` into lines of at most `width` columns. Lines break at each `\n` and between
+/// words; a word longer than `width` is split across several lines.
+pub(crate) fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let needed = self::width(&line) + usize::from(!line.is_empty()) + self::width(word);
+            if !line.is_empty() && needed > width {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            for c in word.chars() {
+                let used = self::width(&line);
+                if used > 0 && used + c.width().unwrap_or(0) > width {
+                    lines.push(std::mem::take(&mut line));
+                }
+                line.push(c);
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Replace the columns of `row` from `column` with `text`, keeping the colors of the other
+/// columns. `row` must only hold characters one column wide, like the rows of a canvas.
+pub(crate) fn overlay(row: &str, column: usize, text: &str) -> String {
+    // Each cell is a character with the escape sequences coloring it.
+    let mut cells = Vec::<String>::new();
+    let mut pending = String::new();
+    let mut chars = row.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            let mut sequence = String::from(c);
+            for c in chars.by_ref() {
+                sequence.push(c);
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            match (sequence.as_str(), cells.last_mut()) {
+                ("\x1b[0m", Some(last)) if pending.is_empty() => last.push_str(&sequence),
+                _ => pending.push_str(&sequence),
+            }
+        } else {
+            cells.push(std::mem::take(&mut pending) + &c.to_string());
+        }
+    }
+    let end = (column + self::width(text)).min(cells.len());
+    if column >= end {
+        return row.to_string();
+    }
+    let mut out = cells[..column].concat();
+    out.push_str(text);
+    out.push_str(&cells[end..].concat());
+    out.push_str(&pending);
+    out
+}
+
+/// Pad `text` with spaces on both sides to center it in `width` columns.
+pub(crate) fn center(text: &str, width: usize) -> String {
+    align(text, width, Align::Center, ' ')
+}
+
+/// Pad `text` with `fill` to align it in `width` columns.
+pub(crate) fn align(text: &str, width: usize, align: Align, fill: char) -> String {
+    let padding = width.saturating_sub(self::width(text));
+    let left = match align {
+        Align::Left => 0,
+        Align::Center => padding / 2,
+        Align::Right => padding,
+    };
+    let fill = |count: usize| fill.to_string().repeat(count);
+    format!("{}{text}{}", fill(left), fill(padding - left))
+}
+
+/// Lay out `text` vertically, one character per row, centered in `height` rows. Characters that
+/// do not fit are dropped.
+///
+/// Every row has the same width: the width of the widest character (at least one column).
+/// Combining characters stay on the row of the character they modify.
+pub(crate) fn vertical(text: &str, height: usize) -> Vec<String> {
+    let mut chars = Vec::<String>::new();
+    for c in text.chars() {
+        match (c.width(), chars.last_mut()) {
+            (Some(0), Some(last)) => last.push(c),
+            _ => chars.push(c.to_string()),
+        }
+    }
+    chars.truncate(height);
+    let column_width = chars.iter().map(|c| width(c)).fold(1, usize::max);
+    let top = (height - chars.len()) / 2;
+    let mut rows = vec![" ".repeat(column_width); height];
+    for (row, c) in rows[top..].iter_mut().zip(chars.iter()) {
+        *row = align(c, column_width, Align::Left, ' ');
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_sequences_have_no_width() {
+        assert_eq!(width("\x1b[34m⣿⣿\x1b[0m ab"), 5);
+        assert_eq!(center("\x1b[34mab\x1b[0m", 5), " \x1b[34mab\x1b[0m  ");
+        assert_eq!(align("ab", 5, Align::Right, '─'), "───ab");
+    }
+
+    #[test]
+    fn stripped_escape_sequences() {
+        assert_eq!(
+            strip_escapes("\x1b[34m⣿⣿\x1b[0m a\x1b[1;38;2;1;2;3mb"),
+            "⣿⣿ ab"
+        );
+    }
+
+    #[test]
+    fn wide_and_combining_characters() {
+        assert_eq!(width("日本"), 4);
+        assert_eq!(width("e\u{301}"), 1);
+        assert_eq!(center("日本", 6), " 日本 ");
+    }
+
+    #[test]
+    fn truncated_text() {
+        assert_eq!(truncate("1.25", 4), "1.25");
+        assert_eq!(truncate("1.25", 3), "1.…");
+        assert_eq!(truncate("日本", 3), "日…");
+        assert_eq!(truncate("1.25", 1), "…");
+        assert_eq!(truncate("1.25", 0), "");
+    }
+
+    #[test]
+    fn wrapped_text() {
+        assert_eq!(wrap("a long title", 6), vec!["a long", "title"]);
+        assert_eq!(wrap("first\nsecond line", 20), vec!["first", "second line"]);
+        assert_eq!(wrap("abcdefgh", 3), vec!["abc", "def", "gh"]);
+        assert_eq!(wrap("", 5), vec![""]);
+    }
+
+    #[test]
+    fn overlaid_text() {
+        assert_eq!(overlay("⣿⣿⣿⣿", 1, "ab"), "⣿ab⣿");
+        assert_eq!(
+            overlay("\x1b[34m⣿\x1b[0m\x1b[31m⣿\x1b[0m⣿", 1, "a"),
+            "\x1b[34m⣿\x1b[0ma⣿"
+        );
+        assert_eq!(overlay("⣿⣿", 3, "a"), "⣿⣿");
+    }
+
+    #[test]
+    fn vertical_text() {
+        assert_eq!(vertical("ab", 5), vec![" ", "a", "b", " ", " "]);
+        assert_eq!(vertical("abc", 2), vec!["a", "b"]);
+        assert_eq!(vertical("a日", 3), vec!["a ", "日", "  "]);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{color, Border, Color};
+
+/// The colors and styles of a [`Plot`](crate::Plot). See
+/// [`Plot::set_theme`](crate::Plot::set_theme).
+///
+/// The default theme uses the default color of the terminal for the axis and the ticks, and
+/// draws the series of multi-series plots (like [`MultiLines`](crate::plot::MultiLines)) with
+/// blue, red, green, yellow, magenta and cyan.
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let x = (0..50).map(|x| x as f64 / 5.0).collect::<Vec<_>>();
+/// let ys = vec![
+///     x.iter().map(|x| x.sin()).collect(),
+///     x.iter().map(|x| x.cos()).collect(),
+/// ];
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..10.0))
+///     .set_codomain(Domain(-1.2..1.2))
+///     .set_theme(Theme::dark())
+///     .set_size(Size::new(50, 25))
+///     .add_plot(Box::new(plot::MultiLines::new(x, ys)));
+///
+/// println!("{plot}");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    /// The color of the x and y axis.
+    pub axis: Option<Color>,
+    /// The color of the values written next to the axis.
+    pub ticks: Option<Color>,
+    /// The colors given in order to the series of a plot. The colors are reused when there are
+    /// more series than colors. When empty, the series use the default color of the terminal.
+    pub palette: Vec<Color>,
+    /// The border around the figure.
+    pub border: Border,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            axis: None,
+            ticks: None,
+            palette: color::PALETTE.to_vec(),
+            border: Border::Rounded,
+        }
+    }
+}
+
+impl Theme {
+    /// A theme for terminals with a dark background, with bright colors.
+    pub fn dark() -> Self {
+        Self {
+            axis: Some(Color::BrightBlack),
+            ticks: Some(Color::White),
+            palette: vec![
+                Color::BrightBlue,
+                Color::BrightRed,
+                Color::BrightGreen,
+                Color::BrightYellow,
+                Color::BrightMagenta,
+                Color::BrightCyan,
+            ],
+            border: Border::Rounded,
+        }
+    }
+
+    /// A theme for terminals with a light background, with dark colors.
+    pub fn light() -> Self {
+        Self {
+            axis: Some(Color::BrightBlack),
+            ticks: Some(Color::Black),
+            palette: vec![
+                Color::Blue,
+                Color::Red,
+                Color::Green,
+                Color::Magenta,
+                Color::Cyan,
+                Color::Black,
+            ],
+            border: Border::Square,
+        }
+    }
+
+    /// A theme without any color.
+    pub fn monochrome() -> Self {
+        Self {
+            axis: None,
+            ticks: None,
+            palette: Vec::new(),
+            border: Border::Rounded,
+        }
+    }
+
+    /// The color of the series at `index`, if any.
+    pub(crate) fn series_color(&self, index: usize) -> Option<Color> {
+        match self.palette.len() {
+            0 => None,
+            len => Some(self.palette[index % len]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{plot, Domain, Plot, Size};
+
+    #[test]
+    fn colors_and_border() {
+        let theme = Theme::light();
+        assert_eq!(theme.series_color(7), Some(Color::Red));
+        assert_eq!(Theme::monochrome().series_color(0), None);
+        let mut plot = Plot::default();
+        plot.set_domain(Domain(-1.0..1.0))
+            .set_codomain(Domain(-1.0..1.0))
+            .set_size(Size::new(20, 8))
+            .set_theme(theme)
+            .add_plot(Box::new(plot::Graph::new(|x| x)));
+        let mut rendered = String::new();
+        plot.render_to(plot.decorations, true, &mut rendered)
+            .unwrap();
+        assert!(rendered.starts_with('┌'));
+        // The axis are bright black and the ticks are black.
+        assert!(rendered.contains("\x1b[90m"));
+        assert!(rendered.contains("\x1b[30m-1.0"));
+    }
+}
@@ -1,34 +1,243 @@
-use crate::Domain;
+use crate::{text, Domain, Scale, ViewCanvas};
 use std::fmt;
 
+/// The number of major ticks on each axis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Counts {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Default for Counts {
+    fn default() -> Self {
+        Self { x: 2, y: 2 }
+    }
+}
+
+/// How the values written next to an axis are formatted. See
+/// [`Plot::set_y_tick_format`](crate::Plot::set_y_tick_format).
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut plot = Plot::default();
+/// plot.set_domain(Domain(0.0..10.0))
+///     .set_codomain(Domain(0.0..1.0))
+///     .set_y_tick_format(TickFormat::Percent { precision: 0 })
+///     .set_y_ticks(3)
+///     .set_size(Size::new(50, 25))
+///     .add_plot(Box::new(plot::Graph::new(|x| 1.0 - (-x / 3.0).exp())));
+///
+/// println!("{plot}");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TickFormat {
+    /// The value with one decimal: `0.25` is written `0.3`.
+    #[default]
+    Decimal,
+    /// The value as a percentage with `precision` decimals, where `1.0` is 100%: `0.25` is
+    /// written `25%` with a precision of `0`.
+    Percent { precision: usize },
+    /// The value with an SI prefix and at most one decimal: `1500.0` is written `1.5k`,
+    /// `2_000_000.0` is written `2M` and `0.000003` is written `3µ`.
+    Si,
+    /// The value as a number of seconds since the Unix epoch, written as the time of the day in
+    /// UTC: `1700000000.0` is written `22:13:20`.
+    Time,
+}
+
+impl TickFormat {
+    pub(crate) fn format(&self, value: f64) -> String {
+        match *self {
+            Self::Decimal => format!("{value:.1}"),
+            Self::Percent { precision } => format!("{:.precision$}%", value * 100.0),
+            Self::Si => si(value),
+            Self::Time => time(value),
+        }
+    }
+}
+
+/// Write `value` with an SI prefix, from yocto (`y`, 10⁻²⁴) to yotta (`Y`, 10²⁴).
+fn si(value: f64) -> String {
+    const PREFIXES: [&str; 17] = [
+        "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+    ];
+    const UNIT: i32 = 8;
+    if value == 0.0 || !value.is_finite() {
+        return format!("{value}");
+    }
+    let mut index = ((value.abs().log10() / 3.0).floor() as i32).clamp(-UNIT, UNIT);
+    let mut mantissa = value / 1000f64.powi(index);
+    // Rounding can give a mantissa of 1000 (999.96 is written 1k and not 1000.0).
+    if (mantissa.abs() * 10.0).round() >= 10_000.0 && index < UNIT {
+        index += 1;
+        mantissa /= 1000.0;
+    }
+    let mantissa = format!("{mantissa:.1}");
+    let mantissa = mantissa.strip_suffix(".0").unwrap_or(&mantissa);
+    format!("{mantissa}{}", PREFIXES[(index + UNIT) as usize])
+}
+
+/// Write a number of seconds since the Unix epoch as the time of the day in UTC (`HH:MM:SS`).
+fn time(seconds: f64) -> String {
+    let seconds = seconds.round().rem_euclid(86_400.0) as u64;
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// The values of `count` major ticks, evenly spaced on the axis from the start to the end of
+/// `domain`.
+pub(crate) fn major(domain: &Domain, scale: Scale, count: usize) -> Vec<f64> {
+    let count = count.max(2);
+    (0..count)
+        .map(|index| scale.value_at(domain, index as f64 / (count - 1) as f64))
+        .collect()
+}
+
+/// The values of the minor ticks: `per_interval` ticks evenly spaced on the axis between each
+/// pair of consecutive major ticks.
+pub(crate) fn minor(domain: &Domain, scale: Scale, count: usize, per_interval: usize) -> Vec<f64> {
+    let intervals = count.max(2) - 1;
+    (0..intervals)
+        .flat_map(|interval| {
+            (1..=per_interval).map(move |index| {
+                let offset = index as f64 / (per_interval + 1) as f64;
+                scale.value_at(domain, (interval as f64 + offset) / intervals as f64)
+            })
+        })
+        .collect()
+}
+
+/// Draw a mark of `length` pixels across the x axis (at `y`) for each value of `xs`, and across
+/// the y axis (at `x`) for each value of `ys`.
+pub(crate) fn draw_marks(
+    canvas: &mut ViewCanvas,
+    (x, y): (f64, f64),
+    xs: &[f64],
+    ys: &[f64],
+    length: f64,
+) {
+    let view = canvas.view();
+    // The ends of the marks, `length / 2` pixels on each side of the axis.
+    let across = |domain: &Domain, scale: Scale, value: f64, pixels: usize| {
+        let fraction = scale.fraction(domain, value);
+        let half = length / 2.0 / pixels as f64;
+        (
+            scale.value_at(domain, fraction - half),
+            scale.value_at(domain, fraction + half),
+        )
+    };
+    let (y0, y1) = across(&view.codomain, view.y_scale, y, view.size.h);
+    for &tick in xs.iter() {
+        canvas.line(tick, y0, tick, y1);
+    }
+    let (x0, x1) = across(&view.domain, view.x_scale, x, view.size.w);
+    for &tick in ys.iter() {
+        canvas.line(x0, tick, x1, tick);
+    }
+}
+
 /// Create the labels for the x axis.
 pub(crate) struct XTicks {
-    labels: Vec<String>,
+    /// The labels with the column where they start.
+    labels: Vec<(usize, String)>,
     width: usize,
 }
 
 impl XTicks {
-    pub fn new(domain: &Domain, width: usize, _count: usize) -> Self {
-        let max = format!("{:.1}", domain.max());
-        let min = format!("{:.1}", domain.min());
-        Self {
-            labels: vec![min, max],
-            width,
+    /// Labels for `count` values evenly spaced from the start to the end of the domain, each
+    /// one written at the column of its value.
+    ///
+    /// The first and last labels are aligned with the edges of the view and the others are
+    /// centered on their column. A label that would overlap another label is skipped.
+    ///
+    /// The labels never take more than `width` columns.
+    pub fn new(
+        domain: &Domain,
+        scale: Scale,
+        format: TickFormat,
+        width: usize,
+        count: usize,
+    ) -> Self {
+        let last_column = width.max(1) - 1;
+        let values = major(domain, scale, count);
+        let first = format.format(values[0]);
+        let last = format.format(values[values.len() - 1]);
+        // When the first and last labels do not fit side by side, only the first one is kept,
+        // shortened to the width of the axis when needed.
+        if text::width(&first) + 1 + text::width(&last) > width {
+            let labels = vec![(0, text::truncate(&first, width))];
+            return Self { labels, width };
         }
+        let last_start = width.saturating_sub(text::width(&last));
+        let mut end = text::width(&first);
+        let mut labels = vec![(0, first)];
+        for (index, value) in values.iter().enumerate().take(values.len() - 1).skip(1) {
+            let column = index as f64 / (values.len() - 1) as f64 * last_column as f64;
+            let label = format.format(*value);
+            let width = text::width(&label);
+            let start = (column.round() as usize).saturating_sub(width / 2);
+            // Keep at least one space between two labels.
+            if start > end && start + width < last_start {
+                end = start + width;
+                labels.push((start, label));
+            }
+        }
+        labels.push((last_start, last));
+        Self { labels, width }
+    }
+    /// Labels written centered on the column of their x coordinate, for a categorical axis.
+    ///
+    /// Labels outside of the domain, or that would overlap another label, are skipped. Labels
+    /// wider than the axis are shortened.
+    pub fn categories(
+        domain: &Domain,
+        scale: Scale,
+        width: usize,
+        categories: &[(f64, String)],
+    ) -> Self {
+        let last_column = width.max(1) - 1;
+        let mut columns = categories
+            .iter()
+            .map(|(x, label)| (scale.fraction(domain, *x), label))
+            .filter(|(fraction, _)| (0.0..=1.0).contains(fraction))
+            .map(|(fraction, label)| ((fraction * last_column as f64).round() as usize, label))
+            .collect::<Vec<_>>();
+        columns.sort_by_key(|(column, _)| *column);
+        let mut labels = Vec::<(usize, String)>::new();
+        let mut end = 0;
+        for (column, label) in columns {
+            let label = text::truncate(label, width);
+            let label_width = text::width(&label);
+            let start = column
+                .saturating_sub(label_width / 2)
+                .min(width.saturating_sub(label_width));
+            // Keep at least one space between two labels.
+            if labels.is_empty() || start > end {
+                end = start + label_width;
+                labels.push((start, label));
+            }
+        }
+        Self { labels, width }
     }
 }
 
 impl fmt::Display for XTicks {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let labels_width = self.labels.iter().fold(0, |sum, label| label.len() + sum);
-        let total_spacing = self.width - labels_width;
-        let spacing = total_spacing / (self.labels.len() - 1);
-        for (index, label) in self.labels.iter().enumerate() {
-            let space = if index == 0 { 0 } else { spacing };
+        let mut column = 0;
+        for (start, label) in self.labels.iter() {
+            let space = start.saturating_sub(column);
             write!(f, "{: >space$}{label}", "")?;
+            column += space + text::width(label);
         }
-        let fill = self.width - spacing * (self.labels.len() - 1) - labels_width;
-        write!(f, "{: >fill$}", "",)
+        let fill = self.width.saturating_sub(column);
+        write!(f, "{: >fill$}", "")
     }
 }
 
@@ -39,22 +248,66 @@ pub(crate) struct YTicks {
 }
 
 impl YTicks {
-    pub fn new(codomain: &Domain, row_count: usize, _count: usize) -> Self {
-        let max = format!("{:.1}", codomain.max());
-        let min = format!("{:.1}", codomain.min());
+    /// Labels for `count` rows evenly spaced from the first row (the end of the codomain) to the
+    /// last row (the start of the codomain). Each label is the value of its row.
+    pub fn new(
+        codomain: &Domain,
+        scale: Scale,
+        format: TickFormat,
+        row_count: usize,
+        count: usize,
+    ) -> Self {
+        let last_row = row_count.max(1) - 1;
+        let count = count.clamp(2, last_row.max(1) + 1);
+        let mut row_indexes = (0..count)
+            .map(|index| (index * last_row + (count - 1) / 2) / (count - 1))
+            .collect::<Vec<_>>();
+        row_indexes.dedup();
+        let labels = row_indexes
+            .iter()
+            .map(|&row| {
+                let t = row as f64 / last_row.max(1) as f64;
+                format.format(scale.value_at(codomain, 1.0 - t))
+            })
+            .collect();
+        Self {
+            labels,
+            row_indexes,
+        }
+    }
+
+    /// Labels written on the row holding their y coordinate, for a categorical axis.
+    ///
+    /// Labels outside of the codomain, or on the row of a previous label, are skipped.
+    pub fn categories(
+        codomain: &Domain,
+        scale: Scale,
+        row_count: usize,
+        categories: &[(f64, String)],
+    ) -> Self {
+        let last_row = row_count.max(1) - 1;
+        let (mut labels, mut row_indexes) = (Vec::new(), Vec::new());
+        for (y, label) in categories {
+            let fraction = scale.fraction(codomain, *y);
+            let row = (((1.0 - fraction) * row_count as f64) as usize).min(last_row);
+            if (0.0..=1.0).contains(&fraction) && !row_indexes.contains(&row) {
+                labels.push(label.clone());
+                row_indexes.push(row);
+            }
+        }
         Self {
-            labels: vec![max, min],
-            row_indexes: vec![0, row_count - 1],
+            labels,
+            row_indexes,
         }
     }
 
     /// The width required for the widest label
     pub fn display_width(&self) -> usize {
-        let widest_label = self.labels.iter().max_by_key(|label| label.len());
-        match widest_label {
-            Some(label) => label.len(),
-            _ => 0,
-        }
+        self.labels
+            .iter()
+            .map(|label| text::width(label))
+            .max()
+            .unwrap_or(0)
     }
 
     /// Get a label for a specific row.
@@ -69,3 +322,113 @@ impl YTicks {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minor_ticks_between_major_ticks() {
+        let domain = Domain(0.0..10.0);
+        assert_eq!(major(&domain, Scale::Linear, 3), vec![0.0, 5.0, 10.0]);
+        assert_eq!(
+            minor(&domain, Scale::Linear, 3, 4),
+            vec![1.0, 2.0, 3.0, 4.0, 6.0, 7.0, 8.0, 9.0]
+        );
+        assert!(minor(&domain, Scale::Linear, 2, 0).is_empty());
+    }
+
+    #[test]
+    fn x_labels_on_evenly_spaced_columns() {
+        let ticks = XTicks::new(
+            &Domain(0.0..10.0),
+            Scale::Linear,
+            TickFormat::Decimal,
+            21,
+            3,
+        );
+        assert_eq!(ticks.to_string(), "0.0      5.0     10.0");
+        let ticks = XTicks::new(
+            &Domain(0.0..10.0),
+            Scale::Linear,
+            TickFormat::Decimal,
+            21,
+            11,
+        );
+        assert_eq!(ticks.to_string(), "0.0  3.0 5.0 7.0 10.0");
+    }
+
+    #[test]
+    fn narrow_x_labels() {
+        let ticks = |width| {
+            let domain = Domain(0.0..10.0);
+            XTicks::new(&domain, Scale::Linear, TickFormat::Decimal, width, 3).to_string()
+        };
+        assert_eq!(ticks(8), "0.0 10.0");
+        assert_eq!(ticks(7), "0.0    ");
+        assert_eq!(ticks(2), "0…");
+        assert_eq!(ticks(0), "");
+        let categories = [(0.5, "apples".to_string())];
+        let ticks = XTicks::categories(&Domain(0.0..1.0), Scale::Linear, 4, &categories);
+        assert_eq!(ticks.to_string(), "app…");
+    }
+
+    #[test]
+    fn y_categories() {
+        let categories = [
+            (4.5, "a".to_string()),
+            (1.5, "b".to_string()),
+            (1.6, "c".to_string()),
+            (6.0, "d".to_string()),
+        ];
+        let ticks = YTicks::categories(&Domain(0.0..5.0), Scale::Linear, 5, &categories);
+        let rows = (0..5).map(|row| ticks.get(row)).collect::<Vec<_>>();
+        assert_eq!(rows, ["a", "", "", "b", ""]);
+    }
+
+    #[test]
+    fn categories() {
+        let categories = [(0.5, "a".to_string()), (1.5, "bb".to_string())];
+        let ticks = XTicks::categories(&Domain(0.0..2.0), Scale::Linear, 9, &categories);
+        assert_eq!(ticks.to_string(), "  a  bb  ");
+        let categories = [(0.5, "long".to_string()), (0.6, "skipped".to_string())];
+        let ticks = XTicks::categories(&Domain(0.0..2.0), Scale::Linear, 9, &categories);
+        assert_eq!(ticks.to_string(), "long     ");
+    }
+
+    #[test]
+    fn y_labels_on_evenly_spaced_rows() {
+        let ticks = YTicks::new(&Domain(0.0..8.0), Scale::Linear, TickFormat::Decimal, 9, 3);
+        assert_eq!(ticks.get(0), "8.0");
+        assert_eq!(ticks.get(4), "4.0");
+        assert_eq!(ticks.get(8), "0.0");
+        assert_eq!(ticks.get(2), "");
+        let ticks = YTicks::new(&Domain(0.0..1.0), Scale::Linear, TickFormat::Decimal, 2, 5);
+        assert_eq!(ticks.labels, vec!["1.0", "0.0"]);
+    }
+
+    #[test]
+    fn si_prefixes() {
+        assert_eq!(si(1500.0), "1.5k");
+        assert_eq!(si(2_000_000.0), "2M");
+        assert_eq!(si(0.000003), "3µ");
+        assert_eq!(si(-42.0), "-42");
+        assert_eq!(si(999_960.0), "1M");
+        assert_eq!(si(0.0), "0");
+    }
+
+    #[test]
+    fn percent_labels() {
+        let format = TickFormat::Percent { precision: 1 };
+        let ticks = YTicks::new(&Domain(0.0..0.5), Scale::Linear, format, 3, 3);
+        assert_eq!(ticks.labels, vec!["50.0%", "25.0%", "0.0%"]);
+        assert_eq!(ticks.display_width(), 5);
+    }
+
+    #[test]
+    fn time_of_the_day() {
+        assert_eq!(time(1_700_000_000.0), "22:13:20");
+        assert_eq!(time(59.6), "00:01:00");
+        assert_eq!(time(-1.0), "23:59:59");
+    }
+}
@@ -0,0 +1,82 @@
+use crate::color::Ink;
+use crate::{text, Color};
+
+/// The horizontal alignment of a text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+
+/// How the title of a [`Plot`](crate::Plot) is drawn. See
+/// [`Plot::set_title_style`](crate::Plot::set_title_style).
+///
+/// # Examples
+///
+/// ```rust
+/// use termplot::*;
+///
+/// let mut plot = Plot::default();
+/// plot.set_title("Graph title")
+///     .set_title_style(TitleStyle {
+///         align: Align::Left,
+///         bold: true,
+///         padding: 1,
+///         ..TitleStyle::default()
+///     })
+///     .set_size(Size::new(50, 25))
+///     .add_plot(Box::new(plot::Graph::new(|x| x.sin() / x)));
+///
+/// println!("{plot}");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TitleStyle {
+    /// The alignment of the title.
+    pub align: Align,
+    /// Whether the title is written in bold.
+    pub bold: bool,
+    /// The color of the title. `None` uses the default color of the terminal.
+    pub color: Option<Color>,
+    /// The number of spaces on each side of the title.
+    pub padding: usize,
+    /// Whether the title is embedded in the top border of the figure, or written on its own line
+    /// above the figure.
+    pub embedded: bool,
+}
+
+impl Default for TitleStyle {
+    fn default() -> Self {
+        Self {
+            align: Align::Center,
+            bold: false,
+            color: None,
+            padding: 0,
+            embedded: true,
+        }
+    }
+}
+
+impl TitleStyle {
+    /// The title with its padding and emphasis (when `colors` is `true`), aligned in `width`
+    /// columns filled with `fill`.
+    pub(crate) fn render(&self, title: &str, width: usize, fill: char, colors: bool) -> String {
+        if title.is_empty() {
+            return text::align("", width, self.align, fill);
+        }
+        let ink = Ink {
+            color: self.color,
+            bold: self.bold,
+            ..Ink::default()
+        };
+        let styled = ink.paint(title, colors);
+        let padding = " ".repeat(self.padding);
+        text::align(
+            &format!("{padding}{styled}{padding}"),
+            width,
+            self.align,
+            fill,
+        )
+    }
+}
@@ -0,0 +1,55 @@
+use crate::color::Ink;
+use crate::{text, Position};
+
+/// Write `watermark` faintly (when `colors` is `true`) over the `rows` of the view, at
+/// `position`. The watermark is written on a single line and cut to the width of the view.
+pub(crate) fn overlay(rows: &mut [String], watermark: &str, position: Position, colors: bool) {
+    let Some(width) = rows.first().map(|row| text::width(row)) else {
+        return;
+    };
+    let watermark = text::truncate(&watermark.replace('\n', " "), width);
+    let length = text::width(&watermark);
+    let last = rows.len() - 1;
+    let (row, column) = match position {
+        Position::TopLeft => (0, 0),
+        Position::TopRight => (0, width - length),
+        Position::BottomLeft => (last, 0),
+        Position::BottomRight => (last, width - length),
+        Position::Bottom => (last, (width - length) / 2),
+        Position::Left => (last / 2, 0),
+        Position::Right => (last / 2, width - length),
+    };
+    let faint = Ink {
+        dimmed: true,
+        ..Ink::default()
+    };
+    rows[row] = text::overlay(&rows[row], column, &faint.paint(&watermark, colors));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corners() {
+        let overlaid = |position| {
+            let mut rows = vec![" ".repeat(6); 3];
+            overlay(&mut rows, "host", position, false);
+            assert!(rows.iter().all(|row| text::width(row) == 6));
+            rows.iter().map(|row| row.find("host")).collect::<Vec<_>>()
+        };
+        assert!(matches!(
+            overlaid(Position::TopLeft)[..],
+            [Some(_), None, None]
+        ));
+        assert!(matches!(
+            overlaid(Position::BottomRight)[..],
+            [None, None, Some(_)]
+        ));
+        assert!(matches!(
+            overlaid(Position::Left)[..],
+            [None, Some(_), None]
+        ));
+        assert!(overlaid(Position::TopRight)[0].unwrap() >= 2);
+    }
+}